@@ -37,8 +37,60 @@ fn x509(c: &mut Criterion) {
     group.bench_function("x509_parser", |b| {
         b.iter(|| black_box(X509Certificate::from_der(data)))
     });
+
+    // Certificates nest several levels of `SEQUENCE`s, which the encoder
+    // encodes with pooled scratch buffers rather than allocating for each.
+    let certificate = rasn::der::decode::<rasn_pkix::Certificate>(data).unwrap();
+    group.bench_function("rasn_encode", |b| {
+        b.iter_with_large_drop(|| black_box(rasn::der::encode(&certificate).unwrap()))
+    });
+    group.finish();
+}
+
+/// A `SEQUENCE` of only primitive fields, such as one of millions of small
+/// records.
+#[derive(rasn::AsnType, rasn::Decode, rasn::Encode)]
+struct Record {
+    id: u32,
+    active: bool,
+    value: i64,
+}
+
+fn primitives(c: &mut Criterion) {
+    let mut group = c.benchmark_group("primitives");
+
+    let integer = black_box(0x1234_5678_i64);
+    let data = rasn::der::encode(&integer).unwrap();
+    group.bench_function("integer_encode", |b| {
+        b.iter(|| black_box(rasn::der::encode(&integer).unwrap()))
+    });
+    group.bench_function("integer_decode", |b| {
+        b.iter(|| black_box(rasn::der::decode::<i64>(&data).unwrap()))
+    });
+
+    let octets = black_box(rasn::types::OctetString::from(vec![0xAB; 64]));
+    let data = rasn::der::encode(&octets).unwrap();
+    group.bench_function("octet_string_encode", |b| {
+        b.iter(|| black_box(rasn::der::encode(&octets).unwrap()))
+    });
+    group.bench_function("octet_string_decode", |b| {
+        b.iter(|| black_box(rasn::der::decode::<rasn::types::OctetString>(&data).unwrap()))
+    });
+
+    let record = black_box(Record {
+        id: 42,
+        active: true,
+        value: -0x1234_5678,
+    });
+    let data = rasn::der::encode(&record).unwrap();
+    group.bench_function("sequence_encode", |b| {
+        b.iter(|| black_box(rasn::der::encode(&record).unwrap()))
+    });
+    group.bench_function("sequence_decode", |b| {
+        b.iter(|| black_box(rasn::der::decode::<Record>(&data).unwrap()))
+    });
     group.finish();
 }
 
-criterion_group!(codec, x509, asn1tools);
+criterion_group!(codec, x509, asn1tools, primitives);
 criterion_main!(codec);
@@ -1,5 +1,3 @@
-use itertools::Itertools;
-
 use crate::config::*;
 
 pub fn derive_struct_impl(
@@ -10,31 +8,8 @@ pub fn derive_struct_impl(
 ) -> proc_macro2::TokenStream {
     let crate_root = &config.crate_root;
     let tag = config.tag_for_struct(&container.fields);
-    let field_groups = container
-        .fields
-        .iter()
-        .enumerate()
-        .map(|(i, f)| (i, FieldConfig::new(f, config)))
-        .group_by(|(_, config)| config.is_option_type());
-
-    let all_optional_tags_are_unique = field_groups
-        .into_iter()
-        .filter_map(|(key, fields)| key.then(|| fields))
-        .map(|fields| {
-            let tag_tree = fields.map(|(i, f)| f.tag_tree(i));
-            let error_message = format!(
-                "{}'s fields is not a valid \
-                        order of ASN.1 tags, ensure that your field's tags and \
-                        OPTIONALs are correct.",
-                name
-            );
-
-            quote!({
-                const LIST: &'static [#crate_root::TagTree] = &[#(#tag_tree),*];
-                const TAG_TREE: #crate_root::TagTree = #crate_root::TagTree::Choice(LIST);
-                const _: () = assert!(TAG_TREE.is_unique(), #error_message);
-            })
-        });
+    let unique_field_tags = (!config.delegate && !config.named_bits)
+        .then(|| unique_field_tags(&name, &container.fields, config));
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -42,7 +17,7 @@ pub fn derive_struct_impl(
         #[automatically_derived]
         impl #impl_generics  #crate_root::AsnType for #name #ty_generics #where_clause {
             const TAG: #crate_root::Tag = {
-                #(#all_optional_tags_are_unique)*
+                #unique_field_tags
 
                 #tag
             };
@@ -7,11 +7,13 @@ use crate::{ext::TypeExt, tag::Tag};
 pub struct Config {
     pub crate_root: Path,
     pub enumerated: bool,
+    pub integer: bool,
     pub choice: bool,
     pub set: bool,
     pub automatic_tags: bool,
     pub option_type: OptionalEnum,
     pub delegate: bool,
+    pub named_bits: bool,
     pub tag: Option<Tag>,
 }
 
@@ -21,10 +23,12 @@ impl Config {
         let mut set = false;
         let mut crate_root = None;
         let mut enumerated = false;
+        let mut integer = false;
         let mut automatic_tags = false;
         let mut tag = None;
         let mut option = None;
         let mut delegate = false;
+        let mut named_bits = false;
 
         let mut iter = input
             .attrs
@@ -48,6 +52,8 @@ impl Config {
                     }
                 } else if path.is_ident("enumerated") {
                     enumerated = true;
+                } else if path.is_ident("integer") {
+                    integer = true;
                 } else if path.is_ident("choice") {
                     choice = true;
                 } else if path.is_ident("set") {
@@ -76,6 +82,8 @@ impl Config {
                     tag = Tag::from_meta(item);
                 } else if path.is_ident("delegate") {
                     delegate = true;
+                } else if path.is_ident("named_bits") {
+                    named_bits = true;
                 } else {
                     panic!("unknown input provided: {}", path.to_token_stream());
                 }
@@ -84,14 +92,22 @@ impl Config {
 
         let is_enum = matches!(input.data, syn::Data::Enum(_));
 
-        if !is_enum && (choice || enumerated) {
-            panic!("Structs cannot be annotated with `#[rasn(choice)]` or `#[rasn(enumerated)]`.");
+        if !is_enum && (choice || enumerated || integer) {
+            panic!("Structs cannot be annotated with `#[rasn(choice)]`, `#[rasn(enumerated)]`, or `#[rasn(integer)]`.");
         } else if is_enum && set {
             panic!("Enums cannot be annotated with `#[rasn(set)]`.");
-        } else if is_enum && ((choice && enumerated) || (!choice && !enumerated)) {
+        } else if is_enum
+            && [choice, enumerated, integer]
+                .iter()
+                .filter(|kind| **kind)
+                .count()
+                != 1
+        {
             panic!(
-                "Enums must be annotated with either `#[rasn(choice)]` OR `#[rasn(enumerated)]`."
+                "Enums must be annotated with one of `#[rasn(choice)]`, `#[rasn(enumerated)]`, or `#[rasn(integer)]`."
             )
+        } else if integer && matches!(tag, Some(Tag { explicit: true, .. })) {
+            panic!("`#[rasn(integer)]` enums can't have an explicit tag, tag the field instead.");
         }
 
         let mut invalid_delegate = false;
@@ -108,6 +124,11 @@ impl Config {
             panic!("`#[rasn(delegate)]` is only valid on single-unit structs.");
         }
 
+        let explicit = matches!(tag, Some(Tag { explicit: true, .. }));
+        if named_bits && (is_enum || set || delegate || automatic_tags || explicit) {
+            panic!("`#[rasn(named_bits)]` is only valid on structs of `bool` fields, and can't be combined with `set`, `delegate`, `automatic_tags`, or an explicit tag.");
+        }
+
         let option_type = {
             let (path, some_variant, none_variant) = option.unwrap_or((None, None, None));
 
@@ -137,9 +158,11 @@ impl Config {
             choice,
             set,
             enumerated,
+            integer,
             tag,
             option_type,
             delegate,
+            named_bits,
             crate_root: crate_root.unwrap_or_else(|| {
                 syn::LitStr::new(crate::CRATE_NAME, proc_macro2::Span::call_site())
                     .parse()
@@ -164,6 +187,27 @@ impl Config {
         self.tag.as_ref().map_or(false, |tag| tag.explicit)
     }
 
+    /// The bit position of each field of a `#[rasn(named_bits)]` struct,
+    /// which follow on from the previous field's unless set with
+    /// `#[rasn(bit = N)]`.
+    pub fn named_bit_positions(&self, fields: &syn::Fields) -> Vec<usize> {
+        let mut positions: Vec<usize> = Vec::new();
+
+        for field in fields {
+            let position = FieldConfig::new(field, self)
+                .bit
+                .unwrap_or_else(|| positions.last().map_or(0, |position| position + 1));
+
+            if positions.contains(&position) {
+                panic!("Bit {} is used by more than one field.", position);
+            }
+
+            positions.push(position);
+        }
+
+        positions
+    }
+
     pub fn tag_for_struct(&self, fields: &syn::Fields) -> proc_macro2::TokenStream {
         let crate_root = &self.crate_root;
         self.tag
@@ -178,6 +222,10 @@ impl Config {
                 })
             })
             .or_else(|| self.set.then(|| quote!(#crate_root::Tag::SET)))
+            .or_else(|| {
+                self.named_bits
+                    .then(|| quote!(#crate_root::Tag::BIT_STRING))
+            })
             .unwrap_or(quote!(#crate_root::Tag::SEQUENCE))
     }
 }
@@ -202,27 +250,48 @@ impl OptionalEnum {
     }
 
     pub(crate) fn map_to_inner_type<'ty>(&self, ty: &'ty syn::Type) -> Option<&'ty syn::Type> {
-        match ty {
-            syn::Type::Path(path) => path
-                .path
-                .segments
-                .last()
-                .filter(|segment| segment.ident == self.path)
-                .and_then(|segment| {
-                    if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
-                        args.args.first().and_then(|arg| {
-                            if let syn::GenericArgument::Type(ty) = arg {
-                                Some(ty)
-                            } else {
-                                None
-                            }
-                        })
-                    } else {
-                        None
-                    }
-                }),
-            _ => None,
-        }
+        map_to_inner_type(ty, &self.path)
+    }
+}
+
+/// Whether `ty` is one of the known `UTF8String` types.
+fn is_string_type(ty: &syn::Type) -> bool {
+    matches!(
+        ty,
+        syn::Type::Path(path) if path.path.segments.last().is_some_and(|segment| {
+            ["String", "Utf8String", "ArrayString"]
+                .iter()
+                .any(|name| segment.ident == name)
+        })
+    )
+}
+
+/// Returns `T` if `ty` is a `wrapper<T>` (e.g. `Option<T>`).
+fn map_to_inner_type<'ty, I>(ty: &'ty syn::Type, wrapper: &I) -> Option<&'ty syn::Type>
+where
+    I: ?Sized,
+    syn::Ident: PartialEq<I>,
+{
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .filter(|segment| segment.ident == *wrapper)
+            .and_then(|segment| {
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    args.args.first().and_then(|arg| {
+                        if let syn::GenericArgument::Type(ty) = arg {
+                            Some(ty)
+                        } else {
+                            None
+                        }
+                    })
+                } else {
+                    None
+                }
+            }),
+        _ => None,
     }
 }
 
@@ -321,10 +390,8 @@ impl<'config> VariantConfig<'config> {
                         &self.container_config,
                     )
                 } else {
-                    let decode_fields = self.variant.fields.iter().enumerate().map(|(i, field)| {
-                        let field_config = FieldConfig::new(field, self.container_config);
-                        field_config.decode(name, i)
-                    });
+                    let decode_fields =
+                        decode_sequence_fields(name, &self.variant.fields, self.container_config);
 
                     quote! {
                         decoder.decode_sequence(#tag, |decoder| {
@@ -346,8 +413,8 @@ impl<'config> VariantConfig<'config> {
 
     pub fn tag(&self, context: usize) -> proc_macro2::TokenStream {
         let crate_root = &self.container_config.crate_root;
-        if let Some(Tag { class, value, .. }) = &self.tag {
-            quote!(#crate_root::Tag::new(#class, #value))
+        if let Some(tag) = &self.tag {
+            tag.to_tokens(crate_root)
         } else if self.container_config.automatic_tags {
             quote!(#crate_root::Tag::new(#crate_root::types::Class::Context, #context as u32))
         } else {
@@ -361,30 +428,19 @@ impl<'config> VariantConfig<'config> {
             let tag = self.tag(context);
             quote!(#crate_root::TagTree::Leaf(#tag),)
         } else {
-            let field_tags = self
-                .variant
-                .fields
-                .iter()
-                .enumerate()
-                .filter(|(_, f)| FieldConfig::new(f, self.container_config).is_option_type())
-                .map(|(i, f)| FieldConfig::new(f, self.container_config).tag_tree(i));
-
             match self.variant.fields {
                 syn::Fields::Unit => {
                     quote!(#crate_root::TagTree::Leaf(<() as #crate_root::AsnType>::TAG),)
                 }
                 syn::Fields::Named(_) => {
-                    let error_message = format!(
-                        "{}'s fields is not a valid \
-                        order of ASN.1 tags, ensure that your field's tags and \
-                        OPTIONALs are correct.",
-                        self.variant.ident
+                    let unique_field_tags = unique_field_tags(
+                        &self.variant.ident,
+                        &self.variant.fields,
+                        self.container_config,
                     );
 
                     quote!({
-                        const FIELD_LIST: &'static [#crate_root::TagTree] = &[#(#field_tags,)*];
-                        const FIELD_TAG_TREE: #crate_root::TagTree = #crate_root::TagTree::Choice(FIELD_LIST);
-                        const _: () = assert!(FIELD_TAG_TREE.is_unique(), #error_message);
+                        #unique_field_tags
                         #crate_root::TagTree::Leaf(#crate_root::Tag::SEQUENCE)
                     },)
                 }
@@ -409,12 +465,20 @@ pub struct FieldConfig<'a> {
     pub container_config: &'a Config,
     pub tag: Option<Tag>,
     pub default: Option<Option<syn::Path>>,
+    pub unknown_fields: bool,
+    pub bit: Option<usize>,
+    pub value: Option<syn::LitStr>,
+    pub size: Option<syn::LitStr>,
 }
 
 impl<'a> FieldConfig<'a> {
     pub fn new(field: &'a syn::Field, container_config: &'a Config) -> Self {
         let mut default = None;
         let mut tag = None;
+        let mut unknown_fields = false;
+        let mut bit = None;
+        let mut value = None;
+        let mut size = None;
         let mut iter = field
             .attrs
             .iter()
@@ -437,19 +501,52 @@ impl<'a> FieldConfig<'a> {
                         },
                         _ => None,
                     });
+                } else if path.is_ident("unknown_fields") {
+                    unknown_fields = true;
+                } else if path.is_ident("bit") {
+                    bit = match item {
+                        syn::Meta::NameValue(syn::MetaNameValue {
+                            lit: syn::Lit::Int(int),
+                            ..
+                        }) => Some(int.base10_parse().unwrap()),
+                        _ => panic!("Expected a bit position, e.g. `#[rasn(bit = 5)]`."),
+                    };
+                } else if path.is_ident("value") {
+                    value = Some(parse_range(item, "value"));
+                } else if path.is_ident("size") {
+                    size = Some(parse_range(item, "size"));
                 }
             }
         }
 
+        if unknown_fields && (tag.is_some() || default.is_some()) {
+            panic!("`#[rasn(unknown_fields)]` can't be combined with `tag` or `default`.");
+        }
+
+        // An `Option` field is already `OPTIONAL`, and can't also be `DEFAULT`,
+        // as an absent field would then mean both `None` and the default.
+        if default.is_some() && container_config.option_type.is_option_type(&field.ty) {
+            panic!("An `Option` field is `OPTIONAL`, so it can't also have `#[rasn(default)]`.");
+        }
+
         Self {
             field,
             container_config,
             tag,
             default,
+            unknown_fields,
+            bit,
+            value,
+            size,
         }
     }
 
-    pub fn encode(&self, context: usize, use_self: bool) -> proc_macro2::TokenStream {
+    pub fn encode(
+        &self,
+        name: &syn::Ident,
+        context: usize,
+        use_self: bool,
+    ) -> proc_macro2::TokenStream {
         let this = use_self.then(|| quote!(self.));
         let tag = self.tag(context);
         let i = syn::Index::from(context);
@@ -461,25 +558,55 @@ impl<'a> FieldConfig<'a> {
             .unwrap_or_else(|| quote!(#i));
 
         let encode_op = if self.tag.is_some() || self.container_config.automatic_tags {
-            if self.tag.as_ref().map_or(false, |tag| tag.explicit) {
-                let encode = quote!(encoder.encode_explicit_prefix(#tag, &self.#field)?;);
-                if self.is_option_type() {
-                    let none = &self.container_config.option_type.none_variant;
-                    quote! {
-                        if !matches!(&#this #field, #none) {
-                            #encode
-                        }
+            let encode = quote!(encoder.encode_explicit_prefix(#tag, &#this #field)?;);
+            let explicit = if self.is_option_type() {
+                let none = &self.container_config.option_type.none_variant;
+                quote! {
+                    #[allow(clippy::redundant_pattern_matching)]
+                    if !matches!(&#this #field, #none) {
+                        #encode
                     }
-                } else {
-                    encode
                 }
             } else {
-                quote!(#this #field.encode_with_tag(encoder, #tag)?;)
+                encode
+            };
+
+            if self.has_explicit_tag() {
+                explicit
+            } else {
+                let crate_root = &self.container_config.crate_root;
+                let ty = self.stripped_type();
+
+                // A `CHOICE` can only be explicitly tagged, as its own tag is
+                // what identifies the chosen alternative.
+                quote! {
+                    if <#ty as #crate_root::AsnType>::TAG.is_choice() {
+                        #explicit
+                    } else {
+                        #this #field.encode_with_tag(encoder, #tag)?;
+                    }
+                }
             }
         } else {
             quote!(#this #field.encode(encoder)?;)
         };
 
+        let crate_root = &self.container_config.crate_root;
+        let path = self.path(name, context);
+        let value = if use_self {
+            quote!(&self.#field)
+        } else {
+            quote!(#field)
+        };
+        let check_constraints = self.check_constraints(value, |message| {
+            let message = format!("`{}` {}", path, message);
+            quote!(#crate_root::enc::Error::custom(#message))
+        });
+        let encode_op = quote! {
+            #check_constraints
+            #encode_op
+        };
+
         if self.default.is_some() {
             let mut ty = self.field.ty.clone();
             ty.strip_lifetimes();
@@ -489,7 +616,7 @@ impl<'a> FieldConfig<'a> {
             };
 
             quote! {
-                if #this #field != #default_fn {
+                if #crate_root::Encoder::emits_defaults(encoder) || #this #field != #default_fn {
                     #encode_op
                 }
             }
@@ -498,40 +625,201 @@ impl<'a> FieldConfig<'a> {
         }
     }
 
-    pub fn decode(&self, name: &syn::Ident, context: usize) -> proc_macro2::TokenStream {
+    /// Decodes the field, where `following` is the tag trees of the
+    /// mandatory fields after it in its `SEQUENCE`.
+    pub fn decode(
+        &self,
+        name: &syn::Ident,
+        context: usize,
+        following: &[proc_macro2::TokenStream],
+    ) -> proc_macro2::TokenStream {
         let crate_root = &self.container_config.crate_root;
         let or_else = match self.default {
             Some(Some(ref path)) => quote! { .unwrap_or_else(|_| #path ()) },
             Some(None) => quote! { .unwrap_or_default() },
             None if self.is_option_type() => quote! { .ok() },
             None => {
-                let ident = format!(
-                    "{}.{}",
-                    name,
-                    self.field
-                        .ident
-                        .as_ref()
-                        .map(|ident| ident.to_string())
-                        .unwrap_or_else(|| context.to_string())
-                );
-                quote!(.map_err(|error| #crate_root::de::Error::field_error(#ident, error))?)
+                let path = self.path(name, context);
+                quote!(.map_err(|error| #crate_root::de::Error::context(error, #path))?)
             }
         };
 
         let lhs = self.field.ident.as_ref().map(|i| quote!(#i :));
         let tag = self.tag(context);
 
-        if self.tag.is_some() || self.container_config.automatic_tags {
-            if self.tag.as_ref().map_or(false, |tag| tag.explicit) {
-                quote!(#lhs decoder.decode_explicit_prefix(#tag) #or_else)
+        let decode_op = if self.unknown_fields {
+            quote!(decoder.decode_unknown_fields() #or_else)
+        } else if self.tag.is_some() || self.container_config.automatic_tags {
+            let explicit = quote!(decoder.decode_explicit_prefix(#tag) #or_else);
+
+            if self.has_explicit_tag() {
+                explicit
+            } else {
+                let ty = self.stripped_type();
+
+                quote! {
+                    if <#ty as #crate_root::AsnType>::TAG.is_choice() {
+                        #explicit
+                    } else {
+                        <_>::decode_with_tag(decoder, #tag) #or_else
+                    }
+                }
+            }
+        } else {
+            quote!(<_>::decode(decoder) #or_else)
+        };
+
+        // An optional field that can have the same tag as a mandatory field
+        // after it is only present if there's still a value left for each
+        // of the mandatory fields after decoding it.
+        let decode_op = if self.is_option_type()
+            && self.default.is_none()
+            && !self.unknown_fields
+            && !following.is_empty()
+        {
+            let none = &self.container_config.option_type.none_variant;
+
+            quote! {
+                {
+                    let following: &[#crate_root::TagTree] = &[#(#following),*];
+                    let tag = #tag;
+
+                    if !tag.is_choice()
+                        && following.iter().any(|field| field.contains(tag))
+                        && !#crate_root::Decoder::is_present_before(decoder, tag, following)
+                    {
+                        #none
+                    } else {
+                        #decode_op
+                    }
+                }
+            }
+        } else {
+            decode_op
+        };
+
+        if self.has_constraints() {
+            let ty = &self.field.ty;
+            let check_constraints = self.decode_constraints(name, context, quote!(&value));
+
+            quote! {
+                #lhs {
+                    let value: #ty = #decode_op;
+                    #check_constraints
+                    value
+                }
+            }
+        } else {
+            quote!(#lhs #decode_op)
+        }
+    }
+
+    /// The path to the field used in errors, e.g. `Certificate.signature`.
+    fn path(&self, name: &syn::Ident, context: usize) -> String {
+        format!("{}.{}", name, self.name(context))
+    }
+
+    fn has_constraints(&self) -> bool {
+        self.value.is_some() || self.size.is_some()
+    }
+
+    /// Checks the decoded `value` (a reference to the field) against the
+    /// field's constraints, returning a decoding error if it's outside of
+    /// them.
+    pub fn decode_constraints(
+        &self,
+        name: &syn::Ident,
+        context: usize,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let crate_root = &self.container_config.crate_root;
+        let path = self.path(name, context);
+
+        self.check_constraints(value, |message| {
+            let message = format!("Field {}", message);
+            quote! {
+                #crate_root::de::Error::context(#crate_root::de::Error::custom(#message), #path)
+            }
+        })
+    }
+
+    /// Checks `value` (a reference to the field) against the field's `value`
+    /// and `size` constraints, returning the error from `error` if it's
+    /// outside of them. The constraints apply to the type inside of any
+    /// `Option` or `Box`, so an absent optional field is always valid.
+    fn check_constraints(
+        &self,
+        value: proc_macro2::TokenStream,
+        error: impl Fn(String) -> proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        if !self.has_constraints() {
+            return quote!();
+        }
+
+        let check = |constraint: &Option<syn::LitStr>, operand, kind| {
+            constraint.as_ref().map(|range| {
+                let error = error(format!(
+                    "is outside of the {} constraint `{}`",
+                    kind,
+                    range.value()
+                ));
+                let range = range.parse::<syn::ExprRange>().unwrap();
+
+                quote! {
+                    if !(#range).contains(#operand) {
+                        return Err(#error);
+                    }
+                }
+            })
+        };
+        let mut ty = &self.field.ty;
+        let mut unwrap = Vec::new();
+
+        loop {
+            if let Some(inner) = self.container_config.option_type.map_to_inner_type(ty) {
+                let some = &self.container_config.option_type.some_variant;
+                unwrap.push(quote!(if let #some(value) = value));
+                ty = inner;
+            } else if let Some(inner) = map_to_inner_type(ty, "Box") {
+                unwrap.push(quote!(let value = &**value;));
+                ty = inner;
             } else {
-                quote!(#lhs <_>::decode_with_tag(decoder, #tag) #or_else)
+                break;
             }
+        }
+
+        // The size of a character string is its number of characters.
+        let size = if is_string_type(ty) {
+            quote!(&value.chars().count())
         } else {
-            quote!(#lhs <_>::decode(decoder) #or_else)
+            quote!(&value.len())
+        };
+        let value_check = check(&self.value, quote!(value), "value");
+        let size_check = check(&self.size, size, "size");
+        let mut check = quote!(#value_check #size_check);
+
+        for unwrap in unwrap.into_iter().rev() {
+            check = quote!(#unwrap { #check });
+        }
+
+        quote! {
+            {
+                let value = #value;
+                #check
+            }
         }
     }
 
+    fn has_explicit_tag(&self) -> bool {
+        matches!(self.tag, Some(Tag { explicit: true, .. }))
+    }
+
+    fn stripped_type(&self) -> syn::Type {
+        let mut ty = self.field.ty.clone();
+        ty.strip_lifetimes();
+        ty
+    }
+
     pub fn tag_derive(&self, context: usize) -> proc_macro2::TokenStream {
         if let Some(Tag {
             class,
@@ -560,11 +848,11 @@ impl<'a> FieldConfig<'a> {
 
     pub fn tag(&self, context: usize) -> proc_macro2::TokenStream {
         let crate_root = &self.container_config.crate_root;
-        if let Some(Tag { class, value, .. }) = &self.tag {
+        if let Some(tag) = &self.tag {
             if self.container_config.automatic_tags {
                 panic!("You can't use the `#[rasn(tag)]` with `#[rasn(automatic_tags)]`")
             }
-            quote!(#crate_root::Tag::new(#class, #value))
+            tag.to_tokens(crate_root)
         } else if self.container_config.automatic_tags {
             quote!(#crate_root::Tag::new(#crate_root::types::Class::Context, #context as u32))
         } else {
@@ -586,9 +874,152 @@ impl<'a> FieldConfig<'a> {
         }
     }
 
+    /// The name of the field, or its index if it's unnamed.
+    fn name(&self, context: usize) -> String {
+        self.field
+            .ident
+            .as_ref()
+            .map(|ident| ident.to_string())
+            .unwrap_or_else(|| context.to_string())
+    }
+
     pub fn is_option_type(&self) -> bool {
         self.container_config
             .option_type
             .is_option_type(&self.field.ty)
     }
 }
+
+/// Decodes each of the fields of a `SEQUENCE`.
+pub fn decode_sequence_fields(
+    name: &syn::Ident,
+    fields: &syn::Fields,
+    config: &Config,
+) -> Vec<proc_macro2::TokenStream> {
+    let fields: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| (i, FieldConfig::new(field, config)))
+        .collect();
+
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, (i, field))| {
+            let following: Vec<_> = fields[index + 1..]
+                .iter()
+                .filter(|(_, field)| {
+                    !field.is_option_type() && field.default.is_none() && !field.unknown_fields
+                })
+                .map(|(i, field)| field.tag_tree(*i))
+                .collect();
+
+            field.decode(name, *i, &following)
+        })
+        .collect()
+}
+
+/// Parses the range of a constraint attribute, e.g. `#[rasn(value("0..=9"))]`.
+fn parse_range(item: &syn::Meta, name: &str) -> syn::LitStr {
+    let range = match item {
+        syn::Meta::List(list) if list.nested.len() == 1 => match list.nested.first() {
+            Some(syn::NestedMeta::Lit(syn::Lit::Str(range))) => Some(range.clone()),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    range
+        .filter(|range| range.parse::<syn::ExprRange>().is_ok())
+        .unwrap_or_else(|| panic!("Expected a range, e.g. `#[rasn({}(\"0..=9\"))]`.", name))
+}
+
+/// Generates compile time checks that no two of a container's components
+/// that a decoder has to tell apart by their tag can have the same tag,
+/// naming both components if they do. In a `SET` that's every pair of
+/// fields, while in a `SEQUENCE` it's each run of consecutive `OPTIONAL` (or
+/// `DEFAULT`) fields along with the field that follows them, except for an
+/// `OPTIONAL` field and a mandatory field, which decoders tell apart by
+/// looking ahead at the rest of the components. As the look ahead only
+/// counts the mandatory fields, such an `OPTIONAL` field is also checked
+/// against every `OPTIONAL` or `DEFAULT` field after it.
+pub fn unique_field_tags(
+    name: &dyn core::fmt::Display,
+    fields: &syn::Fields,
+    config: &Config,
+) -> proc_macro2::TokenStream {
+    let fields: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| (i, FieldConfig::new(field, config)))
+        .filter(|(_, field)| !field.unknown_fields)
+        .collect();
+    let mut checks = Vec::new();
+    let mut optional_run: Vec<&(usize, FieldConfig)> = Vec::new();
+    // The `OPTIONAL` fields before the current run, which are told apart
+    // from the mandatory fields after them by looking ahead.
+    let mut looked_ahead: Vec<&(usize, FieldConfig)> = Vec::new();
+
+    for (index, component) in fields.iter().enumerate() {
+        let is_optional = component.1.is_option_type() || component.1.default.is_some();
+        let candidates: Vec<&(usize, FieldConfig)> = if config.set {
+            fields[..index].iter().collect()
+        } else if is_optional {
+            looked_ahead.iter().chain(&optional_run).copied().collect()
+        } else {
+            // The decoder looks ahead to tell whether an `OPTIONAL` field is
+            // present before a mandatory field with the same tag.
+            optional_run
+                .iter()
+                .copied()
+                .filter(|other| other.1.default.is_some())
+                .collect()
+        };
+
+        for other in candidates {
+            let message = format!(
+                "`{}`'s fields `{}` and `{}` can have the same tag, so they can't be told \
+                apart when decoding. Give one of them a different tag with `#[rasn(tag(...))]`.",
+                name,
+                other.1.name(other.0),
+                component.1.name(component.0),
+            );
+
+            checks.push(unique_tags(
+                &config.crate_root,
+                {
+                    let (a, b) = (other.1.tag_tree(other.0), component.1.tag_tree(component.0));
+                    quote!(#a, #b)
+                },
+                &message,
+            ));
+        }
+
+        if is_optional {
+            optional_run.push(component);
+        } else {
+            looked_ahead.extend(
+                optional_run
+                    .drain(..)
+                    .filter(|field| field.1.default.is_none()),
+            );
+        }
+    }
+
+    quote!(#(#checks)*)
+}
+
+/// Generates a compile time check that the comma separated tag trees in
+/// `tag_trees` don't share any tags, failing with `message` if they do.
+pub fn unique_tags(
+    crate_root: &syn::Path,
+    tag_trees: proc_macro2::TokenStream,
+    message: &str,
+) -> proc_macro2::TokenStream {
+    quote! {
+        const _: () = {
+            const LIST: &'static [#crate_root::TagTree] = &[#tag_trees];
+            assert!(#crate_root::TagTree::Choice(LIST).is_unique(), #message);
+        };
+    }
+}
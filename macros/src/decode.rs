@@ -8,7 +8,6 @@ pub fn derive_struct_impl(
     container: syn::DataStruct,
     config: &Config,
 ) -> proc_macro2::TokenStream {
-    let mut list = vec![];
     let crate_root = &config.crate_root;
     generics.add_trait_bounds(crate_root, quote::format_ident!("Decode"));
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -30,7 +29,38 @@ pub fn derive_struct_impl(
                 <#ty as #crate_root::Decode>::decode_with_tag(decoder, tag).map(Self)
             }
         }
+    } else if config.named_bits {
+        let positions = config.named_bit_positions(&container.fields);
+        let bits = positions
+            .iter()
+            .map(|position| quote!(#position < bits.len() && bits[#position]));
+        let fields = container.fields.iter().zip(bits).map(|(field, bit)| {
+            let lhs = field.ident.as_ref().map(|ident| quote!(#ident :));
+            quote!(#lhs #bit)
+        });
+
+        let fields = match container.fields {
+            Fields::Named(_) => quote!({ #(#fields),* }),
+            Fields::Unnamed(_) => quote!(( #(#fields),* )),
+            Fields::Unit => quote!(),
+        };
+
+        // Bits past the end of the string are unset, so shorter strings
+        // (such as from trimming trailing unset bits) decode as normal.
+        quote! {
+            #[allow(unused)]
+            let bits = decoder.decode_bit_string(tag)?;
+            Ok(Self #fields)
+        }
     } else if config.set {
+        if container
+            .fields
+            .iter()
+            .any(|field| FieldConfig::new(field, config).unknown_fields)
+        {
+            panic!("`#[rasn(unknown_fields)]` is not supported in a `SET`.");
+        }
+
         let field_names = container.fields.iter().map(|field| field.ident.clone());
         let field_names2 = field_names.clone();
         let field_names3 = field_names.clone();
@@ -63,6 +93,11 @@ pub fn derive_struct_impl(
             })
             .unzip();
 
+        let check_constraints = container.fields.iter().enumerate().map(|(i, field)| {
+            let ident = &field.ident;
+            FieldConfig::new(field, config).decode_constraints(&name, i, quote!(&#ident))
+        });
+
         let choice_name = quote::format_ident!("{}Fields", name);
 
         let choice_def = quote! {
@@ -97,25 +132,35 @@ pub fn derive_struct_impl(
                 }
 
                 #(let #required_field_names = #required_field_names.ok_or_else(|| #crate_root::de::Error::missing_field(stringify!(#required_field_names)))?;)*
+                #(#check_constraints)*
 
                 Ok(Self #set_init)
             })
         }
     } else {
+        let field_count = container.fields.len();
         for (i, field) in container.fields.iter().enumerate() {
             let field_config = FieldConfig::new(field, config);
 
-            list.push(field_config.decode(&name, i));
+            if field_config.unknown_fields && i + 1 != field_count {
+                panic!(
+                    "`#[rasn(unknown_fields)]` is only valid on the last field of a `SEQUENCE`."
+                );
+            }
         }
 
+        let list = decode_sequence_fields(&name, &container.fields, config);
+
         let fields = match container.fields {
             Fields::Named(_) => quote!({ #(#list),* }),
             Fields::Unnamed(_) => quote!(( #(#list),* )),
             Fields::Unit => quote!(),
         };
+        let check_order = check_sequence_order(&container.fields, config);
 
         quote! {
             decoder.decode_sequence(tag, |decoder| {
+                #check_order
                 Ok(Self #fields)
             })
         }
@@ -167,10 +212,9 @@ pub fn map_from_inner_type(
         quote!(#name : inner.#name)
     });
 
-    let decode_fields = fields.iter().enumerate().map(|(i, field)| {
-        let field_config = FieldConfig::new(field, config);
-        field_config.decode(name, i)
-    });
+    let decode_fields = decode_sequence_fields(name, fields, config);
+
+    let check_order = check_sequence_order(fields, config);
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
@@ -180,6 +224,7 @@ pub fn map_from_inner_type(
         impl #impl_generics #crate_root::Decode for #inner_name #ty_generics #where_clause {
             fn decode_with_tag<D: #crate_root::Decoder>(decoder: &mut D, tag: #crate_root::Tag) -> core::result::Result<Self, D::Error> {
                 decoder.decode_sequence(tag, |decoder| {
+                    #check_order
                     Ok::<_, D::Error>(#inner_name { #(#decode_fields),* })
                 })
             }
@@ -190,3 +235,30 @@ pub fn map_from_inner_type(
         Ok(#outer_name { #(#map_from_inner),* })
     }
 }
+
+/// Generates a call to `Decoder::check_sequence_order` with the tag tree of
+/// each field. When every field is mandatory, decoding each field in turn
+/// already fails on any other order, so the check (which has to scan the
+/// components a second time) is left out.
+fn check_sequence_order(fields: &Fields, config: &Config) -> proc_macro2::TokenStream {
+    let crate_root = &config.crate_root;
+    let is_fixed = fields.iter().all(|field| {
+        let field = FieldConfig::new(field, config);
+        !field.is_option_type() && field.default.is_none() && !field.unknown_fields
+    });
+
+    if is_fixed {
+        return quote!();
+    }
+
+    let tag_trees = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| (i, FieldConfig::new(field, config)))
+        .filter(|(_, field)| !field.unknown_fields)
+        .map(|(i, field)| field.tag_tree(i));
+
+    quote! {
+        #crate_root::Decoder::check_sequence_order(decoder, &[#(#tag_trees),*])?;
+    }
+}
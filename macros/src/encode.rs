@@ -11,7 +11,7 @@ pub fn derive_struct_impl(
         .fields
         .iter()
         .enumerate()
-        .map(|(i, field)| FieldConfig::new(field, config).encode(i, true))
+        .map(|(i, field)| FieldConfig::new(field, config).encode(&name, i, true))
         .collect();
 
     generics.add_trait_bounds(crate_root, quote::format_ident!("Encode"));
@@ -40,6 +40,28 @@ pub fn derive_struct_impl(
         } else {
             quote!(<#ty as #crate_root::Encode>::encode_with_tag(&self.0, encoder, tag))
         }
+    } else if config.named_bits {
+        let positions = config.named_bit_positions(&container.fields);
+        let length = positions.iter().max().map_or(0, |position| position + 1);
+        let fields = container.fields.iter().enumerate().map(|(i, field)| {
+            let i = syn::Index::from(i);
+            field
+                .ident
+                .as_ref()
+                .map(|ident| quote!(#ident))
+                .unwrap_or_else(|| quote!(#i))
+        });
+
+        quote! {
+            let mut bits = #crate_root::types::BitString::repeat(false, #length);
+            #(bits.set(#positions, self.#fields);)*
+
+            // Trailing unset bits are removed, as DER requires for a `BIT
+            // STRING` with named bits.
+            let length = bits.iter().rposition(|bit| *bit).map_or(0, |index| index + 1);
+            bits.truncate(length);
+            encoder.encode_bit_string(tag, &bits).map(drop)
+        }
     } else {
         let operation = config
             .set
@@ -18,10 +18,13 @@ impl Enum {
             .as_ref()
             .map(|t| t.to_tokens(crate_root))
             .unwrap_or_else(|| {
-                self.config
-                    .enumerated
-                    .then(|| quote!(#crate_root::Tag::ENUMERATED))
-                    .unwrap_or(quote!(#crate_root::Tag::EOC))
+                if self.config.enumerated {
+                    quote!(#crate_root::Tag::ENUMERATED)
+                } else if self.config.integer {
+                    quote!(#crate_root::Tag::INTEGER)
+                } else {
+                    quote!(#crate_root::Tag::EOC)
+                }
             });
 
         let error_message = format!(
@@ -30,17 +33,37 @@ impl Enum {
         );
 
         let tag_tree = if self.config.choice {
-            let field_tags = self
+            let variants: Vec<_> = self
                 .variants
                 .iter()
                 .enumerate()
-                .map(|(i, v)| VariantConfig::new(v, &self.generics, &self.config).tag_tree(i));
+                .map(|(i, v)| {
+                    (
+                        v,
+                        VariantConfig::new(v, &self.generics, &self.config).tag_tree(i),
+                    )
+                })
+                .collect();
+            let unique_variant_tags = variants.iter().enumerate().flat_map(|(i, (a, a_tags))| {
+                variants[i + 1..].iter().map(move |(b, b_tags)| {
+                    let message = format!(
+                        "`{}`'s variants `{}` and `{}` can have the same tag, so they can't be \
+                        told apart when decoding. Give one of them a different tag with \
+                        `#[rasn(tag(...))]`.",
+                        self.name, a.ident, b.ident,
+                    );
+
+                    // Variant tag trees already end with a comma.
+                    unique_tags(crate_root, quote!(#a_tags #b_tags), &message)
+                })
+            });
+            let field_tags = variants.iter().map(|(_, tags)| tags);
 
             quote! {
                 {
+                    #(#unique_variant_tags)*
                     const VARIANT_LIST: &'static [#crate_root::TagTree] = &[#(#field_tags)*];
                     const VARIANT_TAG_TREE: #crate_root::TagTree = #crate_root::TagTree::Choice(VARIANT_LIST);
-                    const _: () = assert!(VARIANT_TAG_TREE.is_unique(), #error_message);
                     VARIANT_TAG_TREE
                 }
             }
@@ -89,21 +112,56 @@ impl Enum {
         let mut generics = self.generics.clone();
         generics.add_trait_bounds(&self.config.crate_root, quote::format_ident!("Decode"));
         let decode_with_tag = if self.config.enumerated {
-            let variants = self.variants.iter().map(|v| {
+            let discriminants = self.variants.iter().map(|v| {
+                let ident = &v.ident;
+                quote!(Self::#ident as i128)
+            });
+            let variants = self.variants.iter().enumerate().map(|(i, v)| {
                 let ident = &v.ident;
-                quote!(i if i == (Self::#ident as isize).into() => Self::#ident,)
+                quote!(#i => Ok(Self::#ident),)
             });
 
+            let message = format!("Index `{{}}` isn't a variant of `{}`.", self.name);
+
             quote! {
-                let integer = decoder.decode_enumerated(tag)?;
+                let discriminants: &[i128] = &[#(#discriminants),*];
 
-                Ok(match integer {
+                match decoder.decode_enumerated_in(tag, discriminants)? {
                     #(#variants)*
-                    _ => return Err(#crate_root::de::Error::custom("Invalid enumerated disrciminant."))
-                })
+                    index => Err(#crate_root::de::Error::custom(format_args!(#message, index))),
+                }
+            }
+        } else if self.config.integer {
+            let (named, catch_all) = self.integer_variants();
+            let named = named.iter().map(|(ident, value)| {
+                quote! {
+                    if integer == #crate_root::types::Integer::from((#value) as i128) {
+                        return Ok(Self::#ident);
+                    }
+                }
+            });
+            let unnamed = match catch_all {
+                Some(ident) => quote! {
+                    core::convert::TryFrom::try_from(integer)
+                        .map(Self::#ident)
+                        .map_err(#crate_root::de::Error::custom)
+                },
+                None => {
+                    let message = format!("Integer `{{}}` isn't a named value of `{}`.", self.name);
+                    quote!(Err(#crate_root::de::Error::custom(format_args!(#message, integer))))
+                }
+            };
 
+            quote! {
+                let integer = decoder.decode_integer(tag)?;
+                #(#named)*
+                #unnamed
             }
         } else {
+            // A `CHOICE` can't be implicitly tagged, as its tag is what
+            // identifies the variant, so any tag it's decoded with (such as
+            // from a tagged field) is treated as explicit. Untagged choices
+            // are decoded through `decode` below instead.
             quote!(decoder.decode_explicit_prefix(tag))
         };
 
@@ -198,6 +256,25 @@ impl Enum {
             quote! {
                 encoder.encode_enumerated(tag, *self as isize).map(drop)
             }
+        } else if self.config.integer {
+            let (named, catch_all) = self.integer_variants();
+            let named = named.iter().map(|(ident, value)| {
+                quote! {
+                    Self::#ident => encoder
+                        .encode_integer(tag, &#crate_root::types::Integer::from((#value) as i128))
+                        .map(drop),
+                }
+            });
+            let catch_all = catch_all.map(|ident| {
+                quote!(Self::#ident(value) => #crate_root::Encode::encode_with_tag(value, encoder, tag),)
+            });
+
+            quote! {
+                match self {
+                    #(#named)*
+                    #catch_all
+                }
+            }
         } else {
             quote! {
                 encoder.encode_explicit_prefix(tag, self).map(drop)
@@ -211,6 +288,31 @@ impl Enum {
         }
     }
 
+    /// The named values of an `#[rasn(integer)]` enum, from the discriminants
+    /// of its unit variants, and its catch-all variant for any other value,
+    /// if it has one.
+    fn integer_variants(&self) -> (Vec<(&syn::Ident, &syn::Expr)>, Option<&syn::Ident>) {
+        let mut named = Vec::new();
+        let mut catch_all = None;
+
+        for variant in &self.variants {
+            match (&variant.fields, &variant.discriminant) {
+                (syn::Fields::Unit, Some((_, value))) => named.push((&variant.ident, value)),
+                (syn::Fields::Unnamed(fields), None)
+                    if fields.unnamed.len() == 1 && catch_all.is_none() =>
+                {
+                    catch_all = Some(&variant.ident)
+                }
+                _ => panic!(
+                    "`#[rasn(integer)]` enums can only have unit variants with a value (e.g. \
+                    `V1 = 0`), and one single field variant for any other value."
+                ),
+            }
+        }
+
+        (named, catch_all)
+    }
+
     fn encode(&self, generics: &syn::Generics) -> Option<proc_macro2::TokenStream> {
         if self.config.choice {
             Some(self.encode_choice(&generics))
@@ -236,7 +338,7 @@ impl Enum {
 
                     let fields = v.fields.iter().enumerate().map(|(i, f)| {
                         let field_config = FieldConfig::new(f, &self.config);
-                        field_config.encode(i, false)
+                        field_config.encode(name, i, false)
                     });
 
                     let encode_impl = |tag| {
@@ -80,6 +80,57 @@ pub fn encode_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 /// and generate a *compile-time* check that all of your fields (if struct) or
 /// variants (if a choice style enum) have distinct tags.
 ///
+/// Structs are a `SEQUENCE` (or a `SET`) of their fields, so a struct with no
+/// fields (e.g. `struct Empty;`) is an empty `SEQUENCE`, rather than the `NULL`
+/// that `()` is.
+///
+/// An `Option` field is `OPTIONAL`, and decodes as `None` if the next value
+/// doesn't have its tag, or if there are no values left in the `SEQUENCE`. So
+/// a `SEQUENCE` that grows over versions by appending `OPTIONAL` fields still
+/// decodes the shorter encodings of earlier versions.
+///
+/// In a `SEQUENCE`, consecutive `OPTIONAL` fields can't have the same tag,
+/// and neither can a `DEFAULT` field and the field after it, as a decoder
+/// couldn't tell which of them a value belongs to. The error names both
+/// fields. An `OPTIONAL` field can have the same tag as the mandatory field
+/// after it, as the decoder looks ahead to check that the mandatory field's
+/// value still follows, but only if no `OPTIONAL` or `DEFAULT` field after it
+/// can have the same tag either.
+/// ```
+/// #[derive(rasn::AsnType)]
+/// struct Version {
+///     major: Option<u8>,
+///     minor: u8,
+/// }
+/// ```
+/// ```compile_fail,E0080
+/// // error: `Version`'s fields `major` and `minor` can have the same tag, so
+/// // they can't be told apart when decoding. Give one of them a different
+/// // tag with `#[rasn(tag(...))]`.
+/// #[derive(rasn::AsnType)]
+/// struct Version {
+///     major: Option<u8>,
+///     minor: Option<u8>,
+/// }
+/// ```
+/// ```compile_fail,E0080
+/// #[derive(rasn::AsnType)]
+/// struct Version {
+///     #[rasn(default)]
+///     major: u8,
+///     minor: u8,
+/// }
+/// ```
+/// ```compile_fail,E0080
+/// // `{ a: None, b: 5, c: Some(6) }` would decode as `{ a: Some(5), b: 6 }`.
+/// #[derive(rasn::AsnType)]
+/// struct S {
+///     a: Option<i32>,
+///     b: i32,
+///     c: Option<i32>,
+/// }
+/// ```
+///
 /// ##### Shared Attributes
 /// These attributes are available on containers, variants, and fields.
 /// - *`tag([class], number)`* — override the default tag with the one
@@ -89,9 +140,40 @@ pub fn encode_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 ///
 /// ##### Container Attributes
 /// - `crate_root` The path to the `rasn` library to use in the macro.
-/// - `enumerated/choice` Use either `#[rasn(choice)]` or `#[rasn(enumerated)]`
+/// - `enumerated/choice` Use either `#[rasn(choice)]` or `#[rasn(enumerated)]`.
+///   As a `CHOICE` has no tag of its own, a derived choice decodes by trying
+///   each of its variants, and any tag applied to it (e.g. with
+///   `#[rasn(tag(0))]` on a field) is always encoded and decoded as explicit.
+///   A derived enumerated's values are its variants' discriminants, including
+///   those of a `#[repr(u8)]` (or other `repr`) enum, and decoding any other
+///   value fails.
+/// - `integer` For an `INTEGER` with named values, such as X.509's
+///   `Version ::= INTEGER { v1(0), v2(1), v3(2) }`. The named values are the
+///   discriminants of the enum's unit variants, and unlike an enumerated, any
+///   other value is decoded into its single field variant (e.g.
+///   `Other(i64)`), if it has one. A catch-all holding a named value is
+///   decoded as the named variant.
 /// - `delegate` Only available for newtype wrappers (e.g. `struct Delegate(T)`);
 /// uses the inner `T` type for implementing the trait.
+/// - `named_bits` Only available for structs of `bool` fields; encodes the
+///   struct as a `BIT STRING`, with each field as a bit numbered in
+///   declaration order, or from `#[rasn(bit = 5)]` on the field.
+///
+/// ##### Field Attributes
+/// - `default` Marks the field as `DEFAULT`, so it's left out when encoding if
+///   it's equal to its `Default` value (or the value returned by the function
+///   given with `#[rasn(default = "path")]`), and takes that value when it's
+///   absent while decoding. An `Option` field is `OPTIONAL` instead, and
+///   can't also be `default`.
+/// - `value("range")` Constrains the field's value to a Rust range expression
+///   (e.g. `#[rasn(value("0..=9"))]`), which is checked when encoding and
+///   decoding the field.
+/// - `size("range")` Constrains the field's size (as given by its `len`, or
+///   its number of characters for `String`, `Utf8String`, and `ArrayString`)
+///   to a Rust range expression, e.g. `#[rasn(size("1..=64"))]`.
+///
+/// Constraints apply to the type inside of an `Option` or `Box`, so an absent
+/// optional field always satisfies them.
 #[proc_macro_derive(AsnType, attributes(rasn))]
 pub fn asn_type_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     // Parse the input tokens into a syntax tree
@@ -8,9 +8,139 @@ mod rules;
 pub use identifier::Identifier;
 pub(crate) use rules::EncodingRules;
 
-/// Attempts to decode `T` from `input` using BER.
+/// Attempts to decode `T` from `input` using BER, failing if there's any
+/// input left after the value.
 pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, de::Error> {
-    T::decode(&mut de::Decoder::new(input, de::DecoderOptions::ber()))
+    decode_with_options(input, de::DecoderOptions::ber())
+}
+
+/// Attempts to decode `T` from the start of `input` using BER, ignoring any
+/// input left after the value.
+pub fn decode_partial<T: crate::Decode>(input: &[u8]) -> Result<T, de::Error> {
+    decode_with_options(input, de::DecoderOptions::ber().require_complete(false))
+}
+
+/// Attempts to decode `T` from `input` using `options`, failing if there's any
+/// input left after the value unless
+/// [`require_complete`][de::DecoderOptions::require_complete] is turned off.
+/// ```
+/// use rasn::ber::de::DecoderOptions;
+///
+/// let padded = [0x02, 0x01, 0x05, 0x00, 0x00];
+/// assert!(rasn::ber::decode::<u8>(&padded).is_err());
+///
+/// let options = DecoderOptions::der().require_complete(false);
+/// assert_eq!(5, rasn::ber::decode_with_options::<u8>(&padded, options).unwrap());
+/// ```
+pub fn decode_with_options<T: crate::Decode>(
+    input: &[u8],
+    options: de::DecoderOptions,
+) -> Result<T, de::Error> {
+    let mut decoder = de::Decoder::new(input, options);
+    let value = T::decode(&mut decoder)?;
+
+    match input.len() - decoder.decoded_len() {
+        length if length > 0 && options.require_complete => {
+            Err(de::Error::UnexpectedExtraData { length })
+        }
+        _ => Ok(value),
+    }
+}
+
+/// Attempts to decode `T` from `input` using BER, borrowing from `input` where
+/// `T` can, such as for a `&str`. Fails if there's any input left after the
+/// value.
+/// ```
+/// let input = [0x0C, 0x02, b'h', b'i'];
+/// assert_eq!("hi", rasn::ber::decode_borrowed::<&str>(&input).unwrap());
+/// ```
+pub fn decode_borrowed<'de, T: crate::de::DecodeBorrowed<'de>>(
+    input: &'de [u8],
+) -> Result<T, de::Error> {
+    decode_borrowed_with_options(input, de::DecoderOptions::ber())
+}
+
+pub(crate) fn decode_borrowed_with_options<'de, T: crate::de::DecodeBorrowed<'de>>(
+    input: &'de [u8],
+    options: de::DecoderOptions,
+) -> Result<T, de::Error> {
+    let mut decoder = de::Decoder::new(input, options);
+    let value = T::decode_borrowed(&mut decoder)?;
+
+    match input.len() - decoder.decoded_len() {
+        0 => Ok(value),
+        length => Err(de::Error::UnexpectedExtraData { length }),
+    }
+}
+
+/// Attempts to decode `T` from the start of `input` using BER, returning the
+/// value along with the number of bytes it was encoded in, so that decoding
+/// can continue with the next value in `input`.
+/// ```
+/// let input = [0x30, 0x80, 0x02, 0x01, 0x05, 0x00, 0x00, 0x01, 0x01, 0xFF];
+///
+/// let (value, consumed) = rasn::ber::decode_with_consumed::<Vec<u8>>(&input).unwrap();
+/// assert_eq!((vec![5], 7), (value, consumed));
+/// assert!(rasn::ber::decode::<bool>(&input[consumed..]).unwrap());
+/// ```
+pub fn decode_with_consumed<T: crate::Decode>(input: &[u8]) -> Result<(T, usize), de::Error> {
+    let mut decoder = de::Decoder::new(input, de::DecoderOptions::ber());
+    let value = T::decode(&mut decoder)?;
+
+    Ok((value, decoder.position()))
+}
+
+/// Attempts to decode every `T` from `input` using BER, such as a
+/// concatenation of values, until `input` is exhausted. The error for any
+/// value that can't be decoded, including trailing bytes that don't form a
+/// complete value, is wrapped in [`de::Error::ElementError`] with its index.
+pub fn decode_all<T: crate::Decode>(input: &[u8]) -> Result<alloc::vec::Vec<T>, de::Error> {
+    decode_all_with_options(input, de::DecoderOptions::ber())
+}
+
+pub(crate) fn decode_all_with_options<T: crate::Decode>(
+    input: &[u8],
+    options: de::DecoderOptions,
+) -> Result<alloc::vec::Vec<T>, de::Error> {
+    let mut decoder = de::Decoder::new(input, options);
+    let mut values = alloc::vec::Vec::new();
+
+    while decoder.decoded_len() < input.len() {
+        let value = T::decode(&mut decoder).map_err(|error| de::Error::ElementError {
+            index: values.len(),
+            error: alloc::boxed::Box::new(error),
+        })?;
+        values.push(value);
+    }
+
+    Ok(values)
+}
+
+/// Attempts to validate that `input` is a single BER encoded value matching
+/// `schema`.
+pub fn validate(input: &[u8], schema: &crate::schema::Schema) -> Result<(), de::ValidationError> {
+    let mut decoder = de::Decoder::new(input, de::DecoderOptions::ber());
+    decoder.validate(schema)?;
+
+    match input.len() - decoder.decoded_len() {
+        0 => Ok(()),
+        length => Err(de::Error::UnexpectedExtraData { length }.into()),
+    }
+}
+
+/// Compares the encodings of two elements of a `SET OF` for CER and DER, as
+/// octet strings with the shorter one padded with zero octets at its end
+/// (X.690 11.6), so that e.g. `01` and `01 00` are equal. No complete
+/// encoding is a prefix of another, so elements are in the same order as
+/// when comparing the slices directly.
+pub(crate) fn compare_set_of_elements(a: &[u8], b: &[u8]) -> core::cmp::Ordering {
+    let padding = core::iter::repeat(&0);
+    let length = a.len().max(b.len());
+
+    a.iter()
+        .chain(padding.clone())
+        .take(length)
+        .cmp(b.iter().chain(padding).take(length))
 }
 
 /// Attempts to encode `value` to BER.
@@ -49,6 +179,61 @@ mod tests {
         assert_eq!(false, decode::<bool>(&encode(&false).unwrap()).unwrap());
     }
 
+    #[test]
+    fn real() {
+        let values = [
+            1.0,
+            -1.5,
+            0.1,
+            1e300,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            f64::from_bits(1),
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ];
+
+        for value in values {
+            assert_eq!(value, decode::<f64>(&encode(&value).unwrap()).unwrap());
+            assert_eq!(
+                value,
+                crate::der::decode::<f64>(&crate::der::encode(&value).unwrap()).unwrap()
+            );
+        }
+
+        for value in [0.1f32, -3.25, f32::MAX, f32::from_bits(1)] {
+            assert_eq!(value, decode::<f32>(&encode(&value).unwrap()).unwrap());
+        }
+
+        assert_eq!(&[0x09, 0x03, 0x80, 0x00, 0x01][..], &*encode(&1.0).unwrap());
+        assert_eq!(
+            &[0x09, 0x03, 0xC0, 0xFF, 0x03][..],
+            &*encode(&-1.5).unwrap()
+        );
+        assert_eq!(&[0x09, 0x00][..], &*encode(&0.0).unwrap());
+        assert_eq!(&[0x09, 0x01, 0x43][..], &*encode(&-0.0).unwrap());
+        assert_eq!(&[0x09, 0x01, 0x42][..], &*encode(&f64::NAN).unwrap());
+        assert!(decode::<f64>(&[0x09, 0x00]).unwrap().is_sign_positive());
+        assert!(decode::<f64>(&[0x09, 0x01, 0x43])
+            .unwrap()
+            .is_sign_negative());
+        assert!(decode::<f64>(&[0x09, 0x01, 0x42]).unwrap().is_nan());
+
+        // Forms that only BER allows: a decimal, base 16, and an even mantissa.
+        let decimal = [0x09, 0x04, 0x02, b'1', b',', b'5'];
+        let base_16 = [0x09, 0x03, 0xA0, 0x01, 0x01];
+        let even = [0x09, 0x03, 0x80, 0xFF, 0x02];
+        assert_eq!(1.5, decode::<f64>(&decimal).unwrap());
+        assert_eq!(16.0, decode::<f64>(&base_16).unwrap());
+        assert_eq!(1.0, decode::<f64>(&even).unwrap());
+        assert!(crate::der::decode::<f64>(&base_16).is_err());
+        assert!(crate::der::decode::<f64>(&even).is_err());
+
+        assert!(decode::<f64>(&[0x09, 0x02, 0x80, 0x00]).is_err());
+        assert!(decode::<f64>(&[0x09, 0x02, 0x40, 0x00]).is_err());
+        assert!(decode::<f64>(&[0x09, 0x04, 0x02, b'i', b'n', b'f']).is_err());
+    }
+
     macro_rules! integer_tests {
         ($($integer:ident),*) => {
             $(
@@ -79,6 +264,31 @@ mod tests {
         usize
     }
 
+    macro_rules! non_zero_integer_tests {
+        ($($name:ident : $non_zero:ty),*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let one = <$non_zero>::new(1).unwrap();
+                    let max = <$non_zero>::new(<$non_zero>::MAX.get()).unwrap();
+
+                    assert_eq!(one, decode::<$non_zero>(&encode(&one).unwrap()).unwrap());
+                    assert_eq!(max, decode::<$non_zero>(&encode(&max).unwrap()).unwrap());
+                    assert!(decode::<$non_zero>(&[0x02, 0x01, 0x00]).is_err());
+                }
+            )*
+        }
+    }
+
+    non_zero_integer_tests! {
+        non_zero_i8: core::num::NonZeroI8,
+        non_zero_i64: core::num::NonZeroI64,
+        non_zero_u8: core::num::NonZeroU8,
+        non_zero_u16: core::num::NonZeroU16,
+        non_zero_u32: core::num::NonZeroU32,
+        non_zero_u64: core::num::NonZeroU64
+    }
+
     #[test]
     fn octet_string() {
         let a = OctetString::from(vec![1u8, 2, 3, 4, 5]);
@@ -88,6 +298,254 @@ mod tests {
         assert_eq!(b, decode::<OctetString>(&encode(&b).unwrap()).unwrap());
     }
 
+    #[test]
+    fn ip_addr() {
+        use core::net::{Ipv4Addr, Ipv6Addr};
+
+        let v4 = Ipv4Addr::new(192, 0, 2, 1);
+        let encoded = encode(&v4).unwrap();
+        assert_eq!(&[0x04, 0x04, 192, 0, 2, 1][..], &*encoded);
+        assert_eq!(v4, decode::<Ipv4Addr>(&encoded).unwrap());
+
+        let v6 = Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 1);
+        let encoded = encode(&v6).unwrap();
+        assert_eq!(&[0x04, 0x10, 0x20, 0x01, 0x0D, 0xB8][..], &encoded[..6]);
+        assert_eq!(v6, decode::<Ipv6Addr>(&encoded).unwrap());
+
+        assert!(decode::<Ipv4Addr>(&[0x04, 0x03, 192, 0, 2]).is_err());
+        assert!(decode::<Ipv6Addr>(&encode(&v4).unwrap()).is_err());
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid() {
+        let uuid = uuid::Uuid::from_u128(0x0011_2233_4455_6677_8899_AABB_CCDD_EEFF);
+        let encoded = encode(&uuid).unwrap();
+
+        assert_eq!(&[0x04, 0x10, 0x00, 0x11, 0x22], &encoded[..5]);
+        assert_eq!(uuid, decode::<uuid::Uuid>(&encoded).unwrap());
+        assert!(decode::<uuid::Uuid>(&[0x04, 0x02, 0x00, 0x11]).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn offset_date_time() {
+        use time::{Date, Month, OffsetDateTime, UtcOffset};
+
+        let india = UtcOffset::from_hms(5, 30, 0).unwrap();
+        let time = Date::from_calendar_date(2023, Month::October, 14)
+            .unwrap()
+            .with_hms_milli(12, 34, 56, 250)
+            .unwrap()
+            .assume_offset(india);
+
+        let ber = b"\x18\x1620231014123456.25+0530";
+        assert_eq!(&ber[..], &*encode(&time).unwrap());
+        let decoded: OffsetDateTime = decode(ber).unwrap();
+        assert_eq!((time, india), (decoded, decoded.offset()));
+
+        let der = b"\x18\x1220231014070456.25Z";
+        assert_eq!(&der[..], &*crate::der::encode(&time).unwrap());
+        let decoded: OffsetDateTime = crate::der::decode(der).unwrap();
+        assert_eq!((time, UtcOffset::UTC), (decoded, decoded.offset()));
+        assert!(crate::der::decode::<OffsetDateTime>(ber).is_err());
+    }
+
+    #[test]
+    fn set_of_sorted_by_encoding() {
+        let set: SetOf<i8> = vec![-1, 1].into_iter().collect();
+        let sorted = [0x31, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0xFF];
+
+        assert_eq!(&sorted[..], &*crate::der::encode(&set).unwrap());
+        assert_eq!(set, crate::der::decode(&sorted).unwrap());
+        // BER keeps the set's own order.
+        assert_eq!(
+            &[0x31, 0x06, 0x02, 0x01, 0xFF, 0x02, 0x01, 0x01][..],
+            &*encode(&set).unwrap()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_set() {
+        use std::collections::HashSet;
+
+        let set: HashSet<u16> = vec![0x1FF, 2, 3].into_iter().collect();
+        let encoded = crate::der::encode(&set).unwrap();
+
+        assert_eq!(
+            &[0x31, 0x0A, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03, 0x02, 0x02, 0x01, 0xFF][..],
+            &*encoded
+        );
+        assert_eq!(set, crate::der::decode(&encoded).unwrap());
+        assert_eq!(set, decode(&encode(&set).unwrap()).unwrap());
+
+        // BER allows duplicates, which are merged.
+        let duplicated = [0x31, 0x06, 0x02, 0x01, 0x02, 0x02, 0x01, 0x02];
+        let decoded: HashSet<u16> = decode(&duplicated).unwrap();
+        assert_eq!(vec![2].into_iter().collect::<HashSet<_>>(), decoded);
+    }
+
+    #[test]
+    fn vec_deque() {
+        use alloc::collections::VecDeque;
+
+        #[derive(crate::AsnType, crate::Decode, crate::Encode, Debug, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Queue {
+            #[rasn(size("..=3"))]
+            jobs: VecDeque<u8>,
+        }
+
+        // Wrap around the end of the buffer so the deque isn't contiguous.
+        let mut jobs = VecDeque::with_capacity(3);
+        jobs.extend([0, 1, 2]);
+        jobs.pop_front();
+        jobs.push_back(3);
+        assert_eq!(2, jobs.as_slices().0.len());
+
+        let encoded = encode(&jobs).unwrap();
+        assert_eq!(encode(&alloc::vec![1u8, 2, 3]).unwrap(), encoded);
+        assert_eq!(jobs, decode::<VecDeque<u8>>(&encoded).unwrap());
+
+        let queue = Queue { jobs };
+        assert_eq!(queue, decode(&encode(&queue).unwrap()).unwrap());
+
+        let mut jobs = queue.jobs;
+        jobs.push_front(0);
+        assert!(encode(&Queue { jobs: jobs.clone() }).is_err());
+
+        let encoded = encode(&jobs).unwrap();
+        let mut too_long = alloc::vec![0x30, encoded.len() as u8];
+        too_long.extend_from_slice(&encoded);
+        assert!(decode::<Queue>(&too_long).is_err());
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn array_string() {
+        type Name = arrayvec::ArrayString<8>;
+
+        #[derive(crate::AsnType, crate::Decode, crate::Encode, Debug, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Device {
+            #[rasn(size("..=3"))]
+            name: arrayvec::ArrayString<8>,
+        }
+
+        let name = Name::from("Jones").unwrap();
+        let encoded = encode(&name).unwrap();
+        assert_eq!(encode(&"Jones").unwrap(), encoded);
+        assert_eq!(name, decode::<Name>(&encoded).unwrap());
+        assert!(decode::<Name>(&encode(&"Jones Jr.").unwrap()).is_err());
+
+        // "äöü" is six bytes, but only three characters.
+        let device = Device {
+            name: Name::from("äöü").unwrap(),
+        };
+        assert_eq!(device, decode(&encode(&device).unwrap()).unwrap());
+        assert!(encode(&Device { name }).is_err());
+        assert!(decode::<Device>(&[0x30, 0x06, 0x0C, 0x04, 0x4A, 0x6F, 0x6E, 0x65]).is_err());
+    }
+
+    #[test]
+    fn set_of_element_order() {
+        use core::cmp::Ordering;
+
+        assert_eq!(Ordering::Equal, compare_set_of_elements(&[1], &[1, 0]));
+        assert_eq!(Ordering::Less, compare_set_of_elements(&[1], &[1, 1]));
+        assert_eq!(Ordering::Greater, compare_set_of_elements(&[2], &[1, 5]));
+        assert_eq!(Ordering::Less, compare_set_of_elements(&[], &[1]));
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn smallvec() {
+        type Digits = smallvec::SmallVec<[u8; 4]>;
+
+        #[derive(crate::AsnType, crate::Decode, crate::Encode, Debug, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Pin {
+            #[rasn(size("1..=4"))]
+            digits: Digits,
+        }
+
+        let inline = Digits::from_slice(&[1, 2, 3]);
+        let encoded = encode(&inline).unwrap();
+        assert_eq!(encode(&inline.to_vec()).unwrap(), encoded);
+
+        let decoded = decode::<Digits>(&encoded).unwrap();
+        assert_eq!(inline, decoded);
+        assert!(!decoded.spilled());
+
+        let spilled = Digits::from_slice(&[1, 2, 3, 4, 5]);
+        let encoded = encode(&spilled).unwrap();
+        assert!(decode::<Digits>(&encoded).unwrap().spilled());
+
+        let pin = Pin { digits: inline };
+        assert_eq!(pin, decode(&encode(&pin).unwrap()).unwrap());
+        assert!(encode(&Pin {
+            digits: spilled.clone()
+        })
+        .is_err());
+
+        let mut too_long = vec![0x30, encoded.len() as u8];
+        too_long.extend_from_slice(&encoded);
+        assert!(decode::<Pin>(&too_long).is_err());
+
+        let nulls = [0x30, 0x06, 0x05, 0x00, 0x05, 0x00, 0x05, 0x00];
+        let options = de::DecoderOptions::ber().max_output_elements(2);
+        let mut decoder = de::Decoder::new(&nulls, options);
+        assert!(<smallvec::SmallVec<[(); 4]> as crate::Decode>::decode(&mut decoder).is_err());
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn heapless_vec_output_limits() {
+        let nulls = [0x30, 0x06, 0x05, 0x00, 0x05, 0x00, 0x05, 0x00];
+        assert_eq!(3, decode::<heapless::Vec<(), 4>>(&nulls).unwrap().len());
+
+        let options = de::DecoderOptions::ber().max_output_elements(2);
+        let mut decoder = de::Decoder::new(&nulls, options);
+        assert!(<heapless::Vec<(), 4> as crate::Decode>::decode(&mut decoder).is_err());
+    }
+
+    #[test]
+    fn trailing_data() {
+        let padded = &[0x01, 0x01, 0xFF, 0x00, 0x00, 0x00][..];
+
+        assert!(matches!(
+            decode::<bool>(padded),
+            Err(de::Error::UnexpectedExtraData { length: 3 })
+        ));
+        assert!(crate::cer::decode::<bool>(padded).is_err());
+        assert!(crate::der::decode::<bool>(padded).is_err());
+
+        assert!(decode_partial::<bool>(padded).unwrap());
+        assert!(crate::cer::decode_partial::<bool>(padded).unwrap());
+        assert!(crate::der::decode_partial::<bool>(padded).unwrap());
+        assert!(decode::<bool>(&padded[..3]).unwrap());
+    }
+
+    #[test]
+    fn decode_with_consumed() {
+        let nested_indefinite = &[
+            0x30, 0x80, 0x30, 0x80, 0x02, 0x01, 0x01, 0x00, 0x00, 0x30, 0x03, 0x02, 0x01, 0x02,
+            0x00, 0x00,
+        ][..];
+        let mut input = nested_indefinite.to_vec();
+        input.extend_from_slice(&[0x04, 0x01, 0xAA]);
+
+        let (value, consumed) = super::decode_with_consumed::<Vec<Vec<u8>>>(&input).unwrap();
+        assert_eq!(vec![vec![1], vec![2]], value);
+        assert_eq!(nested_indefinite.len(), consumed);
+
+        let (value, consumed) =
+            super::decode_with_consumed::<OctetString>(&input[consumed..]).unwrap();
+        assert_eq!(&[0xAA][..], &*value);
+        assert_eq!(3, consumed);
+    }
+
     #[test]
     fn utf8_string() {
         let name = "Jones";
@@ -95,6 +553,124 @@ mod tests {
             name,
             decode::<Utf8String>(&*encode(&name).unwrap()).unwrap()
         );
+
+        let owned = alloc::string::String::from(name);
+        let encoded = encode(&owned).unwrap();
+        assert_eq!(&[0x0C, 0x05, b'J', b'o', b'n', b'e', b's'], &*encoded);
+        assert_eq!(encoded, encode(&name).unwrap());
+        assert_eq!(owned, decode::<alloc::string::String>(&encoded).unwrap());
+    }
+
+    #[test]
+    fn utc_time_years() {
+        let utc = |year| chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, year, 1, 1, 0, 0, 0);
+
+        for (year, encoded) in [
+            (1950, b"\x17\x0D500101000000Z"),
+            (2049, b"\x17\x0D490101000000Z"),
+        ] {
+            let time = utc(year).unwrap();
+            assert_eq!(encoded[..], *encode(&time).unwrap());
+            assert_eq!(time, decode::<UtcTime>(encoded).unwrap());
+        }
+
+        // Years outside of the pivot window can't be decoded back.
+        for year in [1949, 2050, 2150] {
+            assert!(encode(&utc(year).unwrap()).is_err());
+        }
+    }
+
+    #[test]
+    fn date_and_time() {
+        let date = Date::from_ymd_opt(2021, 2, 28).unwrap();
+        let time = TimeOfDay::from_hms_opt(13, 5, 9).unwrap();
+        let date_time = date.and_time(time);
+        let duration = Duration {
+            days: 1,
+            hours: 12,
+            ..<_>::default()
+        };
+
+        let encoded = encode(&date).unwrap();
+        assert_eq!(b"\x1F\x1F\x0820210228"[..], *encoded);
+        assert_eq!(date, decode::<Date>(&encoded).unwrap());
+
+        let encoded = encode(&time).unwrap();
+        assert_eq!(b"\x1F\x20\x06130509"[..], *encoded);
+        assert_eq!(time, decode::<TimeOfDay>(&encoded).unwrap());
+
+        let encoded = encode(&date_time).unwrap();
+        assert_eq!(b"\x1F\x21\x0E20210228130509"[..], *encoded);
+        assert_eq!(date_time, decode::<DateTime>(&encoded).unwrap());
+
+        let fractional = TimeOfDay::from_hms_milli_opt(13, 5, 9, 500).unwrap();
+        assert!(encode(&fractional).is_err());
+        assert!(encode(&date.and_time(fractional)).is_err());
+        let leap_second = TimeOfDay::from_hms_nano_opt(23, 59, 59, 1_000_000_000).unwrap();
+        assert!(encode(&leap_second).is_err());
+
+        let encoded = encode(&duration).unwrap();
+        assert_eq!(b"\x1F\x22\x07P1DT12H"[..], *encoded);
+        assert_eq!(duration, decode::<Duration>(&encoded).unwrap());
+
+        for invalid in &[
+            &b"\x1F\x1F\x0820211301"[..],
+            b"\x1F\x1F\x0A2021-02-28",
+            b"\x1F\x1F\x06210228",
+            b"\x1F\x1F\x08+2021022",
+            b"\x1F\x1F\x0820210230",
+        ] {
+            assert!(decode::<Date>(invalid).is_err());
+        }
+        for invalid in &[
+            &b"\x1F\x20\x06240000"[..],
+            b"\x1F\x20\x0813:05:09",
+            b"\x1F\x20\x06+30509",
+        ] {
+            assert!(decode::<TimeOfDay>(invalid).is_err());
+        }
+        for invalid in &[
+            &b"\x1F\x21\x0E20210230130509"[..],
+            b"\x1F\x21\x132021-02-28T13:05:09",
+            b"\x1F\x21\x0F20210228T130509",
+        ] {
+            assert!(decode::<DateTime>(invalid).is_err());
+        }
+        assert!(decode::<Duration>(b"\x1F\x22\x03P1H").is_err());
+    }
+
+    #[test]
+    fn leap_days() {
+        for (year, is_leap) in &[(2024, true), (2023, false), (2000, true), (1900, false)] {
+            let encoded = alloc::format!("\x1F\x1F\x08{}0229", year);
+            let decoded = decode::<Date>(encoded.as_bytes());
+
+            assert_eq!(*is_leap, decoded.is_ok(), "{}", year);
+            if let Ok(date) = decoded {
+                assert_eq!(Date::from_ymd_opt(*year, 2, 29).unwrap(), date);
+                assert_eq!(encoded.as_bytes(), &*encode(&date).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn oid_iri() {
+        let iri = OidIri::new("/ISO/Registration_Authority/19785.CBEFF").unwrap();
+        let relative = RelativeOidIri::new("Organizations/JTC1-SC37").unwrap();
+
+        let encoded = encode(&iri).unwrap();
+        assert_eq!(
+            b"\x1F\x23\x27/ISO/Registration_Authority/19785.CBEFF"[..],
+            *encoded
+        );
+        assert_eq!(iri, decode::<OidIri>(&encoded).unwrap());
+
+        let encoded = encode(&relative).unwrap();
+        assert_eq!(b"\x1F\x24\x17Organizations/JTC1-SC37"[..], *encoded);
+        assert_eq!(relative, decode::<RelativeOidIri>(&encoded).unwrap());
+
+        assert!(decode::<OidIri>(b"\x1F\x23\x03ISO").is_err());
+        assert!(decode::<RelativeOidIri>(b"\x1F\x24\x04/ISO").is_err());
     }
 
     #[test]
@@ -106,6 +682,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decode_all() {
+        let encoded = [0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03];
+
+        assert_eq!(vec![1u8, 2, 3], super::decode_all::<u8>(&encoded).unwrap());
+        assert_eq!(
+            vec![1u8, 2, 3],
+            crate::der::decode_all::<u8>(&encoded).unwrap()
+        );
+        assert!(super::decode_all::<u8>(&[]).unwrap().is_empty());
+        assert!(matches!(
+            super::decode_all::<u8>(&encoded[..8]),
+            Err(de::Error::ElementError { index: 2, .. })
+        ));
+        assert!(matches!(
+            super::decode_all::<bool>(&encoded),
+            Err(de::Error::ElementError { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn references() {
+        let mut value = vec![Utf8String::from("Jones")];
+        let expected = encode(&value).unwrap();
+
+        assert_eq!(expected, encode(&&value).unwrap());
+        assert_eq!(expected, encode(&&mut value).unwrap());
+        assert_eq!(expected, encode(&vec![&mut value[0]]).unwrap());
+    }
+
     #[test]
     fn object_identifier() {
         let iso = ObjectIdentifier::new(vec![1, 2]);
@@ -160,6 +766,23 @@ mod tests {
         assert_eq!(new_int, decode(&encode(&new_int).unwrap()).unwrap());
     }
 
+    #[test]
+    fn const_generic_prefix() {
+        let context = ContextTag::<1, u64>::new(5);
+        let application = AppTag::<3, bool>::new(true);
+        let explicit = Explicit::<ContextSpecific<0>, u64>::new(5);
+
+        assert_eq!(&[0x81, 1, 5][..], &encode(&context).unwrap());
+        assert_eq!(context, decode(&[0x81, 1, 5]).unwrap());
+        assert!(decode::<ContextTag<2, u64>>(&[0x81, 1, 5]).is_err());
+        assert_eq!(&[0x43, 1, 0xFF][..], &encode(&application).unwrap());
+        assert_eq!(application, decode(&[0x43, 1, 0xFF]).unwrap());
+        assert_eq!(
+            encode(&Explicit::<C0, u64>::new(5)).unwrap(),
+            encode(&explicit).unwrap()
+        );
+    }
+
     #[test]
     fn implicit_tagged_constructed() {
         type ImpVec = Implicit<C0, Vec<i32>>;
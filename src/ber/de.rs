@@ -1,10 +1,15 @@
 //! # Decoding BER
 
+#[cfg(feature = "borrowed_decoder")]
+mod borrowed;
 mod config;
 mod error;
 pub(super) mod parser;
+mod sequence_of;
+mod validate;
+mod value;
 
-use alloc::{borrow::ToOwned, vec::Vec};
+use alloc::vec::Vec;
 
 use snafu::*;
 
@@ -19,17 +24,289 @@ use crate::{
     Decode,
 };
 
-pub use self::{config::DecoderOptions, error::Error};
+pub(crate) use self::config::RFC_5280_UTC_TIME_PIVOT;
+pub use self::{
+    config::DecoderOptions,
+    error::Error,
+    sequence_of::{ElementErrors, SequenceOfIter},
+    validate::ValidationError,
+};
+
+#[cfg(feature = "borrowed_decoder")]
+pub use self::borrowed::{BorrowedDecoder, SequenceDecoder};
 
 type Result<T, E = Error> = core::result::Result<T, E>;
 
 const EOC: &[u8] = &[0, 0];
 
+/// Checks that the `INTEGER` `contents` use the minimum number of bytes,
+/// if required by the encoding rules.
+fn assert_minimal_integer(config: &DecoderOptions, contents: &[u8]) -> Result<()> {
+    // The first nine bits of a multi-byte integer can't all be the same,
+    // as the integer could then be represented in fewer bytes.
+    let is_minimal = match contents {
+        [0x00, next, ..] => next & 0x80 != 0,
+        [0xFF, next, ..] => next & 0x80 == 0,
+        _ => true,
+    };
+
+    if !is_minimal && !config.encoding_rules.is_ber() {
+        return error::NotCanonicalSnafu { name: "INTEGER" }.fail();
+    }
+
+    Ok(())
+}
+
+/// Parses the contents of a `REAL`. With `canonical` (for CER and DER), a
+/// binary encoding must be base 2, with an odd mantissa, no scaling factor,
+/// and no redundant leading octets in its exponent or mantissa.
+fn parse_real(contents: &[u8], canonical: bool) -> Result<f64> {
+    let (&first, rest) = match contents.split_first() {
+        Some(split) => split,
+        None => return Ok(0.0),
+    };
+
+    if first & 0x80 == 0 {
+        return match (first, rest) {
+            (0x40, []) => Ok(f64::INFINITY),
+            (0x41, []) => Ok(f64::NEG_INFINITY),
+            (0x42, []) => Ok(f64::NAN),
+            (0x43, []) => Ok(-0.0),
+            (0x01..=0x03, _) => parse_decimal_real(rest).context(error::InvalidRealSnafu),
+            _ => error::InvalidRealSnafu.fail(),
+        };
+    }
+
+    let base_bits = match (first >> 4) & 0b11 {
+        0 => 1,
+        1 => 3,
+        2 => 4,
+        _ => return error::InvalidRealSnafu.fail(),
+    };
+    let scaling = i64::from((first >> 2) & 0b11);
+    let (exponent_len, rest) = match first & 0b11 {
+        0b11 => match rest.split_first() {
+            Some((&len, rest)) => (usize::from(len), rest),
+            None => return error::InvalidRealSnafu.fail(),
+        },
+        len => (usize::from(len) + 1, rest),
+    };
+
+    // An exponent or mantissa too long for an `i64` or `u64` is far outside of
+    // what an `f64` can hold or distinguish, so isn't supported.
+    if exponent_len == 0 || rest.len() <= exponent_len {
+        return error::InvalidRealSnafu.fail();
+    }
+
+    let (exponent, mantissa) = rest.split_at(exponent_len);
+
+    if exponent.len() > 8 || mantissa.len() > 8 {
+        return error::InvalidRealSnafu.fail();
+    }
+
+    if canonical
+        && (base_bits != 1
+            || scaling != 0
+            || mantissa[0] == 0
+            || mantissa[mantissa.len() - 1] & 1 == 0
+            || (first & 0b11 == 0b11 && exponent_len <= 3)
+            || (exponent.len() > 1
+                && matches!((exponent[0], exponent[1] & 0x80), (0x00, 0) | (0xFF, 0x80))))
+    {
+        return error::NotCanonicalSnafu { name: "REAL" }.fail();
+    }
+
+    let mantissa = mantissa
+        .iter()
+        .fold(0, |value: u64, &byte| value << 8 | u64::from(byte));
+    let exponent = exponent.iter().fold(
+        if exponent[0] & 0x80 == 0 { 0 } else { -1 },
+        |value: i64, &byte| value << 8 | i64::from(byte),
+    );
+    // Clamped well outside of an `f64`'s range, so the rest can't overflow.
+    let exponent = exponent.clamp(-(1 << 20), 1 << 20) * base_bits + scaling;
+    let value = scale_by_power_of_two(mantissa as f64, exponent);
+
+    Ok(if first & 0x40 == 0 { value } else { -value })
+}
+
+/// Parses the ISO 6093 number of a decimal `REAL`, e.g. `123`, `1.5` or
+/// `-15E-1`.
+fn parse_decimal_real(number: &[u8]) -> Option<f64> {
+    let valid = |byte: &u8| byte.is_ascii_digit() || b" +-.,Ee".contains(byte);
+
+    if !number.iter().all(valid) {
+        return None;
+    }
+
+    core::str::from_utf8(number)
+        .ok()?
+        .trim_start_matches(' ')
+        .replace(',', ".")
+        .parse()
+        .ok()
+}
+
+/// Returns `value * 2^exponent`, in steps small enough that none overflow to
+/// infinity or underflow to zero before the result does.
+fn scale_by_power_of_two(mut value: f64, mut exponent: i64) -> f64 {
+    let power_of_two = |exponent: i64| f64::from_bits(((exponent + 1023) as u64) << 52);
+
+    while exponent > 1000 {
+        value *= power_of_two(1000);
+        exponent -= 1000;
+    }
+
+    while exponent < -1000 {
+        value *= power_of_two(-1000);
+        exponent += 1000;
+    }
+
+    value * power_of_two(exponent)
+}
+
+/// Parses the `YYYYMMDD` contents of a `DATE`, which has to be a real date,
+/// so e.g. the 29th of February is only accepted in a leap year.
+fn parse_date(string: &str) -> Option<types::Date> {
+    if string.len() != "YYYYMMDD".len() || !string.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    chrono::NaiveDate::from_ymd_opt(
+        string[..4].parse().ok()?,
+        string[4..6].parse().ok()?,
+        string[6..].parse().ok()?,
+    )
+}
+
+/// Parses the `HHMMSS` contents of a `TIME-OF-DAY`.
+fn parse_time_of_day(string: &str) -> Option<types::TimeOfDay> {
+    if string.len() != "HHMMSS".len() || !string.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    chrono::NaiveTime::from_hms_opt(
+        string[..2].parse().ok()?,
+        string[2..4].parse().ok()?,
+        string[4..].parse().ok()?,
+    )
+}
+
+/// Parses a `GeneralizedTime` in UTC (with a `Z` suffix), or with a `±hhmm`
+/// or `±hh` UTC offset if `allow_offset`.
+fn parse_generalized_time(
+    string: &str,
+    strict: bool,
+    allow_offset: bool,
+) -> Option<types::GeneralizedTime> {
+    let (string, offset) = match string.strip_suffix('Z') {
+        Some(string) => (string, 0),
+        None if allow_offset => split_utc_offset(string)?,
+        None => return None,
+    };
+    let offset = chrono::FixedOffset::east_opt(offset)?;
+    let date = parse_generalized_time_digits(string, strict)?;
+
+    chrono::TimeZone::from_local_datetime(&offset, &date).single()
+}
+
+/// Splits a `±hhmm` or `±hh` UTC offset from the end of `string`, returning
+/// the rest of the string and the offset in seconds.
+fn split_utc_offset(string: &str) -> Option<(&str, i32)> {
+    let index = string.rfind(['+', '-'])?;
+    let (string, offset) = string.split_at(index);
+    let (sign, digits) = offset.split_at(1);
+
+    if !matches!(digits.len(), 2 | 4) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let hours: i32 = digits[..2].parse().ok()?;
+    let minutes: i32 = match digits.len() {
+        4 => digits[2..].parse().ok()?,
+        _ => 0,
+    };
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    let seconds = hours * 3600 + minutes * 60;
+    Some((string, if sign == "-" { -seconds } else { seconds }))
+}
+
+/// Parses the `YYYYMMDDHHMMSS[.fff...]` digits of a `GeneralizedTime`, before
+/// its `Z` or UTC offset, with any number of digits of fractional seconds
+/// after either a `.` or `,`. Digits past the ninth are more precise than a
+/// nanosecond, so they're truncated, or with `strict` the time is rejected.
+fn parse_generalized_time_digits(string: &str, strict: bool) -> Option<chrono::NaiveDateTime> {
+    let (seconds, fraction) = match string.find(['.', ',']) {
+        Some(index) => (&string[..index], Some(&string[index + 1..])),
+        None => (string, None),
+    };
+    let date = chrono::NaiveDateTime::parse_from_str(seconds, "%Y%m%d%H%M%S").ok()?;
+
+    match fraction {
+        None => Some(date),
+        Some(digits) if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) => None,
+        Some(digits) if strict && digits.len() > 9 => None,
+        Some(digits) => {
+            let nanoseconds = digits
+                .bytes()
+                .chain(core::iter::repeat(b'0'))
+                .take(9)
+                .fold(0, |nanoseconds, digit| {
+                    nanoseconds * 10 + u32::from(digit - b'0')
+                });
+
+            chrono::Timelike::with_nanosecond(&date, nanoseconds)
+        }
+    }
+}
+
+/// Runs the body of a decoding method, and when the `trace` feature is
+/// enabled, logs the method's tag, the offset of the value in the input, and
+/// the decoded value (unless `opaque`).
+#[cfg(feature = "trace")]
+macro_rules! traced {
+    ($decoder:ident, $method:literal, $tag:expr, $body:block) => {
+        traced!(@log $decoder, $method, $tag, value => value, $body)
+    };
+    ($decoder:ident, $method:literal, $tag:expr, opaque, $body:block) => {
+        traced!(@log $decoder, $method, $tag, _ => format_args!(".."), $body)
+    };
+    (@log $decoder:ident, $method:literal, $tag:expr, $value:pat => $show:expr, $body:block) => {{
+        let offset = $decoder.offset();
+        #[allow(clippy::redundant_closure_call)]
+        let result = (|| -> Result<_> { $body })();
+
+        match &result {
+            Ok($value) => log::trace!("{} {:?} at offset {}: {:?}", $method, $tag, offset, $show),
+            Err(error) => log::trace!("{} {:?} at offset {} failed: {}", $method, $tag, offset, error),
+        }
+
+        result
+    }};
+}
+
+#[cfg(not(feature = "trace"))]
+macro_rules! traced {
+    ($decoder:ident, $method:literal, $tag:expr, $(opaque,)? $body:block) => {
+        $body
+    };
+}
+
 /// A BER and variants decoder. Capable of decoding BER, CER, and DER.
 pub struct Decoder<'input> {
     input: &'input [u8],
     config: DecoderOptions,
     initial_len: usize,
+    /// The number of bytes and elements decoded so far, which is carried
+    /// through nested decoders to be checked against the limits in `config`.
+    output_bytes: usize,
+    output_elements: usize,
+    /// The offset of the start of this decoder's input in the complete
+    /// input, for logging offsets.
+    base_offset: usize,
 }
 
 impl<'input> Decoder<'input> {
@@ -39,14 +316,143 @@ impl<'input> Decoder<'input> {
             input,
             config,
             initial_len: input.len(),
+            output_bytes: 0,
+            output_elements: 0,
+            base_offset: 0,
         }
     }
 
+    /// Creates a decoder for `contents`, a part of this decoder's input that
+    /// starts at `offset` in the complete input.
+    fn nested(&self, contents: &'input [u8], offset: usize) -> Self {
+        Self {
+            output_bytes: self.output_bytes,
+            output_elements: self.output_elements,
+            base_offset: offset,
+            ..Self::new(contents, self.config)
+        }
+    }
+
+    /// Continues counting the decoded output from where `nested` left off,
+    /// after decoding some of this decoder's input with it.
+    fn resume_from(&mut self, nested: &Self) {
+        self.output_bytes = nested.output_bytes;
+        self.output_elements = nested.output_elements;
+    }
+
+    /// Adds `bytes` and `elements` to the decoded output, failing if that's
+    /// more than the limits set in the decoder's options.
+    fn add_output(&mut self, bytes: usize, elements: usize) -> Result<()> {
+        self.output_bytes = self.output_bytes.saturating_add(bytes);
+        self.output_elements = self.output_elements.saturating_add(elements);
+        self.check_output_limits()
+    }
+
+    /// Fails if the decoded output is more than the limits set in the
+    /// decoder's options.
+    fn check_output_limits(&self) -> Result<()> {
+        match (self.config.max_total_bytes, self.config.max_output_elements) {
+            (Some(limit), _) if self.output_bytes > limit => error::ExceedsOutputLimitSnafu {
+                limit,
+                unit: "bytes",
+            }
+            .fail(),
+            (_, Some(limit)) if self.output_elements > limit => error::ExceedsOutputLimitSnafu {
+                limit,
+                unit: "elements",
+            }
+            .fail(),
+            _ => Ok(()),
+        }
+    }
+
+    /// The offset of the remaining input from the start of the complete
+    /// input.
+    fn offset(&self) -> usize {
+        self.base_offset + self.decoded_len()
+    }
+
     /// Return a number of the decoded bytes by this decoder
     pub fn decoded_len(&self) -> usize {
         self.initial_len - self.input.len()
     }
 
+    /// Returns the offset of the next byte to be decoded from the start of
+    /// the input, which is the same as [`Self::decoded_len`].
+    pub fn position(&self) -> usize {
+        self.decoded_len()
+    }
+
+    /// Returns the tag of the next value, including its class, without
+    /// decoding the value.
+    pub fn peek_tag(&self) -> Result<Tag> {
+        self.peek_identifier().map(|identifier| identifier.tag)
+    }
+
+    /// Returns whether the next value has a constructed encoding, without
+    /// decoding the value.
+    pub fn peek_is_constructed(&self) -> Result<bool> {
+        self.peek_identifier()
+            .map(|identifier| identifier.is_constructed())
+    }
+
+    /// Returns the number of bytes the next value takes up, including its
+    /// identifier and length octets, without decoding it, such as for
+    /// slicing it out of the input. The length of an indefinite length value
+    /// can't be known without scanning its contents, so it's an
+    /// [`Error::IndefiniteLengthNotAllowed`]; use [`Self::skip_value`] to
+    /// find it instead.
+    /// ```
+    /// use rasn::ber::de::{Decoder, DecoderOptions};
+    ///
+    /// let data = [0x04, 0x02, 0xAB, 0xCD, 0x30, 0x80, 0x00, 0x00];
+    /// let mut decoder = Decoder::new(&data, DecoderOptions::ber());
+    ///
+    /// let length = decoder.peek_length().unwrap();
+    /// assert_eq!(&[0x04, 0x02, 0xAB, 0xCD], &data[decoder.position()..][..length]);
+    /// assert_eq!(length, decoder.skip_value().unwrap());
+    /// assert!(decoder.peek_length().is_err());
+    /// ```
+    pub fn peek_length(&self) -> Result<usize> {
+        match parser::parse_value(&self.config, self.input, None)? {
+            (rest, (_, Some(_))) => Ok(self.input.len() - rest.len()),
+            (_, (_, None)) => error::IndefiniteLengthNotAllowedSnafu.fail(),
+        }
+    }
+
+    /// Skips over the next value without decoding it, returning the number of
+    /// bytes it took up. The contents of an indefinite length value are
+    /// skipped up to and including its end-of-contents marker.
+    /// ```
+    /// use rasn::ber::de::{Decoder, DecoderOptions};
+    ///
+    /// let data = [0x30, 0x80, 0x01, 0x01, 0xFF, 0x00, 0x00, 0x05, 0x00];
+    /// let mut decoder = Decoder::new(&data, DecoderOptions::ber());
+    ///
+    /// assert_eq!(7, decoder.skip_value().unwrap());
+    /// assert_eq!(2, decoder.skip_value().unwrap());
+    /// assert!(decoder.skip_value().is_err());
+    /// ```
+    pub fn skip_value(&mut self) -> Result<usize> {
+        let input = parser::skip_value(&self.config, self.input)?;
+        let length = self.input.len() - input.len();
+        self.input = input;
+
+        Ok(length)
+    }
+
+    fn peek_identifier(&self) -> Result<Identifier> {
+        parser::parse_identifier_octet(self.input)
+            .map(|(_, identifier)| identifier)
+            .map_err(error::map_nom_err)
+    }
+
+    /// Whether the contents of the current value have been exhausted, either
+    /// by reaching the end of the input or an end-of-contents marker.
+    fn is_at_end(&self) -> bool {
+        self.input.is_empty() || self.input.starts_with(EOC)
+    }
+
     fn parse_eoc(&mut self) -> Result<()> {
         let (i, _) = nom::bytes::streaming::tag(EOC)(self.input).map_err(error::map_nom_err)?;
         self.input = i;
@@ -90,14 +496,18 @@ impl<'input> Decoder<'input> {
             return Err(Error::custom("Invalid constructed identifier"));
         }
 
-        let (streaming, contents) = match contents {
-            Some(contents) => (false, contents),
-            None => (true, self.input),
+        // Definite length contents were just parsed, so they end where the
+        // remaining input starts.
+        let (streaming, contents, offset) = match contents {
+            Some(contents) => (false, contents, self.offset() - contents.len()),
+            None => (true, self.input, self.offset()),
         };
 
-        let mut inner = Self::new(contents, self.config);
+        let mut inner = self.nested(contents, offset);
 
-        let result = (decode_fn)(&mut inner)?;
+        let result = (decode_fn)(&mut inner);
+        self.resume_from(&inner);
+        let result = result?;
 
         if streaming {
             self.input = inner.input;
@@ -110,220 +520,401 @@ impl<'input> Decoder<'input> {
 
         Ok(result)
     }
+
+    /// Decodes the string of a time type identified by `tag`, checking that
+    /// it is exactly `length` characters long, as each field is fixed width.
+    fn decode_time_string(&mut self, tag: Tag, length: usize) -> Result<types::Utf8String> {
+        let string = crate::Decoder::decode_utf8_string(self, tag)?;
+
+        if string.len() != length {
+            return Err(Error::InvalidDate);
+        }
+
+        Ok(string)
+    }
 }
 
 impl<'input> crate::Decoder for Decoder<'input> {
     type Error = Error;
 
     fn decode_any(&mut self) -> Result<types::Any> {
-        let (input, _) = self::parser::parse_value(&self.config, self.input, None)?;
-        let diff = self.input.len() - input.len();
-        let contents = &self.input[..diff];
-        self.input = input;
-
-        Ok(types::Any {
-            contents: contents.to_vec(),
+        traced!(self, "decode_any", "ANY", {
+            let input = self.input;
+            let length = self.skip_value()?;
+            self.add_output(length, 0)?;
+
+            Ok(types::Any {
+                contents: input[..length].to_vec(),
+            })
         })
     }
 
     fn decode_bool(&mut self, tag: Tag) -> Result<bool> {
-        let (_, contents) = self.parse_primitive_value(tag)?;
-        error::assert_length(1, contents.len())?;
-        Ok(match contents[0] {
-            0 => false,
-            0xFF => true,
-            _ if self.config.encoding_rules.is_ber() => true,
-            _ => return Err(error::Error::InvalidBool),
+        traced!(self, "decode_bool", tag, {
+            let (_, contents) = self.parse_primitive_value(tag)?;
+            error::assert_length(1, contents.len())?;
+            Ok(match contents[0] {
+                0 => false,
+                0xFF => true,
+                _ if self.config.encoding_rules.is_ber() => true,
+                _ => return Err(error::Error::InvalidBool),
+            })
         })
     }
 
     fn decode_enumerated(&mut self, tag: Tag) -> Result<types::Integer> {
-        self.decode_integer(tag)
+        traced!(self, "decode_enumerated", tag, { self.decode_integer(tag) })
     }
 
     fn decode_integer(&mut self, tag: Tag) -> Result<types::Integer> {
-        Ok(types::Integer::from_signed_bytes_be(
-            self.parse_primitive_value(tag)?.1,
-        ))
+        traced!(self, "decode_integer", tag, {
+            let contents = self.parse_primitive_value(tag)?.1;
+            assert_minimal_integer(&self.config, contents)?;
+            Ok(types::Integer::from_signed_bytes_be(contents))
+        })
     }
 
-    fn decode_octet_string(&mut self, tag: Tag) -> Result<Vec<u8>> {
-        let (identifier, contents) = self.parse_value(tag)?;
+    fn decode_real(&mut self, tag: Tag) -> Result<f64> {
+        traced!(self, "decode_real", tag, {
+            let contents = self.parse_primitive_value(tag)?.1;
+            parse_real(contents, !self.config.encoding_rules.is_ber())
+        })
+    }
 
-        if identifier.is_primitive() {
-            match contents {
-                Some(c) => Ok(c.to_vec()),
-                None => error::IndefiniteLengthNotAllowedSnafu.fail(),
-            }
-        } else if identifier.is_constructed() && self.config.encoding_rules.is_der() {
-            error::ConstructedEncodingNotAllowedSnafu.fail()
-        } else {
-            let mut buffer = Vec::new();
-
-            match contents {
-                Some(mut contents) => {
-                    while !contents.is_empty() {
-                        let (c, mut vec) = self::parser::parse_encoded_value(
-                            &self.config,
-                            contents,
-                            Tag::OCTET_STRING,
-                            |input| Ok(alloc::vec::Vec::from(input)),
-                        )?;
-                        contents = c;
-
-                        buffer.append(&mut vec);
+    fn decode_octet_string(&mut self, tag: Tag) -> Result<Vec<u8>> {
+        traced!(self, "decode_octet_string", tag, {
+            let (identifier, contents) = self.parse_value(tag)?;
+
+            if identifier.is_primitive() {
+                match contents {
+                    Some(c) => {
+                        self.add_output(c.len(), 0)?;
+                        Ok(c.to_vec())
                     }
+                    None => error::IndefiniteLengthNotAllowedSnafu.fail(),
                 }
-                None => {
-                    while !self.input.starts_with(EOC) {
-                        let (c, mut vec) = self::parser::parse_encoded_value(
-                            &self.config,
-                            self.input,
-                            Tag::OCTET_STRING,
-                            |input| Ok(alloc::vec::Vec::from(input)),
-                        )?;
-                        self.input = c;
-
-                        buffer.append(&mut vec);
+            } else if identifier.is_constructed() && self.config.encoding_rules.is_der() {
+                error::ConstructedEncodingNotAllowedSnafu.fail()
+            } else {
+                let mut buffer = Vec::new();
+
+                match contents {
+                    Some(mut contents) => {
+                        while !contents.is_empty() {
+                            let (c, mut vec) = self::parser::parse_encoded_value(
+                                &self.config,
+                                contents,
+                                Tag::OCTET_STRING,
+                                Tag::OCTET_STRING,
+                                |input| Ok(alloc::vec::Vec::from(input)),
+                            )?;
+                            contents = c;
+
+                            self.add_output(vec.len(), 0)?;
+                            buffer.append(&mut vec);
+                        }
                     }
+                    None => {
+                        while !self.input.starts_with(EOC) {
+                            let (c, mut vec) = self::parser::parse_encoded_value(
+                                &self.config,
+                                self.input,
+                                Tag::OCTET_STRING,
+                                Tag::OCTET_STRING,
+                                |input| Ok(alloc::vec::Vec::from(input)),
+                            )?;
+                            self.input = c;
+
+                            self.add_output(vec.len(), 0)?;
+                            buffer.append(&mut vec);
+                        }
 
-                    self.parse_eoc()?;
+                        self.parse_eoc()?;
+                    }
                 }
-            }
 
-            Ok(buffer)
-        }
+                Ok(buffer)
+            }
+        })
     }
 
     fn decode_null(&mut self, tag: Tag) -> Result<()> {
-        let (_, contents) = self.parse_primitive_value(tag)?;
-        error::assert_length(0, contents.len())?;
-        Ok(())
+        traced!(self, "decode_null", tag, {
+            // The input is left as it was if the value isn't a valid `NULL`,
+            // so that an absent `Option<()>` decodes as `None`, while a
+            // malformed `NULL` remains in the input to be reported.
+            let input = self.input;
+            let result = self
+                .parse_primitive_value(tag)
+                .and_then(|(_, contents)| error::assert_length(0, contents.len()));
+
+            if result.is_err() {
+                self.input = input;
+            }
+
+            result
+        })
     }
 
     fn decode_object_identifier(&mut self, tag: Tag) -> Result<crate::types::ObjectIdentifier> {
-        use num_traits::ToPrimitive;
-        let contents = self.parse_primitive_value(tag)?.1;
-        let (mut contents, root_octets) =
-            parser::parse_base128_number(contents).map_err(error::map_nom_err)?;
-        let the_number = root_octets
-            .to_u32()
-            .context(error::IntegerOverflowSnafu { max_width: 32u32 })?;
-        let first: u32;
-        let second: u32;
-        const MAX_OID_THRESHOLD: u32 = MAX_OID_SECOND_OCTET + 1;
-        if the_number > MAX_OID_FIRST_OCTET * MAX_OID_THRESHOLD + MAX_OID_SECOND_OCTET {
-            first = MAX_OID_FIRST_OCTET;
-            second = the_number - MAX_OID_FIRST_OCTET * MAX_OID_THRESHOLD;
-        } else {
-            second = the_number % MAX_OID_THRESHOLD;
-            first = (the_number - second) / MAX_OID_THRESHOLD;
-        }
-        let mut buffer = alloc::vec![first, second];
-
-        while !contents.is_empty() {
-            let (c, number) = parser::parse_base128_number(contents).map_err(error::map_nom_err)?;
-            contents = c;
-            buffer.push(
-                number
-                    .to_u32()
-                    .context(error::IntegerOverflowSnafu { max_width: 32u32 })?,
-            );
-        }
+        traced!(self, "decode_object_identifier", tag, {
+            use num_traits::ToPrimitive;
+            let contents = self.parse_primitive_value(tag)?.1;
+            // The last arc can't be cut off with its continuation bit still
+            // set, and only BER allows arcs to start with `0x80` padding.
+            if !matches!(contents.last(), Some(byte) if byte & 0x80 == 0) {
+                return error::InvalidObjectIdentifierSnafu.fail();
+            }
+            let is_minimal = core::iter::once(&0)
+                .chain(contents)
+                .zip(contents)
+                .all(|(previous, byte)| previous & 0x80 != 0 || *byte != 0x80);
+            if !is_minimal && !self.config.encoding_rules.is_ber() {
+                return error::NotCanonicalSnafu {
+                    name: "OBJECT IDENTIFIER",
+                }
+                .fail();
+            }
+            let (mut contents, root_octets) =
+                parser::parse_base128_number(contents).map_err(error::map_nom_err)?;
+            let the_number = root_octets
+                .to_u32()
+                .context(error::IntegerOverflowSnafu { max_width: 32u32 })?;
+            let first: u32;
+            let second: u32;
+            const MAX_OID_THRESHOLD: u32 = MAX_OID_SECOND_OCTET + 1;
+            if the_number > MAX_OID_FIRST_OCTET * MAX_OID_THRESHOLD + MAX_OID_SECOND_OCTET {
+                first = MAX_OID_FIRST_OCTET;
+                second = the_number - MAX_OID_FIRST_OCTET * MAX_OID_THRESHOLD;
+            } else {
+                second = the_number % MAX_OID_THRESHOLD;
+                first = (the_number - second) / MAX_OID_THRESHOLD;
+            }
+            let mut buffer = alloc::vec![first, second];
+
+            while !contents.is_empty() {
+                let (c, number) =
+                    parser::parse_base128_number(contents).map_err(error::map_nom_err)?;
+                contents = c;
+                buffer.push(
+                    number
+                        .to_u32()
+                        .context(error::IntegerOverflowSnafu { max_width: 32u32 })?,
+                );
+            }
 
-        crate::types::ObjectIdentifier::new(buffer).context(error::InvalidObjectIdentifierSnafu)
+            crate::types::ObjectIdentifier::new(buffer).context(error::InvalidObjectIdentifierSnafu)
+        })
     }
 
     fn decode_bit_string(&mut self, tag: Tag) -> Result<types::BitString> {
-        let (input, bs) =
-            self::parser::parse_encoded_value(&self.config, self.input, tag, |input| {
-                let unused_bits = if let Some(bits) = input.get(0).copied() {
-                    bits
-                } else {
-                    return Ok(types::BitString::new());
-                };
-
-                match unused_bits {
-                    // TODO: https://github.com/myrrlyn/bitvec/issues/72
-                    bits @ 0..=7 => {
-                        let mut buffer = input[1..].to_owned();
-                        if let Some(last) = buffer.last_mut() {
-                            *last &= !((1 << bits) - 1);
-                        }
-                        if buffer.last().map_or(false, |i| *i == 0) {
-                            buffer.pop();
+        traced!(self, "decode_bit_string", tag, {
+            // CER and DER require the unused bits to be zero.
+            let canonical = !self.config.encoding_rules.is_ber();
+            let (input, bs) = self::parser::parse_encoded_value(
+                &self.config,
+                self.input,
+                tag,
+                Tag::BIT_STRING,
+                |input| {
+                    // The initial octet is always present, but earlier
+                    // versions of this crate left it out of an empty value.
+                    let (unused_bits, bytes) = match input.split_first() {
+                        Some(split) => split,
+                        None if canonical => {
+                            return error::NotCanonicalSnafu { name: "BIT STRING" }.fail();
                         }
+                        None => return Ok(types::BitString::new()),
+                    };
 
-                        let string = types::BitString::from_vec(buffer);
+                    if *unused_bits > 7 || (*unused_bits != 0 && bytes.is_empty()) {
+                        return Err(Error::InvalidBitString { bits: *unused_bits });
+                    }
 
-                        if string.not_any() {
-                            Ok(types::BitString::new())
-                        } else {
-                            Ok(string)
-                        }
+                    let mut bits = types::BitString::from_slice(bytes);
+                    let length = bits.len() - usize::from(*unused_bits);
+                    if canonical && bits[length..].any() {
+                        return error::NotCanonicalSnafu { name: "BIT STRING" }.fail();
                     }
-                    _ => Err(Error::InvalidBitString { bits: unused_bits }),
-                }
-            })?;
 
-        self.input = input;
-        if let Some((i, _)) = bs
-            .as_raw_slice()
-            .iter()
-            .enumerate()
-            .rev()
-            .find(|(_, v)| **v != 0)
-        {
-            Ok(types::BitString::from_vec(bs.as_raw_slice()[..=i].to_vec()))
-        } else {
+                    bits.truncate(length);
+                    Ok(bits)
+                },
+            )?;
+
+            self.input = input;
+            self.add_output(bs.as_raw_slice().len(), 0)?;
             Ok(bs)
-        }
+        })
     }
 
     fn decode_utf8_string(&mut self, tag: Tag) -> Result<types::Utf8String> {
-        let vec = self.decode_octet_string(tag)?;
-        types::Utf8String::from_utf8(vec)
-            .ok()
-            .context(error::InvalidUtf8Snafu)
+        traced!(self, "decode_utf8_string", tag, {
+            let vec = self.decode_octet_string(tag)?;
+            types::Utf8String::from_utf8(vec)
+                .ok()
+                .context(error::InvalidUtf8Snafu)
+        })
     }
 
     fn decode_generalized_time(&mut self, tag: Tag) -> Result<types::GeneralizedTime> {
-        let string = self.decode_utf8_string(tag)?;
-        chrono::NaiveDateTime::parse_from_str(&string, "%Y%m%d%H%M%SZ")
-            .ok()
+        traced!(self, "decode_generalized_time", tag, {
+            let string = self.decode_utf8_string(tag)?;
+            // CER and DER require times to be in UTC.
+            parse_generalized_time(
+                &string,
+                self.config.strict_fractional_seconds,
+                self.config.encoding_rules.is_ber(),
+            )
             .context(error::InvalidDateSnafu)
-            .map(|date| types::GeneralizedTime::from_utc(date, chrono::FixedOffset::east(0)))
+        })
     }
 
     fn decode_utc_time(&mut self, tag: Tag) -> Result<types::UtcTime> {
-        let string = self.decode_utf8_string(tag)?;
-        chrono::NaiveDateTime::parse_from_str(&string, "%y%m%d%H%M%SZ")
+        traced!(self, "decode_utc_time", tag, {
+            let string = self.decode_utf8_string(tag)?;
+            let year = string
+                .get(..2)
+                .filter(|year| year.bytes().all(|byte| byte.is_ascii_digit()))
+                .and_then(|year| year.parse::<u8>().ok())
+                .context(error::InvalidDateSnafu)?;
+            let century = if year < self.config.utc_time_pivot {
+                "20"
+            } else {
+                "19"
+            };
+
+            chrono::NaiveDateTime::parse_from_str(
+                &alloc::format!("{}{}", century, string),
+                "%Y%m%d%H%M%SZ",
+            )
             .ok()
             .context(error::InvalidDateSnafu)
             .map(|date| types::UtcTime::from_utc(date, chrono::Utc))
+        })
+    }
+
+    fn decode_date(&mut self, tag: Tag) -> Result<types::Date> {
+        traced!(self, "decode_date", tag, {
+            let string = self.decode_time_string(tag, "YYYYMMDD".len())?;
+            parse_date(&string).context(error::InvalidDateSnafu)
+        })
+    }
+
+    fn decode_time_of_day(&mut self, tag: Tag) -> Result<types::TimeOfDay> {
+        traced!(self, "decode_time_of_day", tag, {
+            let string = self.decode_time_string(tag, "HHMMSS".len())?;
+            parse_time_of_day(&string).context(error::InvalidDateSnafu)
+        })
+    }
+
+    fn decode_date_time(&mut self, tag: Tag) -> Result<types::DateTime> {
+        traced!(self, "decode_date_time", tag, {
+            let string = self.decode_time_string(tag, "YYYYMMDDHHMMSS".len())?;
+            let (date, time) = string.split_at("YYYYMMDD".len());
+
+            parse_date(date)
+                .zip(parse_time_of_day(time))
+                .map(|(date, time)| date.and_time(time))
+                .context(error::InvalidDateSnafu)
+        })
+    }
+
+    fn decode_duration(&mut self, tag: Tag) -> Result<types::Duration> {
+        traced!(self, "decode_duration", tag, {
+            self.decode_utf8_string(tag)?
+                .parse()
+                .ok()
+                .context(error::InvalidDurationSnafu)
+        })
+    }
+
+    fn decode_oid_iri(&mut self, tag: Tag) -> Result<types::OidIri> {
+        traced!(self, "decode_oid_iri", tag, {
+            self.decode_utf8_string(tag)?
+                .parse()
+                .ok()
+                .context(error::InvalidIriSnafu)
+        })
+    }
+
+    fn decode_relative_oid_iri(&mut self, tag: Tag) -> Result<types::RelativeOidIri> {
+        traced!(self, "decode_relative_oid_iri", tag, {
+            self.decode_utf8_string(tag)?
+                .parse()
+                .ok()
+                .context(error::InvalidIriSnafu)
+        })
     }
 
     fn decode_sequence_of<D: Decode>(&mut self, tag: Tag) -> Result<Vec<D>, Self::Error> {
-        self.decode_sequence(tag, |decoder| {
-            let mut items = Vec::new();
+        traced!(self, "decode_sequence_of", tag, opaque, {
+            self.decode_sequence(tag, |decoder| {
+                let mut items = Vec::new();
+
+                loop {
+                    let item = match D::decode(decoder) {
+                        Ok(item) => item,
+                        // A failed element ends the sequence, unless it
+                        // failed by going over the output limits.
+                        Err(_) => {
+                            decoder.check_output_limits()?;
+                            break;
+                        }
+                    };
 
-            while let Ok(item) = D::decode(decoder) {
-                items.push(item);
-            }
+                    decoder.add_output(0, 1)?;
+                    items.push(item);
+                }
 
-            Ok(items)
+                Ok(items)
+            })
         })
     }
 
     fn decode_set_of<D: Decode + Ord>(&mut self, tag: Tag) -> Result<types::SetOf<D>, Self::Error> {
-        self.decode_sequence(tag, |decoder| {
-            let mut items = types::SetOf::new();
+        self.decode_set_of_into(tag)
+    }
 
-            while let Ok(item) = D::decode(decoder) {
-                items.insert(item);
-            }
+    fn decode_set_of_into<D: Decode, C: Default + Extend<D>>(&mut self, tag: Tag) -> Result<C> {
+        traced!(self, "decode_set_of", tag, opaque, {
+            self.decode_sequence(tag, |decoder| {
+                let mut items = C::default();
+                let mut previous: Option<&[u8]> = None;
+
+                loop {
+                    let input = decoder.input;
+                    let item = match D::decode(decoder) {
+                        Ok(item) => item,
+                        Err(_) => {
+                            decoder.check_output_limits()?;
+                            break;
+                        }
+                    };
+                    decoder.add_output(0, 1)?;
+
+                    // CER and DER require the elements to be sorted by their
+                    // encodings, which the collection would otherwise hide.
+                    let encoded = &input[..input.len() - decoder.input.len()];
+                    if !decoder.config.encoding_rules.is_ber() {
+                        let is_sorted = match previous {
+                            Some(previous) => {
+                                super::compare_set_of_elements(previous, encoded).is_le()
+                            }
+                            None => true,
+                        };
+                        if !is_sorted {
+                            return error::NotCanonicalSnafu { name: "SET OF" }.fail();
+                        }
 
-            Ok(items)
+                        previous = Some(encoded);
+                    }
+
+                    items.extend(Some(item));
+                }
+
+                Ok(items)
+            })
         })
     }
 
@@ -332,11 +923,15 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         decode_fn: F,
     ) -> Result<D> {
-        self.parse_constructed_contents(tag, true, decode_fn)
+        traced!(self, "decode_sequence", tag, opaque, {
+            self.parse_constructed_contents(tag, true, decode_fn)
+        })
     }
 
     fn decode_explicit_prefix<D: Decode>(&mut self, tag: Tag) -> Result<D> {
-        self.parse_constructed_contents(tag, false, D::decode)
+        traced!(self, "decode_explicit_prefix", tag, opaque, {
+            self.parse_constructed_contents(tag, false, D::decode)
+        })
     }
 
     fn decode_set<FIELDS, SET, F>(&mut self, tag: Tag, decode_fn: F) -> Result<SET, Self::Error>
@@ -345,14 +940,144 @@ impl<'input> crate::Decoder for Decoder<'input> {
         FIELDS: Decode,
         F: FnOnce(Vec<FIELDS>) -> Result<SET, Self::Error>,
     {
-        self.decode_sequence(tag, |decoder| {
-            let mut fields = Vec::new();
+        traced!(self, "decode_set", tag, opaque, {
+            self.decode_sequence(tag, |decoder| {
+                let mut fields = Vec::new();
+
+                loop {
+                    let value = match FIELDS::decode(decoder) {
+                        Ok(value) => value,
+                        Err(_) => {
+                            decoder.check_output_limits()?;
+                            break;
+                        }
+                    };
+
+                    decoder.add_output(0, 1)?;
+                    fields.push(value);
+                }
+
+                (decode_fn)(fields)
+            })
+        })
+    }
+
+    fn check_sequence_order(&mut self, fields: &[types::TagTree]) -> Result<()> {
+        // Only DER is checked, as every value has a definite length that can
+        // be skipped over without decoding it.
+        if !self.config.encoding_rules.is_der() {
+            return Ok(());
+        }
 
-            while let Ok(value) = FIELDS::decode(decoder) {
-                fields.push(value);
+        let is_open_type = |field: &types::TagTree| matches!(field, types::TagTree::Choice(&[]));
+        let mut position = 0;
+        let mut input = self.input;
+
+        while !input.is_empty() {
+            // A value that isn't valid DER can't be skipped over, so the rest
+            // are left unchecked, and it fails when it's decoded, unless it's
+            // decoded leniently.
+            let (rest, (identifier, _)) = match self::parser::parse_value(&self.config, input, None)
+            {
+                Ok(value) => value,
+                Err(_) => break,
+            };
+            input = rest;
+
+            let tag = identifier.tag;
+            let next_match = fields[position..]
+                .iter()
+                .position(|field| is_open_type(field) || field.contains(tag));
+
+            match next_match {
+                Some(offset) => position += offset + 1,
+                // Components not belonging to any field are left for the type
+                // to handle, as they could be extensions.
+                None if fields[..position]
+                    .iter()
+                    .any(|field| !is_open_type(field) && field.contains(tag)) =>
+                {
+                    return Err(Error::OutOfOrder { tag })
+                }
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn add_output_elements(&mut self, elements: usize) -> Result<()> {
+        self.add_output(0, elements)
+    }
+
+    fn is_present_before(&mut self, tag: Tag, following: &[types::TagTree]) -> bool {
+        let is_open_type = |field: &types::TagTree| matches!(field, types::TagTree::Choice(&[]));
+        let config = &self.config;
+        let mut input = self.input;
+        // The tags of the remaining components, up to the first one that
+        // can't be skipped over.
+        let mut tags = core::iter::from_fn(|| {
+            if input.is_empty() || input.starts_with(EOC) {
+                return None;
             }
 
-            (decode_fn)(fields)
+            let (_, identifier) = parser::parse_identifier_octet(input).ok()?;
+            input = parser::skip_value(config, input).ok()?;
+            Some(identifier.tag)
+        });
+
+        tags.next() == Some(tag)
+            && following
+                .iter()
+                .all(|field| tags.any(|tag| is_open_type(field) || field.contains(tag)))
+    }
+
+    fn decode_unknown_fields(&mut self) -> Result<types::UnknownFields> {
+        traced!(self, "decode_unknown_fields", "ANY", {
+            let mut fields = types::UnknownFields::new();
+
+            while !self.is_at_end() {
+                let (input, tag) = (self.input, self.peek_tag()?);
+                let length = self.skip_value()?;
+                fields.push(tag, input[..length].to_vec());
+            }
+
+            Ok(fields)
+        })
+    }
+
+    fn decode_leniently<D, F>(&mut self, decode_fn: F) -> Result<D>
+    where
+        F: FnOnce(&mut Self) -> Result<D>,
+    {
+        let encoding_rules = self.config.encoding_rules;
+        self.config.encoding_rules = crate::ber::EncodingRules::Ber;
+        let result = (decode_fn)(self);
+        self.config.encoding_rules = encoding_rules;
+        result
+    }
+}
+
+impl<'input> crate::de::ZeroCopyDecoder<'input> for Decoder<'input> {
+    fn decode_octet_string_borrowed(&mut self, tag: Tag) -> Result<&'input [u8]> {
+        traced!(self, "decode_octet_string_borrowed", tag, {
+            let (identifier, contents) = self.parse_primitive_value(tag)?;
+
+            if identifier.is_constructed() {
+                return error::ConstructedEncodingNotAllowedSnafu.fail();
+            }
+
+            self.add_output(contents.len(), 0)?;
+            Ok(contents)
+        })
+    }
+
+    fn decode_utf8_string_borrowed(&mut self, tag: Tag) -> Result<&'input str> {
+        traced!(self, "decode_utf8_string_borrowed", tag, {
+            let contents = self.decode_octet_string_borrowed(tag)?;
+            core::str::from_utf8(contents)
+                .ok()
+                .context(error::InvalidUtf8Snafu)
         })
     }
 }
@@ -441,6 +1166,140 @@ mod tests {
         assert_eq!(bigint, decode(&data).unwrap());
     }
 
+    #[test]
+    fn utc_time_pivot() {
+        use chrono::Datelike;
+
+        let year = |options: DecoderOptions, encoded: &[u8]| {
+            let mut decoder = Decoder::new(encoded, options);
+            crate::Decoder::decode_utc_time(&mut decoder, Tag::UTC_TIME)
+                .unwrap()
+                .year()
+        };
+        let last_2049 = b"\x17\x0D491231235959Z";
+        let first_1950 = b"\x17\x0D500101000000Z";
+
+        assert_eq!(2049, year(DecoderOptions::der(), last_2049));
+        assert_eq!(1950, year(DecoderOptions::der(), first_1950));
+        assert_eq!(
+            1949,
+            year(DecoderOptions::der().utc_time_pivot(0), last_2049)
+        );
+        assert_eq!(
+            2050,
+            year(DecoderOptions::ber().utc_time_pivot(100), first_1950)
+        );
+        assert!(decode::<types::UtcTime>(b"\x17\x0D+91231235959Z").is_err());
+    }
+
+    #[test]
+    fn generalized_time_offset() {
+        let utc =
+            chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2023, 10, 14, 20, 34, 56).unwrap();
+
+        for encoded in [
+            &b"\x18\x1320231014123456-0800"[..],
+            b"\x18\x1120231014123456-08",
+            b"\x18\x1320231015020456+0530",
+            b"\x18\x0F20231014203456Z",
+        ] {
+            assert_eq!(utc, decode::<GeneralizedTime>(encoded).unwrap());
+        }
+
+        let offset = decode::<GeneralizedTime>(b"\x18\x1320231014123456-0800").unwrap();
+        assert_eq!(-8 * 3600, offset.offset().local_minus_utc());
+        assert!(crate::der::decode::<GeneralizedTime>(b"\x18\x1320231014123456-0800").is_err());
+        assert!(decode::<GeneralizedTime>(b"\x18\x1320231014123456+2400").is_err());
+        assert!(decode::<GeneralizedTime>(b"\x18\x1220231014123456+053").is_err());
+    }
+
+    #[test]
+    fn generalized_time_fraction() {
+        use chrono::Timelike;
+
+        let nanoseconds = |options: DecoderOptions, encoded: &[u8]| {
+            let mut decoder = Decoder::new(encoded, options);
+            crate::Decoder::decode_generalized_time(&mut decoder, Tag::GENERALIZED_TIME)
+                .map(|time| time.nanosecond())
+        };
+        let nine_digits = b"\x18\x1920231014123456.123456789Z";
+        let eleven_digits = b"\x18\x1B20231014123456,12345678987Z";
+
+        assert_eq!(
+            123_456_789,
+            nanoseconds(DecoderOptions::der(), nine_digits).unwrap()
+        );
+        assert_eq!(
+            123_456_789,
+            nanoseconds(DecoderOptions::ber(), eleven_digits).unwrap()
+        );
+        assert!(nanoseconds(
+            DecoderOptions::ber().strict_fractional_seconds(true),
+            eleven_digits
+        )
+        .is_err());
+        assert_eq!(
+            500_000_000,
+            nanoseconds(DecoderOptions::der(), b"\x18\x1120231014123456.5Z").unwrap()
+        );
+        assert_eq!(
+            0,
+            nanoseconds(DecoderOptions::der(), b"\x18\x0F20231014123456Z").unwrap()
+        );
+        assert!(decode::<GeneralizedTime>(b"\x18\x1020231014123456.Z").is_err());
+        assert!(decode::<GeneralizedTime>(b"\x18\x1120231014123456.aZ").is_err());
+
+        let time: GeneralizedTime = decode(nine_digits).unwrap();
+        assert_eq!(&nine_digits[..], &*crate::ber::encode(&time).unwrap());
+        let half: GeneralizedTime = decode(b"\x18\x1320231014123456.500Z").unwrap();
+        assert_eq!(
+            &b"\x18\x1120231014123456.5Z"[..],
+            &*crate::ber::encode(&half).unwrap()
+        );
+    }
+
+    #[test]
+    fn unsorted_set_of() {
+        let unsorted = &[0x31, 0x06, 0x02, 0x01, 0x02, 0x02, 0x01, 0x01];
+        let expected = types::SetOf::from([1u8, 2]);
+
+        assert_eq!(expected, decode::<types::SetOf<u8>>(unsorted).unwrap());
+        assert!(matches!(
+            crate::der::decode::<types::SetOf<u8>>(unsorted),
+            Err(Error::NotCanonical { .. })
+        ));
+        assert_eq!(
+            expected,
+            crate::der::decode::<types::SetOf<u8>>(&[
+                0x31, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02
+            ])
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn non_minimal_integer() {
+        let non_minimal = &[0x02, 0x02, 0x00, 0x7F];
+
+        assert_eq!(127, decode::<i32>(non_minimal).unwrap());
+        assert!(matches!(
+            crate::der::decode::<i32>(non_minimal),
+            Err(Error::NotCanonical { .. })
+        ));
+        assert!(matches!(
+            crate::der::decode::<i32>(&[0x02, 0x02, 0xFF, 0x80]),
+            Err(Error::NotCanonical { .. })
+        ));
+        assert_eq!(
+            128,
+            crate::der::decode::<i32>(&[0x02, 0x02, 0x00, 0x80]).unwrap()
+        );
+        assert_eq!(
+            -129,
+            crate::der::decode::<i32>(&[0x02, 0x02, 0xFF, 0x7F]).unwrap()
+        );
+    }
+
     #[test]
     fn octet_string() {
         let octet_string = types::OctetString::from(alloc::vec![1, 2, 3, 4, 5, 6]);
@@ -459,8 +1318,9 @@ mod tests {
 
     #[test]
     fn bit_string() {
-        let bitstring =
-            types::BitString::from_vec([0x0A, 0x3B, 0x5F, 0x29, 0x1C, 0xD0][..].to_owned());
+        let mut bitstring =
+            types::BitString::from_vec(alloc::vec![0x0A, 0x3B, 0x5F, 0x29, 0x1C, 0xD0]);
+        bitstring.truncate(44);
 
         let primitive_encoded: types::BitString =
             decode(&[0x03, 0x07, 0x04, 0x0A, 0x3B, 0x5F, 0x29, 0x1C, 0xD0][..]).unwrap();
@@ -477,6 +1337,137 @@ mod tests {
 
         assert_eq!(bitstring, primitive_encoded);
         assert_eq!(bitstring, constructed_encoded);
+
+        // The unused bits can be anything in BER, but must be zero in DER.
+        let bits = [0x03, 0x02, 0x04, 0xAF];
+        assert_eq!(4, decode::<types::BitString>(&bits).unwrap().len());
+        assert!(crate::der::decode::<types::BitString>(&bits).is_err());
+        assert!(decode::<types::BitString>(&[0x03, 0x01, 0x01]).is_err());
+
+        // Earlier versions encoded an empty `BIT STRING` without its initial
+        // octet, which only BER still accepts.
+        let empty = [0x03, 0x00];
+        assert!(decode::<types::BitString>(&empty).unwrap().is_empty());
+        assert!(crate::der::decode::<types::BitString>(&empty).is_err());
+        assert!(decode::<types::BitString>(&[0x03, 0x02, 0x08, 0x00]).is_err());
+    }
+
+    #[test]
+    fn peek_length() {
+        let mut data = alloc::vec![0x04, 0x82, 0x01, 0x00];
+        data.extend(core::iter::repeat_n(0xAA, 0x100));
+        data.extend([0x05, 0x00]);
+
+        let mut decoder = Decoder::new(&data, DecoderOptions::der());
+        assert_eq!(0x104, decoder.peek_length().unwrap());
+        assert_eq!(0x104, decoder.peek_length().unwrap());
+        assert_eq!(0x104, decoder.skip_value().unwrap());
+        assert_eq!(2, decoder.peek_length().unwrap());
+
+        // The whole value has to be in the input.
+        let truncated = Decoder::new(&data[..0x80], DecoderOptions::der());
+        assert!(matches!(
+            truncated.peek_length(),
+            Err(Error::Incomplete { .. })
+        ));
+
+        let indefinite = &[0x30, 0x80, 0x05, 0x00, 0x00, 0x00];
+        assert!(matches!(
+            Decoder::new(indefinite, DecoderOptions::ber()).peek_length(),
+            Err(Error::IndefiniteLengthNotAllowed)
+        ));
+        assert!(Decoder::new(indefinite, DecoderOptions::der())
+            .peek_length()
+            .is_err());
+    }
+
+    #[test]
+    fn decode_borrowed() {
+        use crate::de::DecodeBorrowed;
+
+        let data = [0x04, 0x02, 0xAA, 0xBB];
+        let bytes: &[u8] = crate::ber::decode_borrowed(&data).unwrap();
+        assert_eq!(data[2..].as_ptr(), bytes.as_ptr());
+        assert_eq!(
+            alloc::vec![0xAA, 0xBB],
+            crate::ber::decode_borrowed::<types::OctetString>(&data).unwrap()
+        );
+
+        let tagged = [0x80, 0x02, b'h', b'i'];
+        let mut decoder = Decoder::new(&tagged, DecoderOptions::der());
+        assert_eq!(
+            "hi",
+            <&str>::decode_borrowed_with_tag(&mut decoder, Tag::new(Class::Context, 0)).unwrap()
+        );
+
+        // An `OPTIONAL` value is `None`, and left in the input, when it
+        // doesn't match.
+        let mut decoder = Decoder::new(&data, DecoderOptions::der());
+        assert_eq!(None, <Option<&str>>::decode_borrowed(&mut decoder).unwrap());
+        assert_eq!(
+            Some(&data[2..]),
+            <Option<&[u8]>>::decode_borrowed(&mut decoder).unwrap()
+        );
+
+        // Segments can't be borrowed as one slice.
+        let constructed = [0x24, 0x04, 0x04, 0x02, 0xAA, 0xBB];
+        assert!(matches!(
+            crate::ber::decode_borrowed::<&[u8]>(&constructed),
+            Err(Error::ConstructedEncodingNotAllowed)
+        ));
+        assert!(matches!(
+            crate::ber::decode_borrowed::<&str>(&[0x0C, 0x01, 0xFF]),
+            Err(Error::InvalidUtf8)
+        ));
+    }
+
+    #[test]
+    fn definite_constructed_strings() {
+        let two_segments = &[0x24, 0x08, 0x04, 0x02, 1, 2, 0x04, 0x02, 3, 4];
+        assert_eq!(
+            alloc::vec![1, 2, 3, 4],
+            decode::<types::OctetString>(two_segments).unwrap()
+        );
+        assert!(matches!(
+            crate::der::decode::<types::OctetString>(two_segments),
+            Err(Error::ConstructedEncodingNotAllowed)
+        ));
+
+        // Segments can be constructed themselves, and always have the
+        // universal tag, even when the string is implicitly tagged.
+        let nested = &[0x24, 0x0A, 0x24, 0x04, 0x04, 0x02, 1, 2, 0x04, 0x02, 3, 4];
+        assert_eq!(
+            alloc::vec![1, 2, 3, 4],
+            decode::<types::OctetString>(nested).unwrap()
+        );
+        let tagged = &[0xA2, 0x08, 0x04, 0x02, 1, 2, 0x04, 0x02, 3, 4];
+        assert_eq!(
+            alloc::vec![1, 2, 3, 4],
+            decode::<types::Implicit<C2, types::OctetString>>(tagged)
+                .unwrap()
+                .value
+        );
+        assert!(decode::<types::Implicit<C2, types::OctetString>>(&[
+            0xA2, 0x04, 0xA2, 0x02, 0x04, 0x00
+        ])
+        .is_err());
+
+        // A zero byte that ends a segment other than the last is kept.
+        let bit_string: types::BitString = decode(
+            &[
+                0x23, 0x09, // TAG + LENGTH
+                0x03, 0x03, 0x00, 0x0A, 0x00, // Part 1
+                0x03, 0x02, 0x04, 0x50, // Part 2
+            ][..],
+        )
+        .unwrap();
+        let mut expected = types::BitString::from_vec(alloc::vec![0x0A, 0x00, 0x50]);
+        expected.truncate(20);
+        assert_eq!(expected, bit_string);
+        assert_eq!(
+            types::BitString::new(),
+            decode::<types::BitString>(&[0x23, 0x06, 0x03, 0x01, 0x00, 0x03, 0x01, 0x00]).unwrap()
+        );
     }
 
     #[test]
@@ -621,4 +1612,138 @@ mod tests {
         let oid = oid.unwrap();
         assert_eq!(ObjectIdentifier::new([2, 999, 1].to_vec()).unwrap(), oid);
     }
+
+    #[test]
+    fn non_minimal_oid_arcs() {
+        use crate::Decoder;
+
+        let decode = |data: &[u8], options| {
+            super::Decoder::new(data, options).decode_object_identifier(Tag::OBJECT_IDENTIFIER)
+        };
+
+        // `88 00` is the minimal encoding of 1024, the first two arcs of
+        // 2.944.1, while `80 2A` pads 42 for 1.2.1 with a leading `0x80`.
+        let oid = ObjectIdentifier::new([2, 944, 1].to_vec()).unwrap();
+        assert_eq!(
+            oid,
+            decode(&[0x06, 0x03, 0x88, 0x00, 0x01], DecoderOptions::der()).unwrap()
+        );
+        assert!(matches!(
+            decode(&[0x06, 0x03, 0x80, 0x2A, 0x01], DecoderOptions::der()),
+            Err(Error::NotCanonical { .. })
+        ));
+        assert!(decode(&[0x06, 0x04, 0x2A, 0x80, 0x80, 0x01], DecoderOptions::cer()).is_err());
+        assert_eq!(
+            ObjectIdentifier::new([1, 2, 1].to_vec()).unwrap(),
+            decode(&[0x06, 0x03, 0x80, 0x2A, 0x01], DecoderOptions::ber()).unwrap()
+        );
+
+        // A final arc with its continuation bit set is truncated.
+        for options in [DecoderOptions::ber(), DecoderOptions::der()] {
+            assert!(matches!(
+                decode(&[0x06, 0x02, 0x2A, 0x81], options),
+                Err(Error::InvalidObjectIdentifier)
+            ));
+            assert!(decode(&[0x06, 0x00], options).is_err());
+        }
+    }
+
+    #[test]
+    fn peek() {
+        use crate::Decoder;
+
+        let data = &[0xA1, 0x03, 0x01, 0x01, 0xFF, 0x43, 0x01, 0x05];
+        let mut decoder = super::Decoder::new(data, DecoderOptions::der());
+
+        assert_eq!(Tag::new(Class::Context, 1), decoder.peek_tag().unwrap());
+        assert!(decoder.peek_is_constructed().unwrap());
+        decoder.decode_any().unwrap();
+
+        assert_eq!(Tag::new(Class::Application, 3), decoder.peek_tag().unwrap());
+        assert!(!decoder.peek_is_constructed().unwrap());
+        assert_eq!(
+            Integer::from(5),
+            decoder
+                .decode_integer(Tag::new(Class::Application, 3))
+                .unwrap()
+        );
+
+        assert!(decoder.peek_tag().is_err());
+        assert!(decoder.peek_is_constructed().is_err());
+    }
+
+    #[test]
+    fn indefinite_length_any() {
+        use crate::Decoder;
+
+        let data = &[0x30, 0x80, 0x01, 0x01, 0xFF, 0x00, 0x00, 0x05, 0x00];
+        let mut decoder = super::Decoder::new(data, DecoderOptions::ber());
+
+        assert_eq!(&data[..7], &*decoder.decode_any().unwrap().contents);
+        decoder.decode_null(Tag::NULL).unwrap();
+
+        let nested = &[
+            0x30, 0x80, 0x30, 0x80, 0x01, 0x01, 0xFF, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let mut decoder = super::Decoder::new(nested, DecoderOptions::ber());
+        decoder.parse_value(Tag::SEQUENCE).unwrap();
+        let fields = decoder.decode_unknown_fields().unwrap();
+        assert_eq!(
+            alloc::vec![(Tag::SEQUENCE, &data[..7])],
+            fields.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(EOC, decoder.input);
+    }
+
+    #[test]
+    fn output_limits() {
+        // Ten sequences of fifty `NULL`s, which are 500 elements of two bytes
+        // each in the input, that take up no memory once decoded.
+        let mut data = alloc::vec![0x30, 0x82, 0x03, 0xFC];
+        for _ in 0..10 {
+            data.extend_from_slice(&[0x30, 100]);
+            data.extend(core::iter::repeat_n([0x05, 0x00], 50).flatten());
+        }
+
+        let decode_with = |options: DecoderOptions| {
+            let mut decoder = Decoder::new(&data, options);
+            <Vec<Vec<()>>>::decode(&mut decoder)
+        };
+
+        assert_eq!(10, decode_with(DecoderOptions::ber()).unwrap().len());
+        assert_eq!(
+            10,
+            decode_with(DecoderOptions::ber().max_output_elements(510))
+                .unwrap()
+                .len()
+        );
+        assert!(matches!(
+            decode_with(DecoderOptions::ber().max_output_elements(100)),
+            Err(Error::ExceedsOutputLimit { limit: 100, .. })
+        ));
+
+        let mut decoder = Decoder::new(&data, DecoderOptions::ber().max_output_elements(100));
+        let mut sequences = decoder
+            .decode_sequence_of_iter::<Vec<()>>(Tag::SEQUENCE)
+            .unwrap();
+        assert_eq!(50, sequences.next().unwrap().unwrap().len());
+        assert!(sequences.next().unwrap().is_err());
+
+        let strings = &[0x30, 0x08, 0x04, 0x03, 1, 2, 3, 0x04, 0x01, 4];
+        let decode_with = |options: DecoderOptions| {
+            let mut decoder = Decoder::new(strings, options);
+            <Vec<OctetString>>::decode(&mut decoder)
+        };
+
+        assert_eq!(
+            2,
+            decode_with(DecoderOptions::ber().max_total_bytes(4))
+                .unwrap()
+                .len()
+        );
+        assert!(matches!(
+            decode_with(DecoderOptions::der().max_total_bytes(3)),
+            Err(Error::ExceedsOutputLimit { limit: 3, .. })
+        ));
+    }
 }
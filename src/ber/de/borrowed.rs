@@ -0,0 +1,232 @@
+//! A decoder that borrows from its input instead of allocating.
+
+use super::{error, parser, DecoderOptions, Error, Identifier, Result};
+use crate::{de::Error as _, types::Tag};
+
+/// A BER, CER, and DER decoder that never allocates when decoding valid
+/// data, for code that can't touch the heap once it's running. The crate
+/// itself still requires `alloc`. It only supports the subset
+/// of types that can be represented by borrowing from the input or as
+/// fixed-size values, so constructed strings, indefinite lengths, and
+/// arbitrarily large integers are not supported.
+///
+/// **Note** Errors that occur while decoding may still allocate their
+/// message.
+#[derive(Clone, Debug)]
+pub struct BorrowedDecoder<'input> {
+    input: &'input [u8],
+    config: DecoderOptions,
+}
+
+impl<'input> BorrowedDecoder<'input> {
+    /// Create a new [`BorrowedDecoder`] from the given `input` and `config`.
+    pub fn new(input: &'input [u8], config: DecoderOptions) -> Self {
+        Self { input, config }
+    }
+
+    /// Returns the input that has not been decoded yet.
+    pub fn remaining(&self) -> &'input [u8] {
+        self.input
+    }
+
+    /// Whether all of the input has been decoded.
+    pub fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    /// Returns the tag of the next value, without decoding it.
+    pub fn peek_tag(&self) -> Option<Tag> {
+        parser::parse_identifier_octet(self.input)
+            .ok()
+            .map(|(_, identifier)| identifier.tag)
+    }
+
+    /// Decodes the next value with any tag, returning its identifier and its
+    /// contents.
+    pub fn decode_raw(&mut self) -> Result<(Identifier, &'input [u8])> {
+        self.parse_value(None)
+    }
+
+    /// Decodes a `BOOL` value.
+    pub fn decode_bool(&mut self, tag: Tag) -> Result<bool> {
+        let contents = self.parse_primitive_value(tag)?;
+        error::assert_length(1, contents.len())?;
+        match contents[0] {
+            0 => Ok(false),
+            0xFF => Ok(true),
+            _ if self.config.encoding_rules.is_ber() => Ok(true),
+            _ => Err(Error::InvalidBool),
+        }
+    }
+
+    /// Decodes a `NULL` value.
+    pub fn decode_null(&mut self, tag: Tag) -> Result<()> {
+        let contents = self.parse_primitive_value(tag)?;
+        error::assert_length(0, contents.len())
+    }
+
+    /// Decodes an `INTEGER` value that fits in an `i64`.
+    pub fn decode_i64(&mut self, tag: Tag) -> Result<i64> {
+        let contents = self.parse_primitive_value(tag)?;
+        super::assert_minimal_integer(&self.config, contents)?;
+
+        if contents.len() > core::mem::size_of::<i64>() {
+            return Err(Error::IntegerOverflow { max_width: 64 });
+        }
+
+        let sign_extension = match contents.first() {
+            Some(byte) if byte & 0x80 != 0 => -1,
+            _ => 0,
+        };
+
+        Ok(contents.iter().fold(sign_extension, |number, byte| {
+            number << 8 | i64::from(*byte)
+        }))
+    }
+
+    /// Decodes a primitive `OCTET STRING` value, borrowing its contents from
+    /// the input.
+    pub fn decode_octet_string_borrowed(&mut self, tag: Tag) -> Result<&'input [u8]> {
+        self.parse_primitive_value(tag)
+    }
+
+    /// Decodes a primitive `UTF8String` value, borrowing its contents from the
+    /// input.
+    pub fn decode_utf8_string_borrowed(&mut self, tag: Tag) -> Result<&'input str> {
+        core::str::from_utf8(self.parse_primitive_value(tag)?).map_err(|_| Error::InvalidUtf8)
+    }
+
+    /// Decodes a primitive `UTF8String` value into a `heapless::String`,
+    /// failing with [`Error::ExceedsMaxLength`] if it's longer than `N`
+    /// bytes.
+    #[cfg(feature = "heapless")]
+    pub fn decode_heapless_string<const N: usize>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<heapless::String<N>> {
+        let mut string = heapless::String::new();
+        string
+            .push_str(self.decode_utf8_string_borrowed(tag)?)
+            .map_err(|_| Error::ExceedsMaxLength { length: N })?;
+        Ok(string)
+    }
+
+    /// Decodes a primitive `UTF8String` value into an `arrayvec::ArrayString`,
+    /// failing with [`Error::ExceedsMaxLength`] if it's longer than `N`
+    /// bytes.
+    #[cfg(feature = "arrayvec")]
+    pub fn decode_array_string<const N: usize>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<arrayvec::ArrayString<N>> {
+        arrayvec::ArrayString::from(self.decode_utf8_string_borrowed(tag)?)
+            .map_err(|_| Error::ExceedsMaxLength { length: N })
+    }
+
+    /// Decodes a `SEQUENCE OF` value into a `heapless::Vec`, decoding each
+    /// element with `decode_element`, and failing with
+    /// [`Error::ExceedsMaxLength`] if there are more than `N` elements.
+    /// ```
+    /// use rasn::{ber::de::{BorrowedDecoder, DecoderOptions}, types::Tag};
+    ///
+    /// let data = [0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02];
+    /// let mut decoder = BorrowedDecoder::new(&data, DecoderOptions::der());
+    /// let values: heapless::Vec<i64, 4> = decoder
+    ///     .decode_heapless_vec(Tag::SEQUENCE, |decoder| decoder.decode_i64(Tag::INTEGER))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(&[1, 2], &*values);
+    /// ```
+    #[cfg(feature = "heapless")]
+    pub fn decode_heapless_vec<T, const N: usize>(
+        &mut self,
+        tag: Tag,
+        mut decode_element: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<heapless::Vec<T, N>> {
+        let mut sequence = self.decode_sequence(tag)?;
+        let mut items = heapless::Vec::new();
+
+        while !sequence.is_empty() {
+            items
+                .push((decode_element)(&mut sequence)?)
+                .map_err(|_| Error::ExceedsMaxLength { length: N })?;
+        }
+
+        Ok(items)
+    }
+
+    /// Decodes a `SEQUENCE` value, returning a decoder over its fields.
+    pub fn decode_sequence(&mut self, tag: Tag) -> Result<SequenceDecoder<'input>> {
+        let (identifier, contents) = self.parse_value(Some(tag))?;
+
+        if identifier.is_primitive() {
+            return Err(Error::custom("Expected constructed `SEQUENCE`"));
+        }
+
+        Ok(SequenceDecoder {
+            decoder: Self::new(contents, self.config),
+        })
+    }
+
+    fn parse_value(&mut self, tag: Option<Tag>) -> Result<(Identifier, &'input [u8])> {
+        let (input, (identifier, contents)) = parser::parse_value(&self.config, self.input, tag)?;
+        let contents = contents.ok_or(Error::IndefiniteLengthNotAllowed)?;
+        self.input = input;
+        Ok((identifier, contents))
+    }
+
+    fn parse_primitive_value(&mut self, tag: Tag) -> Result<&'input [u8]> {
+        let (identifier, contents) = self.parse_value(Some(tag))?;
+
+        if identifier.is_constructed() {
+            return Err(Error::ConstructedEncodingNotAllowed);
+        }
+
+        Ok(contents)
+    }
+}
+
+/// A decoder over the fields of a `SEQUENCE` created by
+/// [`BorrowedDecoder::decode_sequence`]. Fields can be decoded in order
+/// through [`Deref`][core::ops::Deref] to a [`BorrowedDecoder`], or iterated
+/// over as raw values.
+#[derive(Clone, Debug)]
+pub struct SequenceDecoder<'input> {
+    decoder: BorrowedDecoder<'input>,
+}
+
+impl<'input> SequenceDecoder<'input> {
+    /// Checks that every field in the sequence has been decoded.
+    pub fn finish(self) -> Result<()> {
+        match self.decoder.input.len() {
+            0 => Ok(()),
+            length => Err(Error::UnexpectedExtraData { length }),
+        }
+    }
+}
+
+impl<'input> core::ops::Deref for SequenceDecoder<'input> {
+    type Target = BorrowedDecoder<'input>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.decoder
+    }
+}
+
+impl<'input> core::ops::DerefMut for SequenceDecoder<'input> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.decoder
+    }
+}
+
+impl<'input> Iterator for SequenceDecoder<'input> {
+    type Item = Result<(Identifier, &'input [u8])>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.decoder.is_empty() {
+            None
+        } else {
+            Some(self.decoder.decode_raw())
+        }
+    }
+}
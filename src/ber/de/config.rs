@@ -1,9 +1,18 @@
 use crate::ber::EncodingRules;
 
+/// Two digit `UTCTime` years below this are in the 21st century (RFC 5280,
+/// section 4.1.2.5.1).
+pub(crate) const RFC_5280_UTC_TIME_PIVOT: u8 = 50;
+
 /// The options for the [`Decoder`][super::Decoder].
 #[derive(Clone, Copy, Debug)]
 pub struct DecoderOptions {
     pub(crate) encoding_rules: EncodingRules,
+    pub(crate) utc_time_pivot: u8,
+    pub(crate) require_complete: bool,
+    pub(crate) strict_fractional_seconds: bool,
+    pub(crate) max_total_bytes: Option<usize>,
+    pub(crate) max_output_elements: Option<usize>,
 }
 
 impl DecoderOptions {
@@ -11,6 +20,11 @@ impl DecoderOptions {
     pub const fn ber() -> Self {
         Self {
             encoding_rules: EncodingRules::Ber,
+            utc_time_pivot: RFC_5280_UTC_TIME_PIVOT,
+            require_complete: true,
+            strict_fractional_seconds: false,
+            max_total_bytes: None,
+            max_output_elements: None,
         }
     }
 
@@ -18,6 +32,11 @@ impl DecoderOptions {
     pub const fn cer() -> Self {
         Self {
             encoding_rules: EncodingRules::Cer,
+            utc_time_pivot: RFC_5280_UTC_TIME_PIVOT,
+            require_complete: true,
+            strict_fractional_seconds: false,
+            max_total_bytes: None,
+            max_output_elements: None,
         }
     }
 
@@ -25,6 +44,73 @@ impl DecoderOptions {
     pub const fn der() -> Self {
         Self {
             encoding_rules: EncodingRules::Der,
+            utc_time_pivot: RFC_5280_UTC_TIME_PIVOT,
+            require_complete: true,
+            strict_fractional_seconds: false,
+            max_total_bytes: None,
+            max_output_elements: None,
+        }
+    }
+
+    /// Sets the two digit year that `UTCTime` values are pivoted around, so
+    /// years below `pivot` are read as `20YY` and the rest as `19YY`. The
+    /// default is `50`, as required by RFC 5280.
+    ///
+    /// # Panics
+    /// If `pivot` is greater than 100.
+    pub const fn utc_time_pivot(self, pivot: u8) -> Self {
+        assert!(pivot <= 100, "`UTCTime` pivot must be at most 100");
+
+        Self {
+            utc_time_pivot: pivot,
+            ..self
+        }
+    }
+
+    /// Sets whether decoding a value with
+    /// [`ber::decode_with_options`][crate::ber::decode_with_options] fails if
+    /// there's any input left after the value. The default is `true`; with
+    /// `false` any trailing bytes, such as padding, are ignored, as
+    /// `decode_partial` does.
+    pub const fn require_complete(self, require_complete: bool) -> Self {
+        Self {
+            require_complete,
+            ..self
+        }
+    }
+
+    /// Sets whether a `GeneralizedTime` with more than nine digits of
+    /// fractional seconds, which is more precise than a nanosecond, fails to
+    /// decode. The default is `false`, which truncates the fraction to whole
+    /// nanoseconds instead.
+    pub const fn strict_fractional_seconds(self, strict: bool) -> Self {
+        Self {
+            strict_fractional_seconds: strict,
+            ..self
+        }
+    }
+
+    /// Sets the most bytes that decoding a value can copy out of the input
+    /// into strings (such as `OCTET STRING`, `BIT STRING`, and `UTF8String`)
+    /// and `Any`s, in total, failing once it's exceeded. This includes
+    /// values decoded while trying alternatives that didn't match, such as an
+    /// absent `OPTIONAL` field. The default is no limit.
+    pub const fn max_total_bytes(self, limit: usize) -> Self {
+        Self {
+            max_total_bytes: Some(limit),
+            ..self
+        }
+    }
+
+    /// Sets the most elements that decoding a value can collect into
+    /// `SEQUENCE OF`s and `SET OF`s, in total across every nested sequence,
+    /// failing once it's exceeded. Together with
+    /// [`Self::max_total_bytes`] this bounds the memory used when decoding
+    /// untrusted input. The default is no limit.
+    pub const fn max_output_elements(self, limit: usize) -> Self {
+        Self {
+            max_output_elements: Some(limit),
+            ..self
         }
     }
 }
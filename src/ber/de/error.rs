@@ -12,6 +12,9 @@ pub enum Error {
         /// Amount of bytes needed.
         needed: nom::Needed,
     },
+    /// The declared length of a value is larger than can be addressed on
+    /// the current platform.
+    LengthOverflow,
     /// Constructed encoding encountered but not allowed.
     ConstructedEncodingNotAllowed,
     /// Indefinite length encountered but not allowed.
@@ -24,6 +27,12 @@ pub enum Error {
     InvalidUtf8,
     /// Invalid date.
     InvalidDate,
+    /// Invalid or unsupported `REAL`.
+    InvalidReal,
+    /// Invalid duration.
+    InvalidDuration,
+    /// Invalid OID internationalized resource identifier.
+    InvalidIri,
     /// Custom error in the parser.
     #[snafu(display("Error in Parser: {}", msg))]
     Parser {
@@ -58,6 +67,14 @@ pub enum Error {
         /// The maximum length.
         length: usize,
     },
+    /// The decoded values are larger than the decoder's options allow.
+    #[snafu(display("Decoded output exceeds the limit of {} {}", limit, unit))]
+    ExceedsOutputLimit {
+        /// The limit that was exceeded.
+        limit: usize,
+        /// What the limit counts, `bytes` or `elements`.
+        unit: &'static str,
+    },
     /// The actual integer exceeded the expected width.
     #[snafu(display("Actual integer larger than expected {} bits", max_width))]
     IntegerOverflow {
@@ -70,6 +87,19 @@ pub enum Error {
         /// The amount of invalid bits.
         bits: u8,
     },
+    /// The value was not encoded in the canonical form required by the
+    /// encoding rules.
+    #[snafu(display("`{}` value is not canonically encoded", name))]
+    NotCanonical {
+        /// The name of the type.
+        name: &'static str,
+    },
+    /// A `SEQUENCE` component appeared out of the order declared by its type.
+    #[snafu(display("`SEQUENCE` component with {:?} tag is out of order", tag))]
+    OutOfOrder {
+        /// The tag of the component.
+        tag: Tag,
+    },
     /// Expected a certain field, which was not present.
     #[snafu(display("Expected required field `{}`", name))]
     MissingField {
@@ -90,6 +120,24 @@ pub enum Error {
         /// The error that occurred.
         error: alloc::string::String,
     },
+    /// An error that occurred while decoding a nested value, such as a field
+    /// of a `SEQUENCE`.
+    #[snafu(display("{}: {}", DisplayPath(path), error))]
+    Context {
+        /// Where the error occurred, from the innermost value outwards.
+        path: alloc::vec::Vec<&'static str>,
+        /// The error that occurred.
+        error: alloc::boxed::Box<Error>,
+    },
+    /// An error that occurred while decoding one of several consecutive
+    /// values.
+    #[snafu(display("Element {}: {}", index, error))]
+    ElementError {
+        /// The index of the value.
+        index: usize,
+        /// The error that occurred.
+        error: alloc::boxed::Box<Error>,
+    },
     /// A custom error.
     #[snafu(display("{}", msg))]
     Custom {
@@ -98,6 +146,23 @@ pub enum Error {
     },
 }
 
+/// Displays a context path from the outermost value inwards.
+struct DisplayPath<'a>(&'a [&'static str]);
+
+impl core::fmt::Display for DisplayPath<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        for (index, context) in self.0.iter().rev().enumerate() {
+            if index != 0 {
+                f.write_str(" > ")?;
+            }
+
+            f.write_str(context)?;
+        }
+
+        Ok(())
+    }
+}
+
 pub(crate) fn assert_tag(expected: Tag, actual: Tag) -> super::Result<()> {
     if expected != actual {
         Err(Error::MismatchedTag { expected, actual })
@@ -149,6 +214,19 @@ impl crate::de::Error for Error {
         }
     }
 
+    fn context(self, context: &'static str) -> Self {
+        match self {
+            Self::Context { mut path, error } => {
+                path.push(context);
+                Self::Context { path, error }
+            }
+            error => Self::Context {
+                path: alloc::vec![context],
+                error: alloc::boxed::Box::new(error),
+            },
+        }
+    }
+
     fn duplicate_field(name: &'static str) -> Self {
         Self::FieldError {
             name,
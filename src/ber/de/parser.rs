@@ -1,7 +1,6 @@
 use alloc::vec::Vec;
 
 use nom::IResult;
-use num_traits::ToPrimitive;
 
 use super::{error, DecoderOptions};
 use crate::{
@@ -21,19 +20,56 @@ pub(crate) fn parse_value<'config, 'input>(
     }
 
     let (input, contents) =
-        parse_contents(config, identifier, input).map_err(error::map_nom_err)?;
+        parse_contents(config, identifier, input).map_err(|error| match error {
+            nom::Err::Failure(error) if error.code == nom::error::ErrorKind::TooLarge => {
+                error::Error::LengthOverflow
+            }
+            error => error::map_nom_err(error),
+        })?;
 
     Ok((input, (identifier, contents)))
 }
 
-pub(crate) fn parse_encoded_value<'config, 'input, RV>(
+/// Parses past the whole value at the start of `input`, returning the input
+/// after it. The contents of indefinite length values are scanned up to their
+/// matching end-of-contents marker, keeping count of the nesting depth rather
+/// than recursing, so deeply nested input can't overflow the stack.
+pub(crate) fn skip_value<'input>(
+    config: &DecoderOptions,
+    mut input: &'input [u8],
+) -> super::Result<&'input [u8]> {
+    const EOC: &[u8] = &[0, 0];
+    let mut depth = 0usize;
+
+    loop {
+        if depth > 0 && input.starts_with(EOC) {
+            input = &input[EOC.len()..];
+            depth -= 1;
+        } else {
+            let (rest, (_, contents)) = parse_value(config, input, None)?;
+            input = rest;
+
+            if contents.is_none() {
+                depth += 1;
+            }
+        }
+
+        if depth == 0 {
+            return Ok(input);
+        }
+    }
+}
+
+pub(crate) fn parse_encoded_value<'config, 'input, RV, F>(
     config: &'config DecoderOptions,
     slice: &'input [u8],
     tag: Tag,
-    primitive_callback: fn(&'input [u8]) -> super::Result<RV>,
+    segment_tag: Tag,
+    primitive_callback: F,
 ) -> super::Result<(&'input [u8], RV)>
 where
     RV: Appendable,
+    F: Fn(&'input [u8]) -> super::Result<RV> + Copy,
 {
     let (input, (identifier, contents)) = parse_value(config, slice, Some(tag))?;
 
@@ -43,20 +79,33 @@ where
         let mut container = RV::new();
         let mut input = input;
 
+        // The segments of a constructed string always have the string type's
+        // universal tag, even if the string itself has a different tag, and
+        // can themselves be constructed.
         match contents {
-            Some(contents) => {
+            Some(mut contents) => {
                 while !contents.is_empty() {
-                    let (i, mut child) =
-                        parse_encoded_value(config, input, tag, primitive_callback)?;
-                    input = i;
+                    let (c, mut child) = parse_encoded_value(
+                        config,
+                        contents,
+                        segment_tag,
+                        segment_tag,
+                        primitive_callback,
+                    )?;
+                    contents = c;
                     container.append(&mut child);
                 }
             }
             None => {
                 const EOC: &[u8] = &[0, 0];
                 while !input.is_empty() && !input.starts_with(EOC) {
-                    let (i, mut child) =
-                        parse_encoded_value(config, input, tag, primitive_callback)?;
+                    let (i, mut child) = parse_encoded_value(
+                        config,
+                        input,
+                        segment_tag,
+                        segment_tag,
+                        primitive_callback,
+                    )?;
                     input = i;
                     container.append(&mut child);
                 }
@@ -85,17 +134,7 @@ pub(crate) fn parse_identifier_octet(input: &[u8]) -> IResult<&[u8], Identifier>
     }
 
     let (input, tag) = if identifier.tag.value >= 0x1f {
-        let (input, tag) = parse_encoded_number(input)?;
-
-        match tag.to_u32() {
-            Some(value) => (input, value),
-            None => {
-                return Err(nom::Err::Failure(<_>::from_error_kind(
-                    input,
-                    nom::error::ErrorKind::TooLarge,
-                )));
-            }
-        }
+        parse_encoded_number(input)?
     } else {
         (input, identifier.tag.value)
     };
@@ -103,11 +142,25 @@ pub(crate) fn parse_identifier_octet(input: &[u8]) -> IResult<&[u8], Identifier>
     Ok((input, identifier.tag(tag)))
 }
 
-pub fn parse_encoded_number(input: &[u8]) -> IResult<&[u8], Integer> {
+/// Parses a series of 7 bit numbers delimited by `1`'s and ended by a `0` in
+/// the 8th bit, failing if the number doesn't fit in a `u32`.
+pub fn parse_encoded_number(input: &[u8]) -> IResult<&[u8], u32> {
+    use nom::error::ParseError;
+
     let (input, body) = nom::bytes::streaming::take_while(|i| i & 0x80 != 0)(input)?;
     let (input, end) = nom::bytes::streaming::take(1usize)(input)?;
 
-    Ok((input, concat_number(body, end[0])))
+    body.iter()
+        .chain(end)
+        .try_fold(0u32, |number, byte| {
+            number
+                .checked_mul(1 << 7)
+                .map(|number| number | u32::from(byte & 0x7F))
+        })
+        .map(|number| (input, number))
+        .ok_or_else(|| {
+            nom::Err::Failure(<_>::from_error_kind(input, nom::error::ErrorKind::TooLarge))
+        })
 }
 
 pub fn parse_base128_number(input: &[u8]) -> IResult<&[u8], Integer> {
@@ -185,24 +238,6 @@ impl Appendable for crate::types::BitString {
     }
 }
 
-/// Concatenates a series of 7 bit numbers delimited by `1`'s and
-/// ended by a `0` in the 8th bit.
-fn concat_number(body: &[u8], start: u8) -> Integer {
-    let start = Integer::from(start);
-    if body.is_empty() {
-        return start;
-    }
-
-    let mut number = Integer::from(body[0] & 0x7F);
-
-    for byte in body[1..].iter() {
-        number <<= 7usize;
-        number |= Integer::from(byte & 0x7F);
-    }
-
-    (number << 7usize) | start
-}
-
 fn take_contents(input: &[u8], length: u8) -> IResult<&[u8], &[u8]> {
     match length {
         0xff => nom::error::context("Reserved Length Octet found.", |_| {
@@ -216,17 +251,21 @@ fn take_contents(input: &[u8], length: u8) -> IResult<&[u8], &[u8]> {
         _ => {
             let length = length ^ 0x80;
             let (input, length_slice) = nom::bytes::streaming::take(length)(input)?;
-            let length = Integer::from_bytes_be(num_bigint::Sign::Plus, length_slice).to_usize();
-
-            if let Some(length) = length {
-                nom::bytes::streaming::take(length)(input)
-            } else {
-                nom::error::context("Length longer than possible capacity.", |_| {
-                    Err(nom::Err::Failure(nom::error::Error::new(
-                        input,
-                        nom::error::ErrorKind::Tag,
-                    )))
-                })(input)
+            let length = length_slice.iter().try_fold(0usize, |length, byte| {
+                length
+                    .checked_mul(1 << 8)
+                    .map(|length| length | usize::from(*byte))
+            });
+
+            // A length that doesn't fit in `usize` can never be satisfied by
+            // the input, so it's reported as an overflow rather than being
+            // treated as incomplete input.
+            match length {
+                Some(length) => nom::bytes::streaming::take(length)(input),
+                None => Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::TooLarge,
+                ))),
             }
         }
     }
@@ -279,4 +318,42 @@ mod tests {
         assert!(parse_value(&DER_OPTIONS, bytes, Tag::SEQUENCE.into()).is_err());
         assert!(parse_value(&CER_OPTIONS, bytes, Tag::SEQUENCE.into()).is_ok());
     }
+
+    #[test]
+    fn value_length_overflow() {
+        // A length of 2^40 bytes, which only fits in a 64-bit `usize`.
+        let bytes = [0x4, 0x86, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0xFF];
+        let result = parse_value(&BER_OPTIONS, &bytes, Tag::OCTET_STRING.into());
+
+        if cfg!(target_pointer_width = "64") {
+            assert!(matches!(
+                result,
+                Err(error::Error::Incomplete {
+                    needed: nom::Needed::Size(_)
+                })
+            ));
+        } else {
+            assert!(matches!(result, Err(error::Error::LengthOverflow)));
+        }
+
+        // Nine length octets can't fit in any `usize`.
+        let bytes = [0x4, 0x89, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xFF];
+        let result = parse_value(&BER_OPTIONS, &bytes, Tag::OCTET_STRING.into());
+        assert!(matches!(result, Err(error::Error::LengthOverflow)));
+    }
+
+    #[test]
+    fn skip_nested_indefinite_length() {
+        let bytes = [
+            0x30, 0x80, 0x30, 0x80, 0x01, 0x01, 0xFF, 0, 0, 0x02, 0x01, 0x05, 0, 0, 0x05, 0x00,
+        ];
+
+        assert_eq!(&[0x05, 0x00], skip_value(&BER_OPTIONS, &bytes).unwrap());
+        assert!(skip_value(&BER_OPTIONS, &bytes[..14]).is_ok());
+        assert!(matches!(
+            skip_value(&BER_OPTIONS, &bytes[..12]),
+            Err(error::Error::Incomplete { .. })
+        ));
+        assert!(skip_value(&DER_OPTIONS, &bytes).is_err());
+    }
 }
@@ -0,0 +1,298 @@
+//! Decoding a `SEQUENCE OF` one element at a time.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use super::{error, Decoder, Error, Result};
+use crate::{de::Error as _, types::Tag, Decode};
+
+impl<'input> Decoder<'input> {
+    /// Decodes a `SEQUENCE OF D` identified by `tag`, returning an iterator
+    /// that decodes each element as it's requested, instead of collecting the
+    /// whole sequence.
+    ///
+    /// Iteration stops at the end of the sequence, or after the first error.
+    /// Once the iterator has been dropped, the decoder continues after the
+    /// end of the sequence, even if not every element was decoded.
+    /// ```
+    /// use rasn::{ber::de::{Decoder, DecoderOptions}, types::Tag};
+    ///
+    /// let data = [0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x01, 0x01, 0xFF];
+    /// let mut decoder = Decoder::new(&data, DecoderOptions::ber());
+    ///
+    /// let first = decoder.decode_sequence_of_iter::<u8>(Tag::SEQUENCE).unwrap().next();
+    /// assert_eq!(Some(1), first.transpose().unwrap());
+    /// assert!(rasn::Decoder::decode_bool(&mut decoder, Tag::BOOL).unwrap());
+    /// ```
+    pub fn decode_sequence_of_iter<D: Decode>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<SequenceOfIter<'_, 'input, D>> {
+        let (identifier, contents) = self.parse_value(tag)?;
+
+        error::assert_tag(tag, identifier.tag)?;
+
+        if identifier.is_primitive() {
+            return Err(Error::custom("Invalid constructed identifier"));
+        }
+
+        let contents = match contents {
+            Some(contents) => {
+                let offset = self.offset() - contents.len();
+                Contents::Definite(self.nested(contents, offset), self)
+            }
+            None => Contents::Indefinite(self),
+        };
+
+        Ok(SequenceOfIter {
+            contents,
+            done: false,
+            _element: PhantomData,
+        })
+    }
+
+    /// Decodes a `SEQUENCE OF D` identified by `tag`, skipping over the
+    /// elements that fail to decode instead of failing the whole sequence.
+    /// Returns the elements that were decoded, along with the index and
+    /// error of each element that wasn't.
+    ///
+    /// **Note** A failed element is skipped by its length, so this only works
+    /// reliably for definite length elements. The end of an indefinite length
+    /// element can only be found by parsing its contents, which may be what's
+    /// malformed. An element that can't be skipped ends the sequence, which
+    /// is an error if the sequence itself has an indefinite length, as its
+    /// end can't be found either.
+    /// ```
+    /// use rasn::{ber::de::{Decoder, DecoderOptions}, types::Tag};
+    ///
+    /// let data = [0x30, 0x09, 0x02, 0x01, 0x01, 0x01, 0x01, 0xFF, 0x02, 0x01, 0x03];
+    /// let mut decoder = Decoder::new(&data, DecoderOptions::der());
+    ///
+    /// let (elements, errors) = decoder.decode_sequence_of_lenient::<u8>(Tag::SEQUENCE).unwrap();
+    /// assert_eq!(vec![1, 3], elements);
+    /// assert_eq!(1, errors[0].0);
+    /// ```
+    pub fn decode_sequence_of_lenient<D: Decode>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<(Vec<D>, ElementErrors)> {
+        let (identifier, contents) = self.parse_value(tag)?;
+
+        error::assert_tag(tag, identifier.tag)?;
+
+        if identifier.is_primitive() {
+            return Err(Error::custom("Invalid constructed identifier"));
+        }
+
+        let is_indefinite = contents.is_none();
+        let offset = self.offset();
+        let mut nested = contents.map(|contents| self.nested(contents, offset - contents.len()));
+        let decoder = nested.as_mut().unwrap_or(&mut *self);
+        let mut elements = Vec::new();
+        let mut errors = Vec::new();
+
+        loop {
+            if is_indefinite && decoder.is_at_end() {
+                decoder.parse_eoc()?;
+                break;
+            } else if decoder.input.is_empty() {
+                break;
+            }
+
+            let input = decoder.input;
+            let error = match decode_element(decoder) {
+                Ok(element) => {
+                    elements.push(element);
+                    continue;
+                }
+                Err(error) => error,
+            };
+            decoder.check_output_limits()?;
+            errors.push((elements.len() + errors.len(), error));
+
+            decoder.input = input;
+            if let Err(error) = decoder.skip_value() {
+                if is_indefinite {
+                    return Err(error);
+                }
+
+                break;
+            }
+        }
+
+        if let Some(nested) = &nested {
+            self.resume_from(nested);
+        }
+
+        Ok((elements, errors))
+    }
+}
+
+/// The index and error of each element that failed to decode, returned by
+/// [`Decoder::decode_sequence_of_lenient`].
+pub type ElementErrors = Vec<(usize, Error)>;
+
+/// An iterator over the elements of a `SEQUENCE OF`, created by
+/// [`Decoder::decode_sequence_of_iter`].
+pub struct SequenceOfIter<'decoder, 'input, D> {
+    contents: Contents<'decoder, 'input>,
+    done: bool,
+    _element: PhantomData<fn() -> D>,
+}
+
+/// Definite length contents are decoded separately, as the outer decoder has
+/// already moved past them (though it's kept to count the decoded output
+/// against its limits), while indefinite length contents can only be decoded
+/// from the outer decoder, up to the end-of-contents marker.
+enum Contents<'decoder, 'input> {
+    Definite(Decoder<'input>, &'decoder mut Decoder<'input>),
+    Indefinite(&'decoder mut Decoder<'input>),
+}
+
+impl<'decoder, 'input, D: Decode> Iterator for SequenceOfIter<'decoder, 'input, D> {
+    type Item = Result<D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = match &mut self.contents {
+            Contents::Definite(decoder, _) if decoder.input.is_empty() => None,
+            Contents::Indefinite(decoder) if decoder.is_at_end() => {
+                decoder.parse_eoc().err().map(Err)
+            }
+            Contents::Definite(decoder, _) => Some(decode_element(decoder)),
+            Contents::Indefinite(decoder) => Some(decode_element(decoder)),
+        };
+
+        self.done = !matches!(result, Some(Ok(_)));
+        result
+    }
+}
+
+/// Decodes an element, counting it towards the decoder's output limits.
+fn decode_element<D: Decode>(decoder: &mut Decoder) -> Result<D> {
+    let element = D::decode(decoder)?;
+    decoder.add_output(0, 1)?;
+    Ok(element)
+}
+
+impl<'decoder, 'input, D> Drop for SequenceOfIter<'decoder, 'input, D> {
+    fn drop(&mut self) {
+        if let Contents::Definite(inner, outer) = &mut self.contents {
+            outer.resume_from(inner);
+        }
+
+        // The end of indefinite length contents is only known once every
+        // element has been decoded, so any remaining elements have to be
+        // skipped over to leave the outer decoder after the sequence.
+        if let Contents::Indefinite(decoder) = &mut self.contents {
+            while !self.done && !decoder.is_at_end() {
+                self.done = decoder.skip_value().is_err();
+            }
+
+            if !self.done {
+                let _ = decoder.parse_eoc();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ber::de::DecoderOptions, Decoder as _};
+
+    #[test]
+    fn definite() {
+        let data = [
+            0x30, 0x09, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03, 0x05, 0x00,
+        ];
+        let mut decoder = Decoder::new(&data, DecoderOptions::der());
+
+        let elements = decoder
+            .decode_sequence_of_iter::<u8>(Tag::SEQUENCE)
+            .unwrap()
+            .collect::<Result<alloc::vec::Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(alloc::vec![1, 2, 3], elements);
+        decoder.decode_null(Tag::NULL).unwrap();
+        assert!(decoder.input.is_empty());
+    }
+
+    #[test]
+    fn indefinite_stops_early() {
+        let data = [
+            0x30, 0x80, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03, 0x00, 0x00, 0x05,
+            0x00,
+        ];
+        let mut decoder = Decoder::new(&data, DecoderOptions::ber());
+
+        let mut elements = decoder
+            .decode_sequence_of_iter::<u8>(Tag::SEQUENCE)
+            .unwrap();
+        assert_eq!(1, elements.next().unwrap().unwrap());
+        drop(elements);
+
+        decoder.decode_null(Tag::NULL).unwrap();
+        assert!(decoder.input.is_empty());
+    }
+
+    #[test]
+    fn stops_after_error() {
+        let data = [0x30, 0x06, 0x02, 0x01, 0x01, 0x01, 0x01, 0xFF];
+        let mut decoder = Decoder::new(&data, DecoderOptions::der());
+
+        let mut elements = decoder
+            .decode_sequence_of_iter::<u8>(Tag::SEQUENCE)
+            .unwrap();
+        assert_eq!(1, elements.next().unwrap().unwrap());
+        assert!(elements.next().unwrap().is_err());
+        assert!(elements.next().is_none());
+    }
+
+    #[test]
+    fn lenient() {
+        // The second and fourth elements are too large for a `u8`, and the
+        // third has the wrong tag.
+        let data = [
+            0x30, 0x11, 0x02, 0x01, 0x01, 0x02, 0x02, 0x01, 0x00, 0x01, 0x01, 0xFF, 0x02, 0x02,
+            0x01, 0x00, 0x02, 0x01, 0x05, 0x05, 0x00,
+        ];
+        let mut decoder = Decoder::new(&data, DecoderOptions::der());
+
+        let (elements, errors) = decoder
+            .decode_sequence_of_lenient::<u8>(Tag::SEQUENCE)
+            .unwrap();
+        assert_eq!(alloc::vec![1, 5], elements);
+        assert_eq!(
+            alloc::vec![1, 2, 3],
+            errors.iter().map(|(index, _)| *index).collect::<Vec<_>>()
+        );
+        decoder.decode_null(Tag::NULL).unwrap();
+        assert!(decoder.input.is_empty());
+
+        // Definite length elements of an indefinite length sequence are
+        // skipped too.
+        let indefinite = [
+            0x30, 0x80, 0x01, 0x01, 0xFF, 0x02, 0x01, 0x02, 0x00, 0x00, 0x05, 0x00,
+        ];
+        let mut decoder = Decoder::new(&indefinite, DecoderOptions::ber());
+        let (elements, errors) = decoder
+            .decode_sequence_of_lenient::<u8>(Tag::SEQUENCE)
+            .unwrap();
+        assert_eq!((alloc::vec![2], 1), (elements, errors.len()));
+        decoder.decode_null(Tag::NULL).unwrap();
+
+        // An element that can't be skipped ends the sequence.
+        let truncated = [0x30, 0x05, 0x02, 0x01, 0x01, 0x02, 0x05];
+        let mut decoder = Decoder::new(&truncated, DecoderOptions::der());
+        let (elements, errors) = decoder
+            .decode_sequence_of_lenient::<u8>(Tag::SEQUENCE)
+            .unwrap();
+        assert_eq!((alloc::vec![1], 1), (elements, errors.len()));
+        assert!(decoder.input.is_empty());
+    }
+}
@@ -0,0 +1,410 @@
+//! Validation of BER encoded data against a runtime [`Schema`].
+
+use alloc::{format, string::String, vec, vec::Vec};
+
+use super::{parser, Decoder, Error};
+use crate::{
+    de::{Decoder as _, Error as _},
+    schema::{Field, Schema},
+    types::Tag,
+};
+
+/// An error that occurred when validating data against a [`Schema`].
+#[derive(Debug)]
+pub struct ValidationError {
+    /// The field names leading from the root schema to the invalid value.
+    pub path: Vec<String>,
+    /// The error that occurred.
+    pub error: Error,
+    /// The errors from each alternative, when the invalid value is a `CHOICE`
+    /// where no alternative matched.
+    pub alternatives: Vec<ValidationError>,
+}
+
+impl ValidationError {
+    fn new(path: &[String], error: Error) -> Self {
+        Self {
+            path: path.to_vec(),
+            error,
+            alternatives: Vec::new(),
+        }
+    }
+}
+
+impl From<Error> for ValidationError {
+    fn from(error: Error) -> Self {
+        Self::new(&[], error)
+    }
+}
+
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        for (i, segment) in self.path.iter().enumerate() {
+            if i != 0 && !segment.starts_with('[') {
+                f.write_str(".")?;
+            }
+            f.write_str(segment)?;
+        }
+
+        if !self.path.is_empty() {
+            f.write_str(": ")?;
+        }
+
+        write!(f, "{}", self.error)?;
+
+        for alternative in &self.alternatives {
+            write!(f, "\n  {}", alternative)?;
+        }
+
+        Ok(())
+    }
+}
+
+type Result<T = (), E = ValidationError> = core::result::Result<T, E>;
+
+impl<'input> Decoder<'input> {
+    /// Validates that the next value in the input matches `schema`.
+    pub fn validate(&mut self, schema: &Schema) -> Result {
+        self.validate_value(schema, schema.tag(), &mut Vec::new())
+    }
+
+    fn validate_value(&mut self, schema: &Schema, tag: Tag, path: &mut Vec<String>) -> Result {
+        let result = match schema {
+            Schema::Any => self.decode_any().map(drop),
+            Schema::Bool => self.decode_bool(tag).map(drop),
+            Schema::Integer => self.decode_integer(tag).map(drop),
+            Schema::Enumerated => self.decode_enumerated(tag).map(drop),
+            Schema::BitString => self.decode_bit_string(tag).map(drop),
+            Schema::OctetString => self.decode_octet_string(tag).map(drop),
+            Schema::Null => self.decode_null(tag),
+            Schema::ObjectIdentifier => self.decode_object_identifier(tag).map(drop),
+            Schema::Utf8String => self.decode_utf8_string(tag).map(drop),
+            Schema::UtcTime => self.decode_utc_time(tag).map(drop),
+            Schema::GeneralizedTime => self.decode_generalized_time(tag).map(drop),
+            Schema::Sequence(fields) => {
+                return self.validate_constructed(tag, true, path, |decoder, path| {
+                    decoder.validate_sequence(fields, path)
+                })
+            }
+            Schema::Set(fields) => {
+                return self.validate_constructed(tag, true, path, |decoder, path| {
+                    decoder.validate_set(fields, path)
+                })
+            }
+            Schema::SequenceOf(item) | Schema::SetOf(item) => {
+                return self.validate_constructed(tag, true, path, |decoder, path| {
+                    decoder.validate_repeated(item, path)
+                })
+            }
+            // A `CHOICE` can't be implicitly tagged, so it is explicitly tagged.
+            Schema::Choice(alternatives) if tag.is_choice() => {
+                return self.validate_choice(alternatives, path)
+            }
+            Schema::Choice(_) => {
+                return self.validate_constructed(tag, false, path, |decoder, path| {
+                    decoder.validate_value(schema, Tag::CHOICE, path)
+                })
+            }
+            Schema::Implicit(_, inner) => return self.validate_value(inner, tag, path),
+            Schema::Explicit(_, inner) => {
+                return self.validate_constructed(tag, false, path, |decoder, path| {
+                    decoder.validate_value(inner, inner.tag(), path)
+                })
+            }
+        };
+
+        result.map_err(|error| ValidationError::new(path, error))
+    }
+
+    fn validate_constructed<F>(
+        &mut self,
+        tag: Tag,
+        check_identifier: bool,
+        path: &mut Vec<String>,
+        validate_fn: F,
+    ) -> Result
+    where
+        F: FnOnce(&mut Self, &mut Vec<String>) -> Result,
+    {
+        let mut inner_error = None;
+        let result = self.parse_constructed_contents(tag, check_identifier, |decoder| {
+            inner_error = (validate_fn)(decoder, path).err();
+            Ok(())
+        });
+
+        match inner_error {
+            Some(error) => Err(error),
+            None => result.map_err(|error| ValidationError::new(path, error)),
+        }
+    }
+
+    fn validate_sequence(&mut self, fields: &[Field], path: &mut Vec<String>) -> Result {
+        for field in fields {
+            if field.optional && !self.next_matches(&field.schema) {
+                continue;
+            }
+
+            path.push(field.name.clone());
+
+            if self.is_at_end() {
+                return Err(ValidationError::new(
+                    path,
+                    Error::custom("Expected required field"),
+                ));
+            }
+
+            self.validate_value(&field.schema, field.schema.tag(), path)?;
+            path.pop();
+        }
+
+        self.validate_end(path)
+    }
+
+    fn validate_set(&mut self, fields: &[Field], path: &mut Vec<String>) -> Result {
+        let mut seen = vec![false; fields.len()];
+
+        while !self.is_at_end() {
+            let tag = parser::parse_identifier_octet(self.input)
+                .map_err(|error| ValidationError::new(path, super::error::map_nom_err(error)))?
+                .1
+                .tag;
+
+            let index = fields
+                .iter()
+                .position(|field| field.schema.matches_tag(tag))
+                .ok_or_else(|| {
+                    ValidationError::new(
+                        path,
+                        Error::custom(format!("Unexpected {:?} tag in `SET`", tag)),
+                    )
+                })?;
+
+            let field = &fields[index];
+            path.push(field.name.clone());
+
+            if core::mem::replace(&mut seen[index], true) {
+                return Err(ValidationError::new(
+                    path,
+                    Error::custom("Duplicate field found"),
+                ));
+            }
+
+            self.validate_value(&field.schema, field.schema.tag(), path)?;
+            path.pop();
+        }
+
+        for (field, seen) in fields.iter().zip(seen) {
+            if !seen && !field.optional {
+                path.push(field.name.clone());
+                return Err(ValidationError::new(
+                    path,
+                    Error::custom("Expected required field"),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_repeated(&mut self, item: &Schema, path: &mut Vec<String>) -> Result {
+        let mut index = 0;
+
+        while !self.is_at_end() {
+            path.push(format!("[{}]", index));
+            self.validate_value(item, item.tag(), path)?;
+            path.pop();
+            index += 1;
+        }
+
+        Ok(())
+    }
+
+    fn validate_choice(&mut self, alternatives: &[Field], path: &mut Vec<String>) -> Result {
+        let next_tag = parser::parse_identifier_octet(self.input)
+            .ok()
+            .map(|(_, identifier)| identifier.tag);
+
+        if let Some(field) = next_tag.and_then(|tag| {
+            alternatives
+                .iter()
+                .find(|field| field.schema.matches_tag(tag))
+        }) {
+            path.push(field.name.clone());
+            self.validate_value(&field.schema, field.schema.tag(), path)?;
+            path.pop();
+            return Ok(());
+        }
+
+        let start = self.input;
+        let mut errors = Vec::new();
+
+        for field in alternatives {
+            path.push(field.name.clone());
+            let result = self.validate_value(&field.schema, field.schema.tag(), path);
+            path.pop();
+
+            match result {
+                Ok(()) => return Ok(()),
+                Err(error) => errors.push(error),
+            }
+
+            self.input = start;
+        }
+
+        let mut error = ValidationError::new(path, Error::custom("No valid `CHOICE` variant"));
+        error.alternatives = errors;
+        Err(error)
+    }
+
+    fn validate_end(&self, path: &[String]) -> Result {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(ValidationError::new(
+                path,
+                Error::UnexpectedExtraData {
+                    length: self.input.len(),
+                },
+            ))
+        }
+    }
+
+    fn next_matches(&self, schema: &Schema) -> bool {
+        matches!(
+            parser::parse_identifier_octet(self.input),
+            Ok((_, identifier)) if schema.matches_tag(identifier.tag)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{
+        schema::{Field, Schema},
+        types::*,
+        AsnType, Decode, Encode,
+    };
+
+    #[derive(AsnType, Decode, Encode)]
+    #[rasn(crate_root = "crate")]
+    struct Certificate {
+        tbs_certificate: TbsCertificate,
+        signature: BitString,
+    }
+
+    struct C0;
+    impl AsnType for C0 {
+        const TAG: Tag = Tag::new(Class::Context, 0);
+    }
+
+    #[derive(AsnType, Decode, Encode)]
+    #[rasn(crate_root = "crate")]
+    struct TbsCertificate {
+        version: Option<Explicit<C0, u8>>,
+        serial_number: Integer,
+        validity: Validity,
+    }
+
+    #[derive(AsnType, Decode, Encode)]
+    #[rasn(crate_root = "crate")]
+    struct Validity {
+        not_before: UtcTime,
+        not_after: OctetString,
+    }
+
+    fn schema() -> Schema {
+        let time = Schema::Choice(alloc::vec![
+            Field::new("utcTime", Schema::UtcTime),
+            Field::new("generalTime", Schema::GeneralizedTime),
+        ]);
+
+        Schema::Sequence(alloc::vec![
+            Field::new(
+                "tbsCertificate",
+                Schema::Sequence(alloc::vec![
+                    Field::optional(
+                        "version",
+                        Schema::explicit(Tag::new(Class::Context, 0), Schema::Integer),
+                    ),
+                    Field::new("serialNumber", Schema::Integer),
+                    Field::new(
+                        "validity",
+                        Schema::Sequence(alloc::vec![
+                            Field::new("notBefore", time.clone()),
+                            Field::new("notAfter", time),
+                        ]),
+                    ),
+                ]),
+            ),
+            Field::new("signature", Schema::BitString),
+        ])
+    }
+
+    fn certificate(version: Option<u8>) -> alloc::vec::Vec<u8> {
+        use chrono::TimeZone;
+
+        let not_before = chrono::NaiveDate::from_ymd_opt(2021, 1, 1)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .unwrap();
+
+        crate::ber::encode(&Certificate {
+            tbs_certificate: TbsCertificate {
+                version: version.map(Explicit::new),
+                serial_number: 5.into(),
+                validity: Validity {
+                    not_before: chrono::Utc.from_utc_datetime(&not_before),
+                    not_after: OctetString::from_static(&[1, 2, 3]),
+                },
+            },
+            signature: BitString::from_slice(&[0xFF]),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn valid() {
+        let mut schema = schema();
+        if let Schema::Sequence(fields) = &mut schema {
+            if let Schema::Sequence(fields) = &mut fields[0].schema {
+                fields[2].schema = Schema::Sequence(alloc::vec![
+                    Field::new("notBefore", Schema::UtcTime),
+                    Field::new("notAfter", Schema::OctetString),
+                ]);
+            }
+        }
+
+        assert!(crate::ber::validate(&certificate(None), &schema).is_ok());
+        assert!(crate::ber::validate(&certificate(Some(2)), &schema).is_ok());
+    }
+
+    #[test]
+    fn error_path() {
+        let error = crate::ber::validate(&certificate(Some(2)), &schema()).unwrap_err();
+
+        assert_eq!(
+            ["tbsCertificate", "validity", "notAfter"][..],
+            error.path[..]
+        );
+        assert_eq!(2, error.alternatives.len());
+        assert!(error
+            .to_string()
+            .starts_with("tbsCertificate.validity.notAfter: "));
+    }
+
+    #[test]
+    fn sequence_of_path() {
+        let data = crate::ber::encode(&alloc::vec![Integer::from(1), Integer::from(2)]).unwrap();
+
+        assert!(crate::ber::validate(&data, &Schema::sequence_of(Schema::Integer)).is_ok());
+
+        let error = crate::ber::validate(&data, &Schema::sequence_of(Schema::Bool)).unwrap_err();
+        assert_eq!("[0]", error.path[0]);
+    }
+
+    #[test]
+    fn trailing_data() {
+        assert!(crate::ber::validate(&[0x05, 0x00, 0x05, 0x00], &Schema::Null).is_err());
+    }
+}
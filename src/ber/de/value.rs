@@ -0,0 +1,58 @@
+//! Decoding BER encoded data into a dynamic [`Value`] tree.
+
+use alloc::{boxed::Box, vec::Vec};
+
+use super::{error, parser, Decoder, Result};
+use crate::{
+    de::Decoder as _,
+    types::{Tag, Value},
+    Decode,
+};
+
+impl<'input> Decoder<'input> {
+    /// Decodes the next value in the input into a [`Value`], regardless of its
+    /// type.
+    pub fn decode_value(&mut self) -> Result<Value> {
+        let (_, identifier) =
+            parser::parse_identifier_octet(self.input).map_err(error::map_nom_err)?;
+        let tag = identifier.tag;
+
+        Ok(match tag {
+            Tag::BOOL => Value::Bool(self.decode_bool(tag)?),
+            Tag::INTEGER => Value::Integer(self.decode_integer(tag)?),
+            Tag::ENUMERATED => Value::Enumerated(isize::decode_with_tag(self, tag)?),
+            Tag::BIT_STRING => Value::BitString(self.decode_bit_string(tag)?),
+            Tag::OCTET_STRING => Value::OctetString(self.decode_octet_string(tag)?.into()),
+            Tag::NULL => {
+                self.decode_null(tag)?;
+                Value::Null
+            }
+            Tag::OBJECT_IDENTIFIER => Value::ObjectIdentifier(self.decode_object_identifier(tag)?),
+            Tag::UTF8_STRING => Value::Utf8String(self.decode_utf8_string(tag)?),
+            Tag::UTC_TIME => Value::UtcTime(self.decode_utc_time(tag)?),
+            Tag::GENERALIZED_TIME => Value::GeneralizedTime(self.decode_generalized_time(tag)?),
+            Tag::SEQUENCE => Value::Sequence(self.decode_sequence(tag, Self::decode_values)?),
+            Tag::SET => Value::Set(self.decode_sequence(tag, Self::decode_values)?),
+            _ if identifier.is_constructed() => {
+                let mut values = self.decode_sequence(tag, Self::decode_values)?;
+
+                if values.len() == 1 {
+                    Value::Tagged(tag, Box::new(values.remove(0)))
+                } else {
+                    Value::Constructed(tag, values)
+                }
+            }
+            _ => Value::Primitive(tag, self.parse_primitive_value(tag)?.1.to_vec().into()),
+        })
+    }
+
+    fn decode_values(&mut self) -> Result<Vec<Value>> {
+        let mut values = Vec::new();
+
+        while !self.is_at_end() {
+            values.push(self.decode_value()?);
+        }
+
+        Ok(values)
+    }
+}
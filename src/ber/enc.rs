@@ -2,6 +2,8 @@
 
 mod config;
 mod error;
+mod sequence;
+mod stats;
 
 use alloc::{collections::VecDeque, string::ToString, vec::Vec};
 
@@ -17,6 +19,49 @@ use crate::{
 
 pub use config::EncoderOptions;
 pub use error::Error;
+pub use sequence::SequenceEncoder;
+pub use stats::EncodeStats;
+
+/// Logs the method's tag and value, along with the offset in the output of
+/// the encoding and its length, when the `trace` feature is enabled. The
+/// contents of a constructed value are encoded on their own before being
+/// added to the output, so offsets inside them are from the start of their
+/// contents.
+#[cfg(feature = "trace")]
+macro_rules! trace_encode {
+    ($encoder:ident, $method:literal, $tag:expr, $value:ident, $body:block) => {
+        trace_encode!(@log $encoder, $method, $tag, $value, $body)
+    };
+    ($encoder:ident, $method:literal, $tag:expr, $body:block) => {
+        trace_encode!(@log $encoder, $method, $tag, format_args!(".."), $body)
+    };
+    (@log $encoder:ident, $method:literal, $tag:expr, $value:expr, $body:block) => {{
+        let offset = $encoder.output.len();
+        #[allow(clippy::redundant_closure_call)]
+        let result = (|| -> Result<(), Error> { $body })();
+
+        match &result {
+            Ok(()) => log::trace!(
+                "{} {:?} at offset {}: {:?} ({} bytes)",
+                $method,
+                $tag,
+                offset,
+                $value,
+                $encoder.output.len().saturating_sub(offset),
+            ),
+            Err(error) => log::trace!("{} {:?} at offset {} failed: {}", $method, $tag, offset, error),
+        }
+
+        result
+    }};
+}
+
+#[cfg(not(feature = "trace"))]
+macro_rules! trace_encode {
+    ($encoder:ident, $method:literal, $tag:expr, $($value:ident,)? $body:block) => {
+        $body
+    };
+}
 
 const START_OF_CONTENTS: u8 = 0x80;
 const END_OF_CONTENTS: &[u8] = &[0, 0];
@@ -27,6 +72,10 @@ pub struct Encoder {
     config: EncoderOptions,
     is_set_encoding: bool,
     set_buffer: alloc::collections::BTreeMap<Tag, Vec<u8>>,
+    /// Buffers previously used to encode the contents of constructed values,
+    /// kept so that nested values don't allocate a new buffer each time.
+    scratch: Vec<Vec<u8>>,
+    stats: EncodeStats,
 }
 
 /// A convenience type around results needing to return one or many bytes.
@@ -35,6 +84,48 @@ enum ByteOrBytes {
     Many(Vec<u8>),
 }
 
+/// Returns the contents of a `REAL` with `value`, which is the shortest
+/// encoding, and the one required by CER and DER: base 2 with an odd mantissa,
+/// or one of the special values.
+fn real_contents(value: f64) -> Vec<u8> {
+    let sign = if value.is_sign_negative() { 0x40 } else { 0 };
+
+    if value.is_nan() {
+        return alloc::vec![0x42];
+    } else if value.is_infinite() {
+        return alloc::vec![if sign == 0 { 0x40 } else { 0x41 }];
+    } else if value == 0.0 {
+        return if sign == 0 {
+            Vec::new()
+        } else {
+            alloc::vec![0x43]
+        };
+    }
+
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7FF) as i16;
+    let fraction = bits & ((1 << 52) - 1);
+    // Subnormal floats have no implicit leading one bit.
+    let (mantissa, exponent) = match biased_exponent {
+        0 => (fraction, -1074),
+        _ => (fraction | 1 << 52, biased_exponent - 1075),
+    };
+    let trailing_zeros = mantissa.trailing_zeros();
+    let (mantissa, exponent) = (mantissa >> trailing_zeros, exponent + trailing_zeros as i16);
+
+    let mut contents = Vec::with_capacity(10);
+    if (-128..=127).contains(&exponent) {
+        contents.extend_from_slice(&[0x80 | sign, exponent as u8]);
+    } else {
+        contents.push(0x81 | sign);
+        contents.extend_from_slice(&exponent.to_be_bytes());
+    }
+    let mantissa = mantissa.to_be_bytes();
+    let leading_zeros = mantissa.iter().take_while(|&&byte| byte == 0).count();
+    contents.extend_from_slice(&mantissa[leading_zeros..]);
+    contents
+}
+
 impl Encoder {
     /// Creates a new instance from the given `config`.
     pub fn new(config: EncoderOptions) -> Self {
@@ -43,6 +134,8 @@ impl Encoder {
             is_set_encoding: false,
             output: <_>::default(),
             set_buffer: <_>::default(),
+            scratch: <_>::default(),
+            stats: <_>::default(),
         }
     }
 
@@ -54,6 +147,8 @@ impl Encoder {
             is_set_encoding: true,
             output: <_>::default(),
             set_buffer: <_>::default(),
+            scratch: <_>::default(),
+            stats: <_>::default(),
         }
     }
 
@@ -71,6 +166,50 @@ impl Encoder {
         }
     }
 
+    /// Consumes the encoder and returns the output of the encoding, along with
+    /// statistics about it.
+    pub fn finish_with_stats(self) -> (Vec<u8>, EncodeStats) {
+        let mut stats = self.stats;
+        let output = self.output();
+        stats.bytes = output.len();
+
+        (output, stats)
+    }
+
+    /// The number of bytes encoded so far, outside of SET encoding.
+    pub(crate) fn output_len(&self) -> usize {
+        self.output.len()
+    }
+
+    /// Creates an encoder for the contents of a constructed value, lending it
+    /// this encoder's scratch buffers.
+    fn new_nested(&mut self, is_set_encoding: bool) -> Self {
+        let mut scratch = core::mem::take(&mut self.scratch);
+
+        Self {
+            config: self.config,
+            is_set_encoding,
+            output: scratch.pop().unwrap_or_default(),
+            set_buffer: <_>::default(),
+            scratch,
+            stats: <_>::default(),
+        }
+    }
+
+    /// Encodes the output of `encoder` as the contents of a constructed value
+    /// with `tag`. As the length of the contents is only known once they've
+    /// been encoded, they're kept in a scratch buffer until then, which is
+    /// then returned to the pool along with `encoder`'s.
+    fn encode_nested(&mut self, tag: Tag, mut encoder: Self) {
+        self.scratch = core::mem::take(&mut encoder.scratch);
+        self.stats.values += encoder.stats.values;
+        self.stats.fragmented |= encoder.stats.fragmented;
+        let mut contents = encoder.output();
+        self.encode_constructed(tag, &contents);
+        contents.clear();
+        self.scratch.push(contents);
+    }
+
     fn append_byte_or_bytes(&mut self, bytes: ByteOrBytes) {
         match bytes {
             ByteOrBytes::Single(b) => self.output.push(b),
@@ -120,6 +259,7 @@ impl Encoder {
         }: Identifier,
     ) -> ByteOrBytes {
         const FIVE_BITS: u32 = (1 << 5) - 1;
+        self.stats.values += 1;
         let mut tag_byte = tag.class as u8;
         let tag_number = tag.value;
 
@@ -144,7 +284,7 @@ impl Encoder {
     }
 
     fn encode_length(&mut self, identifier: Identifier, value: &[u8]) {
-        if identifier.is_primitive() || !self.config.encoding_rules.is_cer() {
+        if identifier.is_primitive() || !self.config.indefinite_length {
             let len_bytes = self.encode_definite_length(value.len());
             self.append_byte_or_bytes(len_bytes);
             self.output.extend_from_slice(value);
@@ -184,13 +324,33 @@ impl Encoder {
         let max_string_length = self.config.encoding_rules.max_string_length();
 
         if value.len() > max_string_length {
+            self.stats.fragmented = true;
             let ident_bytes = self.encode_identifier(Identifier::from_tag(tag, true));
             self.append_byte_or_bytes(ident_bytes);
 
             self.output.push(START_OF_CONTENTS);
 
-            for chunk in value.chunks(max_string_length) {
-                self.encode_primitive(nested_tag, chunk);
+            if nested_tag == Tag::BIT_STRING {
+                // Every segment of a `BIT STRING` starts with its own unused
+                // bits octet, and only the last segment can have unused bits.
+                let (unused_bits, bits) = value.split_first().unwrap();
+                let mut chunks = bits.chunks(max_string_length - 1).peekable();
+                let mut segment = Vec::with_capacity(max_string_length);
+
+                while let Some(chunk) = chunks.next() {
+                    segment.clear();
+                    segment.push(if chunks.peek().is_none() {
+                        *unused_bits
+                    } else {
+                        0
+                    });
+                    segment.extend_from_slice(chunk);
+                    self.encode_primitive(nested_tag, &segment);
+                }
+            } else {
+                for chunk in value.chunks(max_string_length) {
+                    self.encode_primitive(nested_tag, chunk);
+                }
             }
 
             self.output.extend_from_slice(END_OF_CONTENTS);
@@ -232,46 +392,67 @@ impl crate::Encoder for Encoder {
     type Ok = ();
     type Error = error::Error;
 
+    fn emits_defaults(&self) -> bool {
+        self.config.emit_defaults
+    }
+
     fn encode_any(&mut self, value: &types::Any) -> Result<Self::Ok, Self::Error> {
-        if self.is_set_encoding {
-            return Err(crate::enc::Error::custom(
-                "Cannot encode `ANY` types in `SET` fields.",
-            ));
-        }
+        trace_encode!(self, "encode_any", "ANY", value, {
+            if self.is_set_encoding {
+                return Err(crate::enc::Error::custom(
+                    "Cannot encode `ANY` types in `SET` fields.",
+                ));
+            }
 
-        self.output.extend_from_slice(&value.contents);
+            self.output.extend_from_slice(&value.contents);
+            self.stats.values += 1;
 
-        Ok(())
+            Ok(())
+        })
     }
 
-    fn encode_bit_string(
+    fn encode_any_with_tag(
         &mut self,
         tag: Tag,
-        value: &types::BitString,
+        value: &types::Any,
     ) -> Result<Self::Ok, Self::Error> {
-        if value.not_any() {
-            self.encode_primitive(tag, &[]);
+        trace_encode!(self, "encode_any_with_tag", tag, value, {
+            self.encode_constructed(tag, &value.contents);
             Ok(())
-        } else {
-            let bit_length = value.len();
-            let bytes = value.clone().into_vec();
-            let mut deque = VecDeque::from(bytes);
-            while deque.back().map_or(false, |i| *i == 0) {
-                deque.pop_back();
-            }
+        })
+    }
 
-            deque.push_front((deque.len() * 8).saturating_sub(bit_length) as u8);
-            self.encode_string(tag, Tag::BIT_STRING, &Vec::from(deque))
-        }
+    fn encode_bit_string(
+        &mut self,
+        tag: Tag,
+        value: &types::BitString,
+    ) -> Result<Self::Ok, Self::Error> {
+        trace_encode!(self, "encode_bit_string", tag, value, {
+            // The initial octet is the number of unused bits in the last
+            // octet, which are always zero.
+            let mut bits = value.clone();
+            bits.force_align();
+            bits.set_uninitialized(false);
+
+            let mut contents = Vec::with_capacity(1 + bits.as_raw_slice().len());
+            contents.push(((8 - value.len() % 8) % 8) as u8);
+            contents.extend_from_slice(bits.as_raw_slice());
+
+            self.encode_string(tag, Tag::BIT_STRING, &contents)
+        })
     }
 
     fn encode_bool(&mut self, tag: Tag, value: bool) -> Result<Self::Ok, Self::Error> {
-        self.encode_primitive(tag, &[if value { 0xff } else { 0x00 }]);
-        Ok(())
+        trace_encode!(self, "encode_bool", tag, value, {
+            self.encode_primitive(tag, &[if value { 0xff } else { 0x00 }]);
+            Ok(())
+        })
     }
 
     fn encode_enumerated(&mut self, tag: Tag, value: isize) -> Result<Self::Ok, Self::Error> {
-        self.encode_integer(tag, &(value.into()))
+        trace_encode!(self, "encode_enumerated", tag, value, {
+            self.encode_integer(tag, &(value.into()))
+        })
     }
 
     fn encode_integer(
@@ -279,45 +460,73 @@ impl crate::Encoder for Encoder {
         tag: Tag,
         value: &types::Integer,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_primitive(tag, &value.to_signed_bytes_be());
-        Ok(())
+        trace_encode!(self, "encode_integer", tag, value, {
+            self.encode_primitive(tag, &value.to_signed_bytes_be());
+            Ok(())
+        })
+    }
+
+    fn encode_real(&mut self, tag: Tag, value: f64) -> Result<Self::Ok, Self::Error> {
+        trace_encode!(self, "encode_real", tag, value, {
+            self.encode_primitive(tag, &real_contents(value));
+            Ok(())
+        })
     }
 
     fn encode_null(&mut self, tag: Tag) -> Result<Self::Ok, Self::Error> {
-        self.encode_primitive(tag, &[]);
-        Ok(())
+        trace_encode!(self, "encode_null", tag, {
+            self.encode_primitive(tag, &[]);
+            Ok(())
+        })
     }
 
     fn encode_object_identifier(&mut self, tag: Tag, oid: &[u32]) -> Result<Self::Ok, Self::Error> {
-        if oid.len() < 2 {
-            return Err(error::Error::InvalidObjectIdentifier);
-        }
-        let mut bytes = Vec::new();
+        trace_encode!(self, "encode_object_identifier", tag, oid, {
+            if oid.len() < 2 {
+                return Err(error::Error::InvalidObjectIdentifier);
+            }
+            let mut bytes = Vec::new();
 
-        let first = oid[0];
-        let second = oid[1];
+            let first = oid[0];
+            let second = oid[1];
 
-        if first > MAX_OID_FIRST_OCTET {
-            return Err(error::Error::InvalidObjectIdentifier);
-        }
+            if first > MAX_OID_FIRST_OCTET {
+                return Err(error::Error::InvalidObjectIdentifier);
+            }
 
-        self.encode_as_base128((first * (MAX_OID_SECOND_OCTET + 1)) + second, &mut bytes);
+            self.encode_as_base128((first * (MAX_OID_SECOND_OCTET + 1)) + second, &mut bytes);
 
-        for component in oid.iter().skip(2) {
-            self.encode_as_base128(*component, &mut bytes);
-        }
+            for component in oid.iter().skip(2) {
+                self.encode_as_base128(*component, &mut bytes);
+            }
 
-        self.encode_primitive(tag, &bytes);
+            self.encode_primitive(tag, &bytes);
 
-        Ok(())
+            Ok(())
+        })
     }
 
     fn encode_octet_string(&mut self, tag: Tag, value: &[u8]) -> Result<Self::Ok, Self::Error> {
-        self.encode_octet_string_(tag, value)
+        trace_encode!(self, "encode_octet_string", tag, value, {
+            self.encode_octet_string_(tag, value)
+        })
+    }
+
+    fn encode_primitive_contents(
+        &mut self,
+        tag: Tag,
+        contents: &[u8],
+    ) -> Result<Self::Ok, Self::Error> {
+        trace_encode!(self, "encode_primitive_contents", tag, contents, {
+            self.encode_primitive(tag, contents);
+            Ok(())
+        })
     }
 
     fn encode_utf8_string(&mut self, tag: Tag, value: &str) -> Result<Self::Ok, Self::Error> {
-        self.encode_octet_string_(tag, value.as_bytes())
+        trace_encode!(self, "encode_utf8_string", tag, value, {
+            self.encode_octet_string_(tag, value.as_bytes())
+        })
     }
 
     fn encode_utc_time(
@@ -325,16 +534,31 @@ impl crate::Encoder for Encoder {
         tag: Tag,
         value: &types::UtcTime,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_primitive(
-            tag,
-            value
-                .naive_utc()
-                .format("%y%m%d%H%M%SZ")
-                .to_string()
-                .as_bytes(),
-        );
+        trace_encode!(self, "encode_utc_time", tag, value, {
+            // Only the years that decode back the same with the default
+            // pivot fit in two digits.
+            let first_year = 1900 + i32::from(super::de::RFC_5280_UTC_TIME_PIVOT);
+            let year = chrono::Datelike::year(value);
+            if !(first_year..first_year + 100).contains(&year) {
+                return Err(crate::enc::Error::custom(alloc::format!(
+                    "`UTCTime` can only hold the years {} to {}, found {}.",
+                    first_year,
+                    first_year + 99,
+                    year
+                )));
+            }
 
-        Ok(())
+            self.encode_primitive(
+                tag,
+                value
+                    .naive_utc()
+                    .format("%y%m%d%H%M%SZ")
+                    .to_string()
+                    .as_bytes(),
+            );
+
+            Ok(())
+        })
     }
 
     fn encode_generalized_time(
@@ -342,16 +566,125 @@ impl crate::Encoder for Encoder {
         tag: Tag,
         value: &types::GeneralizedTime,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_primitive(
-            tag,
-            value
-                .naive_utc()
-                .format("%Y%m%d%H%M%SZ")
-                .to_string()
-                .as_bytes(),
-        );
+        trace_encode!(self, "encode_generalized_time", tag, value, {
+            // BER keeps any UTC offset (in whole minutes) as a `±hhmm` suffix,
+            // while CER and DER require the time in UTC.
+            let offset = value.offset().local_minus_utc();
+            let keep_offset =
+                self.config.encoding_rules.is_ber() && offset != 0 && offset % 60 == 0;
+            let local = match keep_offset {
+                true => value.naive_local(),
+                false => value.naive_utc(),
+            };
+
+            // Fractional seconds are written without trailing zeros, and left out
+            // entirely if they're zero, as DER requires.
+            let mut string = local.format("%Y%m%d%H%M%S").to_string();
+            let nanoseconds = chrono::Timelike::nanosecond(&local);
+
+            if nanoseconds != 0 {
+                let fraction = alloc::format!("{:09}", nanoseconds);
+                string.push('.');
+                string.push_str(fraction.trim_end_matches('0'));
+            }
 
-        Ok(())
+            if keep_offset {
+                let minutes = offset.abs() / 60;
+                let sign = if offset < 0 { '-' } else { '+' };
+                string.push_str(&alloc::format!(
+                    "{}{:02}{:02}",
+                    sign,
+                    minutes / 60,
+                    minutes % 60
+                ));
+            } else {
+                string.push('Z');
+            }
+
+            self.encode_primitive(tag, string.as_bytes());
+
+            Ok(())
+        })
+    }
+
+    fn encode_date(&mut self, tag: Tag, value: &types::Date) -> Result<Self::Ok, Self::Error> {
+        trace_encode!(self, "encode_date", tag, value, {
+            self.encode_primitive(tag, value.format("%Y%m%d").to_string().as_bytes());
+
+            Ok(())
+        })
+    }
+
+    fn encode_time_of_day(
+        &mut self,
+        tag: Tag,
+        value: &types::TimeOfDay,
+    ) -> Result<Self::Ok, Self::Error> {
+        trace_encode!(self, "encode_time_of_day", tag, value, {
+            let nanoseconds = chrono::Timelike::nanosecond(value);
+            if nanoseconds != 0 {
+                return Err(crate::enc::Error::custom(alloc::format!(
+                    "`TIME-OF-DAY` can't hold fractions of a second, found {} nanoseconds.",
+                    nanoseconds
+                )));
+            }
+
+            self.encode_primitive(tag, value.format("%H%M%S").to_string().as_bytes());
+
+            Ok(())
+        })
+    }
+
+    fn encode_date_time(
+        &mut self,
+        tag: Tag,
+        value: &types::DateTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        trace_encode!(self, "encode_date_time", tag, value, {
+            let nanoseconds = chrono::Timelike::nanosecond(value);
+            if nanoseconds != 0 {
+                return Err(crate::enc::Error::custom(alloc::format!(
+                    "`DATE-TIME` can't hold fractions of a second, found {} nanoseconds.",
+                    nanoseconds
+                )));
+            }
+
+            self.encode_primitive(tag, value.format("%Y%m%d%H%M%S").to_string().as_bytes());
+
+            Ok(())
+        })
+    }
+
+    fn encode_duration(
+        &mut self,
+        tag: Tag,
+        value: &types::Duration,
+    ) -> Result<Self::Ok, Self::Error> {
+        trace_encode!(self, "encode_duration", tag, value, {
+            self.encode_primitive(tag, value.to_string().as_bytes());
+
+            Ok(())
+        })
+    }
+
+    fn encode_oid_iri(&mut self, tag: Tag, value: &types::OidIri) -> Result<Self::Ok, Self::Error> {
+        trace_encode!(self, "encode_oid_iri", tag, value, {
+            self.encode_primitive(tag, value.as_str().as_bytes());
+
+            Ok(())
+        })
+    }
+
+    fn encode_relative_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::RelativeOidIri,
+    ) -> Result<Self::Ok, Self::Error> {
+        trace_encode!(self, "encode_relative_oid_iri", tag, value, {
+            self.encode_primitive(tag, value.as_str().as_bytes());
+
+            Ok(())
+        })
     }
 
     fn encode_sequence_of<E: Encode>(
@@ -359,15 +692,40 @@ impl crate::Encoder for Encoder {
         tag: Tag,
         values: &[E],
     ) -> Result<Self::Ok, Self::Error> {
-        let mut sequence_encoder = Self::new(self.config);
+        self.encode_sequence_of_iter(tag, values)
+    }
 
-        for value in values {
-            value.encode(&mut sequence_encoder)?;
-        }
+    fn encode_sequence_of_iter<E: Encode, I: IntoIterator<Item = E>>(
+        &mut self,
+        tag: Tag,
+        values: I,
+    ) -> Result<Self::Ok, Self::Error> {
+        trace_encode!(self, "encode_sequence_of_iter", tag, {
+            // With the indefinite length form (which CER always uses), each
+            // element can be written straight into the output.
+            if self.config.indefinite_length && !self.is_set_encoding {
+                let ident_bytes = self.encode_identifier(Identifier::from_tag(tag, true));
+                self.append_byte_or_bytes(ident_bytes);
+                self.output.push(START_OF_CONTENTS);
+
+                for value in values {
+                    value.encode(self)?;
+                }
+
+                self.output.extend_from_slice(END_OF_CONTENTS);
+                return Ok(());
+            }
 
-        self.encode_constructed(tag, &sequence_encoder.output);
+            let mut sequence_encoder = self.new_nested(false);
 
-        Ok(())
+            for value in values {
+                value.encode(&mut sequence_encoder)?;
+            }
+
+            self.encode_nested(tag, sequence_encoder);
+
+            Ok(())
+        })
     }
 
     fn encode_set_of<E: Encode>(
@@ -375,15 +733,46 @@ impl crate::Encoder for Encoder {
         tag: Tag,
         values: &types::SetOf<E>,
     ) -> Result<Self::Ok, Self::Error> {
-        let mut sequence_encoder = Self::new(self.config);
+        self.encode_set_of_iter(tag, values)
+    }
 
-        for value in values {
-            value.encode(&mut sequence_encoder)?;
-        }
+    fn encode_set_of_iter<E: Encode, I: IntoIterator<Item = E>>(
+        &mut self,
+        tag: Tag,
+        values: I,
+    ) -> Result<Self::Ok, Self::Error> {
+        trace_encode!(self, "encode_set_of", tag, {
+            let mut set_encoder = self.new_nested(false);
 
-        self.encode_constructed(tag, &sequence_encoder.output);
+            if self.config.encoding_rules.is_ber() {
+                for value in values {
+                    value.encode(&mut set_encoder)?;
+                }
+            } else {
+                // CER and DER sort the elements by their encodings, which isn't
+                // necessarily the order of their values (e.g. `-1` is encoded
+                // after `1`), so each is encoded on its own first.
+                let mut elements = Vec::new();
+
+                for value in values {
+                    let mut element = set_encoder.new_nested(false);
+                    value.encode(&mut element)?;
+                    set_encoder.scratch = core::mem::take(&mut element.scratch);
+                    set_encoder.stats.values += element.stats.values;
+                    set_encoder.stats.fragmented |= element.stats.fragmented;
+                    elements.push(element.output());
+                }
 
-        Ok(())
+                elements.sort_by(|a, b| super::compare_set_of_elements(a, b));
+                for element in elements {
+                    set_encoder.output.extend_from_slice(&element);
+                }
+            }
+
+            self.encode_nested(tag, set_encoder);
+
+            Ok(())
+        })
     }
 
     fn encode_explicit_prefix<V: Encode>(
@@ -391,36 +780,42 @@ impl crate::Encoder for Encoder {
         tag: Tag,
         value: &V,
     ) -> Result<Self::Ok, Self::Error> {
-        let mut encoder = Self::new(self.config);
-        value.encode(&mut encoder)?;
-        self.encode_constructed(tag, &encoder.output);
-        Ok(())
+        trace_encode!(self, "encode_explicit_prefix", tag, {
+            let mut encoder = self.new_nested(false);
+            value.encode(&mut encoder)?;
+            self.encode_nested(tag, encoder);
+            Ok(())
+        })
     }
 
     fn encode_sequence<F>(&mut self, tag: Tag, encoder_scope: F) -> Result<Self::Ok, Self::Error>
     where
         F: FnOnce(&mut Self) -> Result<Self::Ok, Self::Error>,
     {
-        let mut encoder = Self::new(self.config);
+        trace_encode!(self, "encode_sequence", tag, {
+            let mut encoder = self.new_nested(false);
 
-        (encoder_scope)(&mut encoder)?;
+            (encoder_scope)(&mut encoder)?;
 
-        self.encode_constructed(tag, &encoder.output);
+            self.encode_nested(tag, encoder);
 
-        Ok(())
+            Ok(())
+        })
     }
 
     fn encode_set<F>(&mut self, tag: Tag, encoder_scope: F) -> Result<Self::Ok, Self::Error>
     where
         F: FnOnce(&mut Self) -> Result<Self::Ok, Self::Error>,
     {
-        let mut encoder = Self::new_set(self.config);
+        trace_encode!(self, "encode_set", tag, {
+            let mut encoder = self.new_nested(true);
 
-        (encoder_scope)(&mut encoder)?;
+            (encoder_scope)(&mut encoder)?;
 
-        self.encode_constructed(tag, &encoder.output());
+            self.encode_nested(tag, encoder);
 
-        Ok(())
+            Ok(())
+        })
     }
 }
 
@@ -444,6 +839,59 @@ mod tests {
         let primitive_encoded = &[0x03, 0x07, 0x00, 0x0A, 0x3B, 0x5F, 0x29, 0x1C, 0xD0][..];
 
         assert_eq!(primitive_encoded, super::super::encode(&bitstring).unwrap());
+        assert_eq!(
+            &[0x03, 0x01, 0x00][..],
+            super::super::encode(&types::BitString::new()).unwrap()
+        );
+
+        // Trailing zero bits are part of the value.
+        let mut trailing_zeros = types::BitString::repeat(false, 9);
+        trailing_zeros.set(0, true);
+        assert_eq!(
+            &[0x03, 0x03, 0x07, 0x80, 0x00][..],
+            super::super::encode(&trailing_zeros).unwrap()
+        );
+        assert_eq!(
+            &[0x03, 0x02, 0x00, 0x00][..],
+            super::super::encode(&types::BitString::repeat(false, 8)).unwrap()
+        );
+
+        // Bits past the end that are still in the buffer aren't encoded.
+        let mut truncated = types::BitString::from_vec(vec![0xFF]);
+        truncated.truncate(3);
+        assert_eq!(
+            &[0x03, 0x02, 0x05, 0xE0][..],
+            super::super::encode(&truncated).unwrap()
+        );
+
+        // In CER, every segment has its own unused bits octet.
+        let mut long = types::BitString::repeat(true, 1000 * 8);
+        long.push(true);
+        let encoded = crate::cer::encode(&long).unwrap();
+        assert_eq!(
+            &[0x23, 0x80, 0x03, 0x82, 0x03, 0xE8, 0x00, 0xFF],
+            &encoded[..8]
+        );
+        assert_eq!(
+            &[0x03, 0x03, 0x07, 0xFF, 0x80, 0x00, 0x00],
+            &encoded[1006..]
+        );
+        assert_eq!(
+            long,
+            crate::cer::decode::<types::BitString>(&encoded).unwrap()
+        );
+    }
+
+    #[test]
+    fn any_with_tag() {
+        use crate::{AsnType, Encoder as _};
+
+        // A non-canonical (BER) BOOLEAN, which must be kept intact.
+        let any = types::Any::new(vec![0x01, 0x01, 0x01]);
+        let mut enc = Encoder::new(EncoderOptions::der());
+        enc.encode_any_with_tag(C0::TAG, &any).unwrap();
+
+        assert_eq!(&[0xA0, 0x03, 0x01, 0x01, 0x01][..], &*enc.output());
     }
 
     #[test]
@@ -537,6 +985,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sequence_of_iter() {
+        use crate::Encoder as _;
+
+        fn encode_iter(options: EncoderOptions) -> Vec<u8> {
+            let mut enc = Encoder::new(options);
+            enc.encode_sequence_of_iter(Tag::SEQUENCE, (1..=3u8).map(|i| i * 2))
+                .unwrap();
+            enc.output()
+        }
+
+        let values = vec![2u8, 4, 6];
+
+        assert_eq!(
+            &[0x30, 0x9, 0x2, 0x1, 0x2, 0x2, 0x1, 0x4, 0x2, 0x1, 0x6][..],
+            encode_iter(EncoderOptions::der())
+        );
+        assert_eq!(
+            crate::der::encode(&values).unwrap(),
+            encode_iter(EncoderOptions::der())
+        );
+        assert_eq!(
+            crate::cer::encode(&values).unwrap(),
+            encode_iter(EncoderOptions::cer())
+        );
+        assert_eq!(
+            values,
+            crate::cer::decode::<Vec<u8>>(&encode_iter(EncoderOptions::cer())).unwrap()
+        );
+    }
+
+    #[test]
+    fn nested_sequences() {
+        use crate::Encoder as _;
+
+        fn encode_nested(enc: &mut Encoder) {
+            enc.encode_sequence(Tag::SEQUENCE, |enc| {
+                enc.encode_bool(Tag::BOOL, true)?;
+                enc.encode_sequence(Tag::SEQUENCE, |enc| {
+                    enc.encode_sequence(Tag::SEQUENCE, |enc| enc.encode_null(Tag::NULL))
+                })
+            })
+            .unwrap();
+        }
+
+        let expected = &[
+            0x30, 0x09, 0x01, 0x01, 0xFF, 0x30, 0x04, 0x30, 0x02, 0x05, 0x00,
+        ];
+        let mut enc = Encoder::new(EncoderOptions::der());
+
+        encode_nested(&mut enc);
+        assert_eq!(3, enc.scratch.len());
+        encode_nested(&mut enc);
+        // The buffers from the first encoding are reused by the second.
+        assert_eq!(3, enc.scratch.len());
+        assert!(enc.scratch.iter().all(|buffer| buffer.capacity() > 0));
+
+        assert_eq!([&expected[..], expected].concat(), enc.output());
+    }
+
     #[test]
     fn set() {
         use crate::{
@@ -587,4 +1095,115 @@ mod tests {
             output,
         );
     }
+
+    #[test]
+    fn indefinite_length() {
+        let value = vec![vec![1u32], vec![2]];
+        let encode = |options: EncoderOptions| {
+            let mut encoder = Encoder::new(options);
+            value.encode(&mut encoder).unwrap();
+            encoder.output()
+        };
+
+        let definite = encode(EncoderOptions::ber());
+        let indefinite = encode(EncoderOptions::ber().use_indefinite_length(true));
+
+        assert_eq!(
+            &[0x30, 0x0A, 0x30, 0x03, 0x02, 0x01, 0x01, 0x30, 0x03, 0x02, 0x01, 0x02][..],
+            &*definite
+        );
+        assert_eq!(
+            &[
+                0x30, 0x80, 0x30, 0x80, 0x02, 0x01, 0x01, 0x00, 0x00, 0x30, 0x80, 0x02, 0x01, 0x02,
+                0x00, 0x00, 0x00, 0x00
+            ][..],
+            &*indefinite
+        );
+        assert_eq!(
+            value,
+            crate::ber::decode::<Vec<Vec<u32>>>(&definite).unwrap()
+        );
+        assert_eq!(
+            value,
+            crate::ber::decode::<Vec<Vec<u32>>>(&indefinite).unwrap()
+        );
+
+        assert_eq!(
+            definite,
+            encode(EncoderOptions::der().use_indefinite_length(true))
+        );
+    }
+
+    #[test]
+    fn emit_defaults() {
+        #[derive(crate::AsnType, crate::Encode, Debug, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Config {
+            #[rasn(default)]
+            verbose: bool,
+            #[rasn(default = "default_retries")]
+            retries: u8,
+        }
+
+        fn default_retries() -> u8 {
+            3
+        }
+
+        let value = Config {
+            verbose: false,
+            retries: 3,
+        };
+        let encode = |options: EncoderOptions| {
+            let mut encoder = Encoder::new(options);
+            value.encode(&mut encoder).unwrap();
+            encoder.output()
+        };
+
+        assert_eq!(&[0x30, 0x00][..], &*encode(EncoderOptions::ber()));
+        assert_eq!(
+            &[0x30, 0x06, 0x01, 0x01, 0x00, 0x02, 0x01, 0x03][..],
+            &*encode(EncoderOptions::ber().emit_defaults(true))
+        );
+        assert_eq!(
+            &[0x30, 0x00][..],
+            &*encode(EncoderOptions::der().emit_defaults(true))
+        );
+        assert_eq!(
+            &[0x30, 0x80, 0x00, 0x00][..],
+            &*encode(EncoderOptions::cer().emit_defaults(true))
+        );
+    }
+
+    #[test]
+    fn stats() {
+        #[derive(crate::AsnType, crate::Encode)]
+        #[rasn(crate_root = "crate")]
+        struct Upload {
+            compressed: bool,
+            chunks: Vec<types::OctetString>,
+        }
+
+        let value = Upload {
+            compressed: true,
+            chunks: vec![vec![0; 1500].into()],
+        };
+        let encode = |options: EncoderOptions| {
+            let mut encoder = Encoder::new(options);
+            value.encode(&mut encoder).unwrap();
+            encoder.finish_with_stats()
+        };
+
+        let (output, stats) = encode(EncoderOptions::der());
+        assert_eq!(output.len(), stats.bytes);
+        assert_eq!(output.len() * 8, stats.bits());
+        // The SEQUENCE, the BOOLEAN, the SEQUENCE OF, and the string.
+        assert_eq!(4, stats.values);
+        assert!(!stats.fragmented);
+
+        let (output, stats) = encode(EncoderOptions::cer());
+        assert_eq!(output.len(), stats.bytes);
+        // The string is now a constructed value of two segments.
+        assert_eq!(6, stats.values);
+        assert!(stats.fragmented);
+    }
 }
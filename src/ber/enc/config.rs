@@ -4,6 +4,8 @@ use crate::ber::EncodingRules;
 #[derive(Clone, Copy, Debug)]
 pub struct EncoderOptions {
     pub(crate) encoding_rules: EncodingRules,
+    pub(crate) indefinite_length: bool,
+    pub(crate) emit_defaults: bool,
 }
 
 impl EncoderOptions {
@@ -11,6 +13,8 @@ impl EncoderOptions {
     pub const fn ber() -> Self {
         Self {
             encoding_rules: EncodingRules::Ber,
+            indefinite_length: false,
+            emit_defaults: false,
         }
     }
 
@@ -18,6 +22,8 @@ impl EncoderOptions {
     pub const fn cer() -> Self {
         Self {
             encoding_rules: EncodingRules::Cer,
+            indefinite_length: true,
+            emit_defaults: false,
         }
     }
 
@@ -25,6 +31,37 @@ impl EncoderOptions {
     pub const fn der() -> Self {
         Self {
             encoding_rules: EncodingRules::Der,
+            indefinite_length: false,
+            emit_defaults: false,
+        }
+    }
+
+    /// Sets whether constructed values are encoded with the indefinite length
+    /// form (`80 ... 00 00`) rather than the definite length form. The default
+    /// is the definite length form. Only BER allows a choice, so this has no
+    /// effect on CER and DER, which always use the indefinite and definite
+    /// length forms respectively.
+    pub const fn use_indefinite_length(self, indefinite_length: bool) -> Self {
+        match self.encoding_rules {
+            EncodingRules::Ber => Self {
+                indefinite_length,
+                ..self
+            },
+            _ => self,
+        }
+    }
+
+    /// Sets whether `DEFAULT` components are encoded even when they're equal
+    /// to their default value, for peers that expect them to be present. The
+    /// default is to omit them. Only BER allows a choice, so this has no
+    /// effect on CER and DER, which must always omit them.
+    pub const fn emit_defaults(self, emit_defaults: bool) -> Self {
+        match self.encoding_rules {
+            EncodingRules::Ber => Self {
+                emit_defaults,
+                ..self
+            },
+            _ => self,
         }
     }
 }
@@ -0,0 +1,126 @@
+//! Encoding a `SEQUENCE` one field at a time.
+
+use super::{Encoder, Error};
+use crate::{types::Tag, Encode};
+
+impl Encoder {
+    /// Starts encoding a `SEQUENCE` identified by `tag`, returning a builder
+    /// that encodes each field as it's added, as an alternative to
+    /// [`encode_sequence`][crate::Encoder::encode_sequence]'s closure. The
+    /// sequence is only written once [`SequenceEncoder::finish`] is called.
+    /// ```
+    /// use rasn::{ber::enc::{Encoder, EncoderOptions}, types::Tag};
+    ///
+    /// let mut encoder = Encoder::new(EncoderOptions::der());
+    /// let mut sequence = encoder.sequence(Tag::SEQUENCE);
+    /// sequence.field(&5u8)?.optional_field(&None::<bool>)?;
+    /// sequence.default_field(&true, &false)?;
+    /// sequence.finish()?;
+    ///
+    /// assert_eq!(&[0x30, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF][..], &*encoder.output());
+    /// # Ok::<(), rasn::ber::enc::Error>(())
+    /// ```
+    pub fn sequence(&mut self, tag: Tag) -> SequenceEncoder<'_> {
+        SequenceEncoder {
+            contents: self.new_nested(false),
+            outer: self,
+            tag,
+        }
+    }
+}
+
+/// A builder for the fields of a `SEQUENCE`, created by
+/// [`Encoder::sequence`]. Dropping it without calling [`Self::finish`]
+/// leaves the sequence out of the encoding entirely.
+pub struct SequenceEncoder<'encoder> {
+    outer: &'encoder mut Encoder,
+    contents: Encoder,
+    tag: Tag,
+}
+
+impl<'encoder> SequenceEncoder<'encoder> {
+    /// Encodes `value` as the next field of the sequence.
+    pub fn field<T: Encode>(&mut self, value: &T) -> Result<&mut Self, Error> {
+        value.encode(&mut self.contents)?;
+        Ok(self)
+    }
+
+    /// Encodes `value` as the next field of the sequence, if it's present,
+    /// as with an `OPTIONAL` field.
+    pub fn optional_field<T: Encode>(&mut self, value: &Option<T>) -> Result<&mut Self, Error> {
+        self.field(value)
+    }
+
+    /// Encodes `value` as the next field of the sequence, unless it's equal
+    /// to `default` (and the encoder isn't emitting defaults), as with a
+    /// `DEFAULT` field.
+    pub fn default_field<T: Encode + PartialEq>(
+        &mut self,
+        value: &T,
+        default: &T,
+    ) -> Result<&mut Self, Error> {
+        if crate::Encoder::emits_defaults(&self.contents) || value != default {
+            self.field(value)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Writes the sequence of the fields added so far.
+    pub fn finish(self) -> Result<(), Error> {
+        self.outer.encode_nested(self.tag, self.contents);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ber::enc::EncoderOptions, Encoder as _};
+
+    #[test]
+    fn matches_closure() {
+        let note: Option<&str> = Some("hi");
+        let encode_builder = |options| {
+            let mut encoder = Encoder::new(options);
+            let mut sequence = encoder.sequence(Tag::SEQUENCE);
+            sequence.field(&1u8)?.optional_field(&note)?;
+            sequence.optional_field(&None::<u8>)?;
+            sequence.default_field(&0u8, &0)?;
+            sequence.finish()?;
+            Ok::<_, Error>(encoder.output())
+        };
+        let encode_closure = |options| {
+            let mut encoder = Encoder::new(options);
+            encoder.encode_sequence(Tag::SEQUENCE, |encoder| {
+                1u8.encode(encoder)?;
+                note.encode(encoder)?;
+                if encoder.emits_defaults() {
+                    0u8.encode(encoder)?;
+                }
+                Ok(())
+            })?;
+            Ok::<_, Error>(encoder.output())
+        };
+
+        for options in [
+            EncoderOptions::ber(),
+            EncoderOptions::der(),
+            EncoderOptions::ber().emit_defaults(true),
+        ] {
+            assert_eq!(
+                encode_closure(options).unwrap(),
+                encode_builder(options).unwrap()
+            );
+        }
+
+        assert_eq!(
+            &[0x30, 0x07, 0x02, 0x01, 0x01, 0x0C, 0x02, b'h', b'i'][..],
+            &*encode_builder(EncoderOptions::der()).unwrap()
+        );
+
+        let mut encoder = Encoder::new(EncoderOptions::der());
+        encoder.sequence(Tag::SEQUENCE).field(&true).unwrap();
+        assert!(encoder.output().is_empty());
+    }
+}
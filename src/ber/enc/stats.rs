@@ -0,0 +1,22 @@
+/// Statistics about an encoding, returned by
+/// [`Encoder::finish_with_stats`][super::Encoder::finish_with_stats].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EncodeStats {
+    /// The length of the encoding in bytes.
+    pub bytes: usize,
+    /// The number of values encoded, counting each constructed value and each
+    /// of the values it contains, including the segments of a string that
+    /// was fragmented.
+    pub values: usize,
+    /// Whether any strings were fragmented into segments, which CER does for
+    /// strings longer than 1000 bytes.
+    pub fragmented: bool,
+}
+
+impl EncodeStats {
+    /// The length of the encoding in bits.
+    pub const fn bits(&self) -> usize {
+        self.bytes * 8
+    }
+}
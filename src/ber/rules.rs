@@ -10,10 +10,6 @@ impl EncodingRules {
         matches!(self, Self::Ber)
     }
 
-    pub fn is_cer(self) -> bool {
-        matches!(self, Self::Cer)
-    }
-
     pub fn is_der(self) -> bool {
         matches!(self, Self::Der)
     }
@@ -1,11 +1,26 @@
 //! # Canonical Encoding Rules
 
-/// Attempts to decode `T` from `input` using CER.
+/// Attempts to decode `T` from `input` using CER, failing if there's any
+/// input left after the value.
 pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::ber::de::Error> {
-    T::decode(&mut crate::ber::de::Decoder::new(
+    crate::ber::decode_with_options(input, crate::ber::de::DecoderOptions::cer())
+}
+
+/// Attempts to decode `T` from the start of `input` using CER, ignoring any
+/// input left after the value.
+pub fn decode_partial<T: crate::Decode>(input: &[u8]) -> Result<T, crate::ber::de::Error> {
+    crate::ber::decode_with_options(
         input,
-        crate::ber::de::DecoderOptions::cer(),
-    ))
+        crate::ber::de::DecoderOptions::cer().require_complete(false),
+    )
+}
+
+/// Attempts to decode every `T` from `input` using CER, until `input` is
+/// exhausted. See [`ber::decode_all`][crate::ber::decode_all] for details.
+pub fn decode_all<T: crate::Decode>(
+    input: &[u8],
+) -> Result<alloc::vec::Vec<T>, crate::ber::de::Error> {
+    crate::ber::decode_all_with_options(input, crate::ber::de::DecoderOptions::cer())
 }
 
 /// Attempts to encode `value` to CER.
@@ -0,0 +1,104 @@
+//! # Codec methods
+//!
+//! Convenience methods for decoding and encoding values with each of the
+//! enabled codecs, as an alternative to the free functions in each codec's
+//! module.
+
+use crate::AsnType;
+
+#[cfg(feature = "ber")]
+use crate::{ber, Decode, Encode};
+#[cfg(feature = "ber")]
+use alloc::vec::Vec;
+
+/// Methods for decoding and encoding a value with each of the enabled codecs,
+/// such as `Certificate::from_der(&data)` and `certificate.to_der()`.
+/// Implemented for every [`AsnType`], with the decoding methods only available
+/// for types implementing [`Decode`], and the encoding methods for types
+/// implementing [`Encode`].
+/// ```
+/// use rasn::Codec;
+///
+/// let encoded = 5u8.to_der().unwrap();
+/// assert_eq!(&[0x02, 0x01, 0x05][..], &*encoded);
+/// assert_eq!(5, u8::from_der(&encoded).unwrap());
+/// ```
+pub trait Codec: AsnType {
+    /// Attempts to decode `Self` from `input` using BER.
+    #[cfg(feature = "ber")]
+    fn from_ber(input: &[u8]) -> Result<Self, ber::de::Error>
+    where
+        Self: Decode + Sized,
+    {
+        ber::decode(input)
+    }
+
+    /// Attempts to decode `Self` from `input` using CER.
+    #[cfg(feature = "cer")]
+    fn from_cer(input: &[u8]) -> Result<Self, ber::de::Error>
+    where
+        Self: Decode + Sized,
+    {
+        crate::cer::decode(input)
+    }
+
+    /// Attempts to decode `Self` from `input` using DER.
+    #[cfg(feature = "der")]
+    fn from_der(input: &[u8]) -> Result<Self, ber::de::Error>
+    where
+        Self: Decode + Sized,
+    {
+        crate::der::decode(input)
+    }
+
+    /// Attempts to encode `self` to BER.
+    #[cfg(feature = "ber")]
+    fn to_ber(&self) -> Result<Vec<u8>, ber::enc::Error>
+    where
+        Self: Encode + Sized,
+    {
+        ber::encode(self)
+    }
+
+    /// Attempts to encode `self` to CER.
+    #[cfg(feature = "cer")]
+    fn to_cer(&self) -> Result<Vec<u8>, ber::enc::Error>
+    where
+        Self: Encode + Sized,
+    {
+        crate::cer::encode(self)
+    }
+
+    /// Attempts to encode `self` to DER.
+    #[cfg(feature = "der")]
+    fn to_der(&self) -> Result<Vec<u8>, ber::enc::Error>
+    where
+        Self: Encode + Sized,
+    {
+        crate::der::encode(self)
+    }
+}
+
+impl<T: AsnType + ?Sized> Codec for T {}
+
+#[cfg(all(test, feature = "ber", feature = "cer", feature = "der"))]
+mod tests {
+    use super::*;
+    use crate::types::{OctetString, SequenceOf};
+
+    #[test]
+    fn matches_free_functions() {
+        type Strings = SequenceOf<OctetString>;
+
+        let value: Strings = alloc::vec![OctetString::from_static(&[1; 1001])];
+
+        assert_eq!(ber::encode(&value).unwrap(), value.to_ber().unwrap());
+        assert_eq!(crate::cer::encode(&value).unwrap(), value.to_cer().unwrap());
+        assert_eq!(crate::der::encode(&value).unwrap(), value.to_der().unwrap());
+        assert_ne!(value.to_der().unwrap(), value.to_cer().unwrap());
+
+        assert_eq!(value, Strings::from_ber(&value.to_ber().unwrap()).unwrap());
+        assert_eq!(value, Strings::from_cer(&value.to_cer().unwrap()).unwrap());
+        assert_eq!(value, Strings::from_der(&value.to_der().unwrap()).unwrap());
+    }
+}
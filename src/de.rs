@@ -42,10 +42,39 @@ pub trait Decoder: Sized {
     fn decode_bool(&mut self, tag: Tag) -> Result<bool, Self::Error>;
     /// Decode an enumerated enum's discriminant identified by `tag` from the available input.
     fn decode_enumerated(&mut self, tag: Tag) -> Result<types::Integer, Self::Error>;
+    /// Decode an enumerated enum's discriminant identified by `tag` from the
+    /// available input, returning the index of the discriminant in
+    /// `discriminants`. Returns an error if the discriminant isn't present in
+    /// `discriminants`.
+    fn decode_enumerated_in(
+        &mut self,
+        tag: Tag,
+        discriminants: &[i128],
+    ) -> Result<usize, Self::Error> {
+        use num_traits::ToPrimitive;
+
+        let discriminant = self.decode_enumerated(tag)?;
+
+        discriminant
+            .to_i128()
+            .and_then(|value| discriminants.iter().position(|known| *known == value))
+            .ok_or_else(|| {
+                Error::custom(alloc::format!(
+                    "Invalid enumerated discriminant `{}`.",
+                    discriminant
+                ))
+            })
+    }
     /// Decode a `INTEGER` identified by `tag` from the available input.
     fn decode_integer(&mut self, tag: Tag) -> Result<types::Integer, Self::Error>;
     /// Decode `NULL` identified by `tag` from the available input.
     fn decode_null(&mut self, tag: Tag) -> Result<(), Self::Error>;
+    /// Decode a `REAL` identified by `tag` from the available input, as the
+    /// nearest `f64`. The default implementation returns an error.
+    fn decode_real(&mut self, tag: Tag) -> Result<f64, Self::Error> {
+        let _ = tag;
+        Err(Error::custom("`REAL` isn't supported by this decoder."))
+    }
     /// Decode a `OBJECT IDENTIFIER` identified by `tag` from the available input.
     fn decode_object_identifier(
         &mut self,
@@ -60,6 +89,18 @@ pub trait Decoder: Sized {
     fn decode_sequence_of<D: Decode>(&mut self, tag: Tag) -> Result<Vec<D>, Self::Error>;
     /// Decode a `SET OF D` where `D: Decode` identified by `tag` from the available input.
     fn decode_set_of<D: Decode + Ord>(&mut self, tag: Tag) -> Result<types::SetOf<D>, Self::Error>;
+    /// Decode a `SET OF D` value into any collection, such as a `HashSet`,
+    /// with the same checks as [`Self::decode_set_of`]. The default
+    /// implementation decodes the elements with
+    /// [`Self::decode_sequence_of`], without checking their order.
+    fn decode_set_of_into<D: Decode, C: Default + Extend<D>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<C, Self::Error> {
+        let mut collection = C::default();
+        collection.extend(self.decode_sequence_of(tag)?);
+        Ok(collection)
+    }
     /// Decode a `OCTET STRING` identified by `tag` from the available input.
     fn decode_octet_string(&mut self, tag: Tag) -> Result<Vec<u8>, Self::Error>;
     /// Decode a `UTF8 STRING` identified by `tag` from the available input.
@@ -70,6 +111,43 @@ pub trait Decoder: Sized {
     fn decode_utc_time(&mut self, tag: Tag) -> Result<types::UtcTime, Self::Error>;
     /// Decode a `GeneralizedTime` identified by `tag` from the available input.
     fn decode_generalized_time(&mut self, tag: Tag) -> Result<types::GeneralizedTime, Self::Error>;
+    /// Decode a `DATE` identified by `tag` from the available input. The
+    /// default implementation returns an error, as do the other time types'.
+    fn decode_date(&mut self, tag: Tag) -> Result<types::Date, Self::Error> {
+        let _ = tag;
+        Err(Error::custom("`DATE` isn't supported by this decoder."))
+    }
+    /// Decode a `TIME-OF-DAY` identified by `tag` from the available input.
+    fn decode_time_of_day(&mut self, tag: Tag) -> Result<types::TimeOfDay, Self::Error> {
+        let _ = tag;
+        Err(Error::custom(
+            "`TIME-OF-DAY` isn't supported by this decoder.",
+        ))
+    }
+    /// Decode a `DATE-TIME` identified by `tag` from the available input.
+    fn decode_date_time(&mut self, tag: Tag) -> Result<types::DateTime, Self::Error> {
+        let _ = tag;
+        Err(Error::custom(
+            "`DATE-TIME` isn't supported by this decoder.",
+        ))
+    }
+    /// Decode a `DURATION` identified by `tag` from the available input.
+    fn decode_duration(&mut self, tag: Tag) -> Result<types::Duration, Self::Error> {
+        let _ = tag;
+        Err(Error::custom("`DURATION` isn't supported by this decoder."))
+    }
+    /// Decode a `OID-IRI` identified by `tag` from the available input. The
+    /// default implementation decodes it as a `UTF8String`.
+    fn decode_oid_iri(&mut self, tag: Tag) -> Result<types::OidIri, Self::Error> {
+        types::OidIri::new(self.decode_utf8_string(tag)?)
+            .ok_or_else(|| Error::custom(types::InvalidIri))
+    }
+    /// Decode a `RELATIVE-OID-IRI` identified by `tag` from the available
+    /// input. The default implementation decodes it as a `UTF8String`.
+    fn decode_relative_oid_iri(&mut self, tag: Tag) -> Result<types::RelativeOidIri, Self::Error> {
+        types::RelativeOidIri::new(self.decode_utf8_string(tag)?)
+            .ok_or_else(|| Error::custom(types::InvalidIri))
+    }
     /// Decode a `SET` identified by `tag` from the available input. Decoding
     /// `SET`s works a little different than other methods, as you need to
     /// provide two types `SET` and `SET`, `SET` represents the complete type,
@@ -85,8 +163,198 @@ pub trait Decoder: Sized {
         SET: Decode,
         FIELDS: Decode,
         F: FnOnce(Vec<FIELDS>) -> Result<SET, Self::Error>;
+    /// Decode every remaining component in the current `SEQUENCE`, without
+    /// interpreting them, so that they can be re-encoded later. Decoders
+    /// that can't keep components as they were encoded return an error.
+    fn decode_unknown_fields(&mut self) -> Result<types::UnknownFields, Self::Error> {
+        Err(Error::custom(
+            "Unknown `SEQUENCE` components aren't supported by this decoder.",
+        ))
+    }
+    /// Checks that the remaining components of the current `SEQUENCE` appear
+    /// in the same order as `fields`, for encoding rules that require it.
+    /// Each element of `fields` is the tag tree of the corresponding field,
+    /// where an empty `CHOICE` (such as [`types::Any`]) matches any tag.
+    /// Components that don't match any field are ignored.
+    fn check_sequence_order(&mut self, fields: &[types::TagTree]) -> Result<(), Self::Error> {
+        let _ = fields;
+        Ok(())
+    }
+    /// Whether the `OPTIONAL` component identified by `tag` is present at
+    /// the current position of a `SEQUENCE`, when it can have the same tag
+    /// as some of the mandatory components that follow it, whose tag trees
+    /// are `following`. The component is only present if the components
+    /// after it still hold a value for each of `following`, in order.
+    /// Decoders that can't look ahead return `true`, so that the component
+    /// is decoded whenever its tag matches.
+    fn is_present_before(&mut self, tag: Tag, following: &[types::TagTree]) -> bool {
+        let _ = (tag, following);
+        true
+    }
+    /// Runs `decode_fn` with the most lenient variant of the decoder's
+    /// encoding rules, such as BER for a DER decoder, so that a single value
+    /// that doesn't follow the stricter rules can still be decoded. Decoders
+    /// without a more lenient variant run `decode_fn` as normal.
+    fn decode_leniently<D, F>(&mut self, decode_fn: F) -> Result<D, Self::Error>
+    where
+        F: FnOnce(&mut Self) -> Result<D, Self::Error>,
+    {
+        (decode_fn)(self)
+    }
+    /// Counts `elements` more decoded elements of a collection towards the
+    /// decoder's output limits, failing if the output is over them. A
+    /// collection decoded element by element calls this with `1` after each
+    /// element, and with `0` when an element fails, so that going over a
+    /// limit isn't mistaken for the end of the collection. Decoders without
+    /// output limits return `Ok(())`.
+    fn add_output_elements(&mut self, elements: usize) -> Result<(), Self::Error> {
+        let _ = elements;
+        Ok(())
+    }
+}
+
+/// A **data type** that can be decoded from any ASN.1 format by borrowing
+/// from input that lives for `'de`, such as `&'de str`, instead of copying
+/// it.
+///
+/// Every [`Decode`] type also implements this trait, so fields that borrow
+/// and fields that are owned can be decoded the same way. There's no derive
+/// for borrowing types yet, so a `SEQUENCE` is decoded by hand, field by
+/// field in order, as the derive does for [`Decode`].
+/// ```
+/// use rasn::{de::{DecodeBorrowed, ZeroCopyDecoder}, types::{AsnType, Tag}};
+///
+/// // Entry ::= SEQUENCE { name UTF8String, nickname UTF8String OPTIONAL,
+/// //                      data OCTET STRING, count INTEGER }
+/// #[derive(Debug, PartialEq)]
+/// struct Entry<'a> {
+///     name: &'a str,
+///     nickname: Option<&'a str>,
+///     data: &'a [u8],
+///     count: u8,
+/// }
+///
+/// impl AsnType for Entry<'_> {
+///     const TAG: Tag = Tag::SEQUENCE;
+/// }
+///
+/// impl<'de> DecodeBorrowed<'de> for Entry<'de> {
+///     fn decode_borrowed_with_tag<D: ZeroCopyDecoder<'de>>(
+///         decoder: &mut D,
+///         tag: Tag,
+///     ) -> Result<Self, D::Error> {
+///         decoder.decode_sequence(tag, |decoder| {
+///             Ok(Self {
+///                 name: <_>::decode_borrowed(decoder)?,
+///                 nickname: <_>::decode_borrowed(decoder)?,
+///                 data: <_>::decode_borrowed(decoder)?,
+///                 count: <_>::decode_borrowed(decoder)?,
+///             })
+///         })
+///     }
+/// }
+///
+/// let data = [0x30, 0x0A, 0x0C, 0x02, b'h', b'i', 0x04, 0x01, 0xFF, 0x02, 0x01, 0x05];
+/// let entry: Entry = rasn::der::decode_borrowed(&data).unwrap();
+///
+/// assert_eq!(
+///     Entry { name: "hi", nickname: None, data: &[0xFF], count: 5 },
+///     entry
+/// );
+/// assert_eq!(data[4..6].as_ptr(), entry.name.as_ptr());
+/// ```
+pub trait DecodeBorrowed<'de>: Sized + AsnType {
+    /// Decode this value from a given ASN.1 decoder, borrowing from its
+    /// input. See [`Decode::decode`].
+    fn decode_borrowed<D: ZeroCopyDecoder<'de>>(decoder: &mut D) -> Result<Self, D::Error> {
+        Self::decode_borrowed_with_tag(decoder, Self::TAG)
+    }
+
+    /// Decode this value implicitly tagged with `tag` from a given ASN.1
+    /// decoder, borrowing from its input. See [`Decode::decode_with_tag`].
+    fn decode_borrowed_with_tag<D: ZeroCopyDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error>;
+}
+
+/// A **data format** that can decode values by borrowing from its input,
+/// which lives for `'de`. Values that are split into segments, such as
+/// constructed strings, can't be borrowed and fail to decode.
+///
+/// Unlike [`BorrowedDecoder`][crate::ber::de::BorrowedDecoder], which never
+/// allocates, this is a [`Decoder`] that still can, and only borrows the
+/// strings it's asked to.
+pub trait ZeroCopyDecoder<'de>: Decoder {
+    /// Decode a `OCTET STRING` identified by `tag`, borrowing its contents
+    /// from the input.
+    fn decode_octet_string_borrowed(&mut self, tag: Tag) -> Result<&'de [u8], Self::Error>;
+    /// Decode a `UTF8String` identified by `tag`, borrowing its contents
+    /// from the input.
+    fn decode_utf8_string_borrowed(&mut self, tag: Tag) -> Result<&'de str, Self::Error>;
+}
+
+impl<'de, T: Decode> DecodeBorrowed<'de> for T {
+    fn decode_borrowed<D: ZeroCopyDecoder<'de>>(decoder: &mut D) -> Result<Self, D::Error> {
+        T::decode(decoder)
+    }
+
+    fn decode_borrowed_with_tag<D: ZeroCopyDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error> {
+        T::decode_with_tag(decoder, tag)
+    }
+}
+
+impl<'de> DecodeBorrowed<'de> for &'de str {
+    fn decode_borrowed_with_tag<D: ZeroCopyDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_utf8_string_borrowed(tag)
+    }
+}
+
+/// Decoded as an `OCTET STRING`, rather than with the `SEQUENCE` tag that
+/// `&[T]` has for other element types.
+impl<'de> DecodeBorrowed<'de> for &'de [u8] {
+    fn decode_borrowed<D: ZeroCopyDecoder<'de>>(decoder: &mut D) -> Result<Self, D::Error> {
+        Self::decode_borrowed_with_tag(decoder, Tag::OCTET_STRING)
+    }
+
+    fn decode_borrowed_with_tag<D: ZeroCopyDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_octet_string_borrowed(tag)
+    }
 }
 
+macro_rules! impl_optional_borrowed {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+        /// An `OPTIONAL` value that borrows from the input, which is `None`
+        /// when the next value doesn't decode, as with `Option<T>`'s
+        /// [`Decode`] impl.
+        impl<'de> DecodeBorrowed<'de> for Option<$ty> {
+            fn decode_borrowed<D: ZeroCopyDecoder<'de>>(decoder: &mut D) -> Result<Self, D::Error> {
+                Ok(<$ty>::decode_borrowed(decoder).ok())
+            }
+
+            fn decode_borrowed_with_tag<D: ZeroCopyDecoder<'de>>(
+                decoder: &mut D,
+                tag: Tag,
+            ) -> Result<Self, D::Error> {
+                Ok(<$ty>::decode_borrowed_with_tag(decoder, tag).ok())
+            }
+        }
+        )+
+    }
+}
+
+impl_optional_borrowed!(&'de str, &'de [u8]);
+
 /// A generic error that can occur while decoding ASN.1.
 pub trait Error: core::fmt::Display {
     /// Creates a new general error using `msg` when decoding ASN.1.
@@ -102,6 +370,17 @@ pub trait Error: core::fmt::Display {
     /// Creates a new error about being unable to decode a field in a compound
     /// type, such as a set or sequence.
     fn field_error<D: core::fmt::Display>(name: &'static str, error: D) -> Self;
+    /// Adds `context` to the error, describing where it occurred (e.g.
+    /// `"Certificate.tbs_certificate"`). Errors that pass through several
+    /// nested values gain a context for each, from the innermost outwards.
+    ///
+    /// By default this wraps the error with [`Error::field_error`].
+    fn context(self, context: &'static str) -> Self
+    where
+        Self: Sized,
+    {
+        Self::field_error(context, self)
+    }
     /// Creates a new error about finding a duplicate field.
     fn duplicate_field(name: &'static str) -> Self;
 }
@@ -128,6 +407,22 @@ impl Decode for bool {
     }
 }
 
+/// Decodes a `REAL`, which is rounded to the nearest `f64`, as a `REAL` can
+/// have a mantissa or exponent larger than a float has room for.
+impl Decode for f64 {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_real(tag)
+    }
+}
+
+/// Decodes a `REAL`, which is rounded to the nearest `f32`, becoming
+/// infinite if it's too large.
+impl Decode for f32 {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_real(tag).map(|value| value as f32)
+    }
+}
+
 macro_rules! impl_integers {
     ($($int:ty),+ $(,)?) => {
         $(
@@ -156,6 +451,178 @@ impl_integers! {
     usize,
 }
 
+macro_rules! impl_non_zero_integers {
+    ($($int:ty => $non_zero:ty),+ $(,)?) => {
+        $(
+        impl Decode for $non_zero {
+            fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+                <$non_zero>::new(<$int>::decode_with_tag(decoder, tag)?)
+                    .ok_or_else(|| Error::custom("Expected non-zero integer, found `0`."))
+            }
+        }
+        )+
+    }
+}
+
+impl_non_zero_integers! {
+    i8 => core::num::NonZeroI8,
+    i16 => core::num::NonZeroI16,
+    i32 => core::num::NonZeroI32,
+    i64 => core::num::NonZeroI64,
+    i128 => core::num::NonZeroI128,
+    isize => core::num::NonZeroIsize,
+    u8 => core::num::NonZeroU8,
+    u16 => core::num::NonZeroU16,
+    u32 => core::num::NonZeroU32,
+    u64 => core::num::NonZeroU64,
+    u128 => core::num::NonZeroU128,
+    usize => core::num::NonZeroUsize,
+}
+
+macro_rules! impl_ip_addrs {
+    ($($addr:ident: $len:literal),+ $(,)?) => {
+        $(
+        /// Decodes the address from an `OCTET STRING` of its bytes, in
+        /// network (big-endian) order.
+        impl Decode for core::net::$addr {
+            fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+                let bytes = decoder.decode_octet_string(tag)?;
+                let octets: [u8; $len] = core::convert::TryFrom::try_from(&*bytes).map_err(|_| {
+                    Error::custom(alloc::format!(
+                        "Expected {} bytes for `{}`, found {} bytes.",
+                        $len,
+                        stringify!($addr),
+                        bytes.len()
+                    ))
+                })?;
+
+                Ok(Self::from(octets))
+            }
+        }
+        )+
+    }
+}
+
+impl_ip_addrs! {
+    Ipv4Addr: 4,
+    Ipv6Addr: 16,
+}
+
+#[cfg(feature = "uuid")]
+impl Decode for uuid::Uuid {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        let bytes = decoder.decode_octet_string(tag)?;
+        uuid::Uuid::from_slice(&bytes).map_err(|_| {
+            Error::custom(alloc::format!(
+                "Expected 16 bytes for `Uuid`, found {} bytes.",
+                bytes.len()
+            ))
+        })
+    }
+}
+
+/// Decodes into a `String` first, as [`Decoder`] can only return owned
+/// strings, so this allocates. Use
+/// [`BorrowedDecoder::decode_array_string`][crate::ber::de::BorrowedDecoder::decode_array_string]
+/// to decode without an allocator.
+#[cfg(feature = "arrayvec")]
+impl<const N: usize> Decode for arrayvec::ArrayString<N> {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        let string = decoder.decode_utf8_string(tag)?;
+
+        Self::from(&string).map_err(|_| {
+            Error::custom(alloc::format!(
+                "`UTF8String` of {} bytes is longer than the capacity of {} bytes",
+                string.len(),
+                N
+            ))
+        })
+    }
+}
+
+/// Decodes the elements straight into the `SmallVec`, so a `SEQUENCE OF` with
+/// no more elements than fit inline doesn't allocate.
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Decode for smallvec::SmallVec<A>
+where
+    A::Item: Decode,
+{
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_sequence(tag, |decoder| {
+            let mut items = Self::new();
+
+            loop {
+                let item = match A::Item::decode(decoder) {
+                    Ok(item) => item,
+                    Err(_) => {
+                        decoder.add_output_elements(0)?;
+                        break;
+                    }
+                };
+
+                decoder.add_output_elements(1)?;
+                items.push(item);
+            }
+
+            Ok(items)
+        })
+    }
+}
+
+/// Decodes the elements straight into the `heapless::Vec`, failing if there
+/// are more than fit in its capacity. Use
+/// [`BorrowedDecoder::decode_heapless_vec`][crate::ber::de::BorrowedDecoder::decode_heapless_vec]
+/// to decode without an allocator.
+#[cfg(feature = "heapless")]
+impl<T: Decode, const N: usize> Decode for heapless::Vec<T, N> {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_sequence(tag, |decoder| {
+            let mut items = Self::new();
+
+            loop {
+                let item = match T::decode(decoder) {
+                    Ok(item) => item,
+                    Err(_) => {
+                        decoder.add_output_elements(0)?;
+                        break;
+                    }
+                };
+
+                decoder.add_output_elements(1)?;
+                items.push(item).map_err(|_| {
+                    Error::custom(alloc::format!(
+                        "`SEQUENCE OF` has more elements than the capacity of {}",
+                        N
+                    ))
+                })?;
+            }
+
+            Ok(items)
+        })
+    }
+}
+
+/// Use
+/// [`BorrowedDecoder::decode_heapless_string`][crate::ber::de::BorrowedDecoder::decode_heapless_string]
+/// to decode without an allocator.
+#[cfg(feature = "heapless")]
+impl<const N: usize> Decode for heapless::String<N> {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        let string = decoder.decode_utf8_string(tag)?;
+        let mut value = Self::new();
+
+        value.push_str(&string).map_err(|_| {
+            Error::custom(alloc::format!(
+                "`UTF8String` of {} bytes is longer than the capacity of {} bytes",
+                string.len(),
+                N
+            ))
+        })?;
+
+        Ok(value)
+    }
+}
+
 impl<T: Decode> Decode for Box<T> {
     fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error> {
         T::decode(decoder).map(Box::new)
@@ -166,12 +633,47 @@ impl<T: Decode> Decode for Box<T> {
     }
 }
 
+impl<T: Decode> Decode for alloc::rc::Rc<T> {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error> {
+        T::decode(decoder).map(Self::new)
+    }
+
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        T::decode_with_tag(decoder, tag).map(Self::new)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: Decode> Decode for alloc::sync::Arc<T> {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error> {
+        T::decode(decoder).map(Self::new)
+    }
+
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        T::decode_with_tag(decoder, tag).map(Self::new)
+    }
+}
+
 impl Decode for types::Integer {
     fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
         decoder.decode_integer(tag)
     }
 }
 
+/// Decodes an `INTEGER` that has to be non-negative, such as an RSA modulus.
+impl Decode for num_bigint::BigUint {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        let integer = decoder.decode_integer(tag)?;
+
+        integer.to_biguint().ok_or_else(|| {
+            Error::custom(alloc::format!(
+                "Expected non-negative integer, found `{}`.",
+                integer
+            ))
+        })
+    }
+}
+
 impl Decode for types::OctetString {
     fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
         decoder.decode_octet_string(tag).map(Self::from)
@@ -196,6 +698,24 @@ impl Decode for types::Utf8String {
     }
 }
 
+/// Decoded from a `GeneralizedTime`, keeping its UTC offset.
+#[cfg(feature = "time")]
+impl Decode for time::OffsetDateTime {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        let value = decoder.decode_generalized_time(tag)?;
+
+        time::UtcOffset::from_whole_seconds(value.offset().local_minus_utc())
+            .ok()
+            .zip(time::OffsetDateTime::from_unix_timestamp(value.timestamp()).ok())
+            .and_then(|(offset, utc)| {
+                utc.replace_nanosecond(value.timestamp_subsec_nanos())
+                    .ok()?
+                    .checked_to_offset(offset)
+            })
+            .ok_or_else(|| Error::custom("`GeneralizedTime` is out of range for `OffsetDateTime`."))
+    }
+}
+
 impl Decode for types::UtcTime {
     fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
         decoder.decode_utc_time(tag)
@@ -208,24 +728,89 @@ impl Decode for types::GeneralizedTime {
     }
 }
 
+impl Decode for types::Date {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_date(tag)
+    }
+}
+
+impl Decode for types::TimeOfDay {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_time_of_day(tag)
+    }
+}
+
+impl Decode for types::DateTime {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_date_time(tag)
+    }
+}
+
+impl Decode for types::Duration {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_duration(tag)
+    }
+}
+
+impl Decode for types::OidIri {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_oid_iri(tag)
+    }
+}
+
+impl Decode for types::RelativeOidIri {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_relative_oid_iri(tag)
+    }
+}
+
 impl Decode for types::Any {
     fn decode_with_tag<D: Decoder>(decoder: &mut D, _: Tag) -> Result<Self, D::Error> {
         decoder.decode_any()
     }
 }
 
+#[cfg(feature = "ber")]
+impl Decode for types::Value {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, _: Tag) -> Result<Self, D::Error> {
+        // `Any` always holds a complete BER encoded value.
+        let any = decoder.decode_any()?;
+        crate::ber::de::Decoder::new(any.as_bytes(), crate::ber::de::DecoderOptions::ber())
+            .decode_value()
+            .map_err(Error::custom)
+    }
+}
+
 impl<T: Decode> Decode for alloc::vec::Vec<T> {
     fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
         decoder.decode_sequence_of(tag)
     }
 }
 
+impl<T: Decode> Decode for alloc::collections::VecDeque<T> {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_sequence_of(tag).map(Self::from)
+    }
+}
+
 impl<T: Decode + Ord> Decode for alloc::collections::BTreeSet<T> {
     fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
         decoder.decode_set_of(tag)
     }
 }
 
+/// Duplicate elements are merged, as with [`types::SetOf`].
+#[cfg(feature = "std")]
+impl<T, S> Decode for std::collections::HashSet<T, S>
+where
+    T: Decode + Eq + core::hash::Hash,
+    S: core::hash::BuildHasher + Default,
+{
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_set_of_into(tag)
+    }
+}
+
 impl<T: Decode + Default, const N: usize> Decode for [T; N] {
     fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
         let sequence = decoder.decode_sequence_of(tag)?;
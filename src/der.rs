@@ -1,20 +1,130 @@
 //! # Distinguished Encoding Rules
 
-/// Attempts to decode `T` from `input` using DER.
+/// Attempts to decode `T` from `input` using DER, failing if there's any
+/// input left after the value.
 pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::ber::de::Error> {
-    T::decode(&mut crate::ber::de::Decoder::new(
+    crate::ber::decode_with_options(input, crate::ber::de::DecoderOptions::der())
+}
+
+/// Attempts to decode `T` from the start of `input` using DER, ignoring any
+/// input left after the value.
+pub fn decode_partial<T: crate::Decode>(input: &[u8]) -> Result<T, crate::ber::de::Error> {
+    crate::ber::decode_with_options(
         input,
-        crate::ber::de::DecoderOptions::der(),
-    ))
+        crate::ber::de::DecoderOptions::der().require_complete(false),
+    )
+}
+
+/// Attempts to decode `T` from `input` using DER, borrowing from `input` where
+/// `T` can. See [`ber::decode_borrowed`][crate::ber::decode_borrowed].
+pub fn decode_borrowed<'de, T: crate::de::DecodeBorrowed<'de>>(
+    input: &'de [u8],
+) -> Result<T, crate::ber::de::Error> {
+    crate::ber::decode_borrowed_with_options(input, crate::ber::de::DecoderOptions::der())
+}
+
+/// Attempts to decode every `T` from `input` using DER, until `input` is
+/// exhausted. See [`ber::decode_all`][crate::ber::decode_all] for details.
+pub fn decode_all<T: crate::Decode>(
+    input: &[u8],
+) -> Result<alloc::vec::Vec<T>, crate::ber::de::Error> {
+    crate::ber::decode_all_with_options(input, crate::ber::de::DecoderOptions::der())
 }
 
 /// Attempts to encode `value` to DER.
+///
+/// With the `der_self_check` feature, debug builds also check that the
+/// output is canonical, panicking if it isn't. See [`self_check`] for what's
+/// checked.
 pub fn encode<T: crate::Encode>(value: &T) -> Result<alloc::vec::Vec<u8>, crate::ber::enc::Error> {
     let mut enc = crate::ber::enc::Encoder::new(crate::ber::enc::EncoderOptions::der());
 
     value.encode(&mut enc)?;
 
-    Ok(enc.output())
+    let output = enc.output();
+    #[cfg(all(debug_assertions, feature = "der_self_check"))]
+    self_check(&output);
+    Ok(output)
+}
+
+/// Checks that `encoded` is canonical DER, by decoding it as a dynamic
+/// [`Value`][crate::types::Value] and encoding it again, which has to produce
+/// the same bytes, and that the elements of every `SET` are sorted. This
+/// catches encodings such as non-minimal integers or lengths, and unsorted
+/// `SET`s, but as the value's type isn't known, it can't catch a `DEFAULT`
+/// value that should have been omitted.
+///
+/// # Panics
+/// If `encoded` isn't a sequence of valid, canonical DER values.
+#[cfg(all(debug_assertions, feature = "der_self_check"))]
+pub fn self_check(encoded: &[u8]) {
+    use crate::{types::Value, Encode};
+
+    fn encode_value(value: &Value) -> alloc::vec::Vec<u8> {
+        let mut enc = crate::ber::enc::Encoder::new(crate::ber::enc::EncoderOptions::der());
+        value
+            .encode(&mut enc)
+            .expect("Re-encoding a decoded value can't fail");
+        enc.output()
+    }
+
+    // A `SET`'s elements have distinct tags and are sorted by tag, and a
+    // `SET OF`'s are sorted by encoding, and decoded `Value`s don't say
+    // which of the two they were.
+    fn check_sets(value: &Value) {
+        match value {
+            Value::Set(values) => {
+                let encodings: alloc::vec::Vec<_> = values.iter().map(encode_value).collect();
+                assert!(
+                    values.windows(2).all(|pair| pair[0].tag() < pair[1].tag())
+                        || encodings.windows(2).all(|pair| pair[0] <= pair[1]),
+                    "DER encoding has a `SET` whose elements aren't sorted: {:?}",
+                    value
+                );
+                values.iter().for_each(check_sets);
+            }
+            Value::Sequence(values) | Value::Constructed(_, values) => {
+                values.iter().for_each(check_sets)
+            }
+            Value::Tagged(_, value) => check_sets(value),
+            _ => {}
+        }
+    }
+
+    let values: alloc::vec::Vec<Value> = decode_all(encoded)
+        .unwrap_or_else(|error| panic!("DER encoding can't be decoded: {}", error));
+    let reencoded: alloc::vec::Vec<u8> = values.iter().flat_map(encode_value).collect();
+
+    assert_eq!(
+        encoded, &*reencoded,
+        "DER encoding changed when decoded and encoded again, so it isn't canonical"
+    );
+    values.iter().for_each(check_sets);
+}
+
+/// Whether `a` and `b` have the same DER encoding, and so are the same ASN.1
+/// value, regardless of how their Rust representations compare.
+///
+/// Both values are encoded one after the other by the same encoder, so they
+/// share a single output buffer.
+/// ```
+/// use rasn::types::{Integer, SetOf};
+///
+/// let a: SetOf<Integer> = vec![1.into(), 2.into()].into_iter().collect();
+/// let b: SetOf<Integer> = vec![2.into(), 1.into()].into_iter().collect();
+/// assert!(rasn::der::encodings_equal(&a, &b).unwrap());
+/// assert!(!rasn::der::encodings_equal(&a, &SetOf::new()).unwrap());
+/// ```
+pub fn encodings_equal<T: crate::Encode>(a: &T, b: &T) -> Result<bool, crate::ber::enc::Error> {
+    let mut enc = crate::ber::enc::Encoder::new(crate::ber::enc::EncoderOptions::der());
+
+    a.encode(&mut enc)?;
+    let a_len = enc.output_len();
+    b.encode(&mut enc)?;
+
+    let output = enc.output();
+    let (a, b) = output.split_at(a_len);
+    Ok(a == b)
 }
 
 /// Creates a new DER encoder that can be used to encode any value.
@@ -27,3 +137,37 @@ pub fn encode_scope(
 
     Ok(enc.output())
 }
+
+#[cfg(all(test, debug_assertions, feature = "der_self_check"))]
+mod tests {
+    use super::*;
+    use crate::types::{SetOf, Tag, Value};
+
+    #[test]
+    fn canonical_encodings_pass() {
+        let set: SetOf<u8> = alloc::vec![2, 1, 3].into_iter().collect();
+        assert_eq!(
+            &[0x31, 0x09, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03][..],
+            &*encode(&set).unwrap()
+        );
+        assert!(encode(&None::<u8>).unwrap().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "aren't sorted")]
+    fn unsorted_set() {
+        let _ = encode(&Value::Set(alloc::vec![
+            Value::Integer(2.into()),
+            Value::Integer(1.into()),
+        ]));
+    }
+
+    #[test]
+    #[should_panic(expected = "isn't canonical")]
+    fn non_minimal_integer() {
+        let _ = encode(&Value::Primitive(
+            Tag::INTEGER,
+            crate::types::OctetString::from_static(&[0x00, 0x05]),
+        ));
+    }
+}
@@ -31,8 +31,25 @@ pub trait Encoder {
     type Ok;
     type Error: Error;
 
+    /// Whether `DEFAULT` components should be encoded even when they're equal
+    /// to their default value. The default implementation returns `false`,
+    /// which is what canonical encoding rules require.
+    fn emits_defaults(&self) -> bool {
+        false
+    }
+
     /// Encode an unknown ASN.1 value.
     fn encode_any(&mut self, value: &types::Any) -> Result<Self::Ok, Self::Error>;
+    /// Encode an unknown ASN.1 value explicitly tagged with `tag`. The
+    /// contents of `value` are written as-is, without being re-parsed or
+    /// re-canonicalised.
+    fn encode_any_with_tag(
+        &mut self,
+        tag: Tag,
+        value: &types::Any,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_explicit_prefix(tag, value)
+    }
     /// Encode a `BIT STRING` value.
     fn encode_bit_string(
         &mut self,
@@ -55,11 +72,67 @@ pub trait Encoder {
         tag: Tag,
         value: &types::GeneralizedTime,
     ) -> Result<Self::Ok, Self::Error>;
+    /// Encode a `DATE` value. The default implementation returns an error,
+    /// as do the other time types'.
+    fn encode_date(&mut self, tag: Tag, value: &types::Date) -> Result<Self::Ok, Self::Error> {
+        let _ = (tag, value);
+        Err(Error::custom("`DATE` isn't supported by this encoder."))
+    }
+    /// Encode a `TIME-OF-DAY` value.
+    fn encode_time_of_day(
+        &mut self,
+        tag: Tag,
+        value: &types::TimeOfDay,
+    ) -> Result<Self::Ok, Self::Error> {
+        let _ = (tag, value);
+        Err(Error::custom(
+            "`TIME-OF-DAY` isn't supported by this encoder.",
+        ))
+    }
+    /// Encode a `DATE-TIME` value.
+    fn encode_date_time(
+        &mut self,
+        tag: Tag,
+        value: &types::DateTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        let _ = (tag, value);
+        Err(Error::custom(
+            "`DATE-TIME` isn't supported by this encoder.",
+        ))
+    }
+    /// Encode a `DURATION` value.
+    fn encode_duration(
+        &mut self,
+        tag: Tag,
+        value: &types::Duration,
+    ) -> Result<Self::Ok, Self::Error> {
+        let _ = (tag, value);
+        Err(Error::custom("`DURATION` isn't supported by this encoder."))
+    }
+    /// Encode a `OID-IRI` value. The default implementation encodes it as a
+    /// `UTF8String`.
+    fn encode_oid_iri(&mut self, tag: Tag, value: &types::OidIri) -> Result<Self::Ok, Self::Error> {
+        self.encode_utf8_string(tag, value.as_str())
+    }
+    /// Encode a `RELATIVE-OID-IRI` value. The default implementation encodes
+    /// it as a `UTF8String`.
+    fn encode_relative_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::RelativeOidIri,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_utf8_string(tag, value.as_str())
+    }
     /// Encode a `INTEGER` value.
     fn encode_integer(&mut self, tag: Tag, value: &types::Integer)
         -> Result<Self::Ok, Self::Error>;
     /// Encode a `NULL` value.
     fn encode_null(&mut self, tag: Tag) -> Result<Self::Ok, Self::Error>;
+    /// Encode a `REAL` value. The default implementation returns an error.
+    fn encode_real(&mut self, tag: Tag, value: f64) -> Result<Self::Ok, Self::Error> {
+        let _ = (tag, value);
+        Err(Error::custom("`REAL` isn't supported by this encoder."))
+    }
     /// Encode a `OBJECT IDENTIFIER` value.
     fn encode_object_identifier(
         &mut self,
@@ -68,6 +141,18 @@ pub trait Encoder {
     ) -> Result<Self::Ok, Self::Error>;
     /// Encode a `OCTET STRING` value.
     fn encode_octet_string(&mut self, tag: Tag, value: &[u8]) -> Result<Self::Ok, Self::Error>;
+    /// Encode a primitive value identified by `tag` from its already encoded
+    /// `contents`, such as a [`Value::Primitive`][types::Value::Primitive].
+    /// Unlike an `OCTET STRING`, the contents are never split into segments.
+    /// The default implementation encodes them as an `OCTET STRING`, for
+    /// encoders that never split strings.
+    fn encode_primitive_contents(
+        &mut self,
+        tag: Tag,
+        contents: &[u8],
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_octet_string(tag, contents)
+    }
     /// Encode a `SEQUENCE` value.
     fn encode_sequence<F>(&mut self, tag: Tag, encoder_scope: F) -> Result<Self::Ok, Self::Error>
     where
@@ -78,11 +163,44 @@ pub trait Encoder {
         tag: Tag,
         value: &[E],
     ) -> Result<Self::Ok, Self::Error>;
+    /// Encode a `SEQUENCE OF` value from an iterator, encoding each element
+    /// as it is produced rather than requiring the values to be collected.
+    ///
+    /// **Note** Codecs that use a definite length (such as BER and DER) must
+    /// know the length of the contents before writing them, so while the
+    /// *values* are never collected, their encoded contents are still buffered
+    /// before being written. Codecs with an indefinite form (such as CER) can
+    /// write each element directly to the output in a single pass.
+    ///
+    /// The default implementation collects the values and calls
+    /// [`Encoder::encode_sequence_of`].
+    fn encode_sequence_of_iter<E: Encode, I: IntoIterator<Item = E>>(
+        &mut self,
+        tag: Tag,
+        values: I,
+    ) -> Result<Self::Ok, Self::Error> {
+        let values: alloc::vec::Vec<E> = values.into_iter().collect();
+        self.encode_sequence_of(tag, &values)
+    }
     fn encode_set_of<E: Encode>(
         &mut self,
         tag: Tag,
         value: &types::SetOf<E>,
     ) -> Result<Self::Ok, Self::Error>;
+    /// Encode a `SET OF` value from `values` in any order, such as from a
+    /// `HashSet`. Encoding rules that require a canonical order sort the
+    /// elements by their encodings.
+    ///
+    /// The default implementation collects the values and calls
+    /// [`Encoder::encode_sequence_of`], keeping the order of `values`.
+    fn encode_set_of_iter<E: Encode, I: IntoIterator<Item = E>>(
+        &mut self,
+        tag: Tag,
+        values: I,
+    ) -> Result<Self::Ok, Self::Error> {
+        let values: alloc::vec::Vec<E> = values.into_iter().collect();
+        self.encode_sequence_of(tag, &values)
+    }
     /// Encode a `UtcTime` value.
     fn encode_utc_time(
         &mut self,
@@ -117,6 +235,16 @@ impl<E: Encode> Encode for &'_ E {
     }
 }
 
+impl<E: Encode> Encode for &'_ mut E {
+    fn encode<EN: Encoder>(&self, encoder: &mut EN) -> Result<(), EN::Error> {
+        E::encode(self, encoder)
+    }
+
+    fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
+        E::encode_with_tag(self, encoder, tag)
+    }
+}
+
 impl Encode for () {
     fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
         encoder.encode_null(tag).map(drop)
@@ -145,6 +273,22 @@ impl Encode for bool {
     }
 }
 
+/// Encodes the exact value of the float as a base 2 `REAL`, with the special
+/// values for infinity, NaN, and negative zero.
+impl Encode for f64 {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_real(tag, *self).map(drop)
+    }
+}
+
+/// Encodes the exact value of the float as a base 2 `REAL`, with the special
+/// values for infinity, NaN, and negative zero.
+impl Encode for f32 {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_real(tag, f64::from(*self)).map(drop)
+    }
+}
+
 macro_rules! impl_integers {
     ($($int:ty),+) => {
         $(
@@ -172,12 +316,72 @@ impl_integers! {
     usize
 }
 
+macro_rules! impl_non_zero_integers {
+    ($($int:ty),+) => {
+        $(
+            impl Encode for $int {
+                fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+                    self.get().encode_with_tag(encoder, tag)
+                }
+            }
+        )+
+    }
+}
+
+impl_non_zero_integers! {
+    core::num::NonZeroI8,
+    core::num::NonZeroI16,
+    core::num::NonZeroI32,
+    core::num::NonZeroI64,
+    core::num::NonZeroI128,
+    core::num::NonZeroIsize,
+    core::num::NonZeroU8,
+    core::num::NonZeroU16,
+    core::num::NonZeroU32,
+    core::num::NonZeroU64,
+    core::num::NonZeroU128,
+    core::num::NonZeroUsize
+}
+
+/// Encodes the address as an `OCTET STRING` of its bytes, in network
+/// (big-endian) order.
+impl Encode for core::net::Ipv4Addr {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_octet_string(tag, &self.octets()).map(drop)
+    }
+}
+
+/// Encodes the address as an `OCTET STRING` of its bytes, in network
+/// (big-endian) order.
+impl Encode for core::net::Ipv6Addr {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_octet_string(tag, &self.octets()).map(drop)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl Encode for uuid::Uuid {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_octet_string(tag, self.as_bytes()).map(drop)
+    }
+}
+
 impl Encode for types::Integer {
     fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
         encoder.encode_integer(tag, self).map(drop)
     }
 }
 
+/// Encoded with a leading `0x00` byte when its most significant bit is set,
+/// so that it isn't read as negative.
+impl Encode for num_bigint::BigUint {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder
+            .encode_integer(tag, &types::Integer::from(self.clone()))
+            .map(drop)
+    }
+}
+
 impl Encode for types::OctetString {
     fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
         encoder.encode_octet_string(tag, self).map(drop)
@@ -226,12 +430,107 @@ impl Encode for types::GeneralizedTime {
     }
 }
 
+/// Encoded as a `GeneralizedTime`, with its UTC offset for BER, and in UTC
+/// for CER and DER.
+#[cfg(feature = "time")]
+impl Encode for time::OffsetDateTime {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        let value = chrono::FixedOffset::east_opt(self.offset().whole_seconds())
+            .and_then(|offset| {
+                chrono::TimeZone::timestamp_opt(&offset, self.unix_timestamp(), self.nanosecond())
+                    .single()
+            })
+            .ok_or_else(|| {
+                Error::custom("`OffsetDateTime` is out of range for `GeneralizedTime`.")
+            })?;
+
+        encoder.encode_generalized_time(tag, &value).map(drop)
+    }
+}
+
+impl Encode for types::Date {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_date(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::TimeOfDay {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_time_of_day(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::DateTime {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_date_time(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::Duration {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_duration(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::OidIri {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_oid_iri(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::RelativeOidIri {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_relative_oid_iri(tag, self).map(drop)
+    }
+}
+
 impl Encode for types::Any {
     fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, _: Tag) -> Result<(), E::Error> {
         encoder.encode_any(self).map(drop)
     }
 }
 
+impl Encode for types::Value {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, _: Tag) -> Result<(), E::Error> {
+        use types::Value;
+
+        let tag = self.tag();
+        match self {
+            Value::Bool(value) => encoder.encode_bool(tag, *value),
+            Value::Integer(value) => encoder.encode_integer(tag, value),
+            Value::Enumerated(value) => encoder.encode_enumerated(tag, *value),
+            Value::BitString(value) => encoder.encode_bit_string(tag, value),
+            Value::OctetString(value) => encoder.encode_octet_string(tag, value),
+            Value::Null => encoder.encode_null(tag),
+            Value::ObjectIdentifier(value) => encoder.encode_object_identifier(tag, value),
+            Value::Utf8String(value) => encoder.encode_utf8_string(tag, value),
+            Value::UtcTime(value) => encoder.encode_utc_time(tag, value),
+            Value::GeneralizedTime(value) => encoder.encode_generalized_time(tag, value),
+            Value::Sequence(values) | Value::Set(values) | Value::Constructed(_, values) => encoder
+                .encode_sequence(tag, |encoder| {
+                    for value in values {
+                        value.encode(encoder)?;
+                    }
+
+                    Ok(())
+                }),
+            Value::Tagged(_, value) => encoder.encode_explicit_prefix(tag, &**value),
+            Value::Primitive(_, contents) => encoder.encode_primitive_contents(tag, contents),
+        }
+        .map(drop)
+    }
+}
+
+impl Encode for types::UnknownFields {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, _: Tag) -> Result<(), E::Error> {
+        for (_, encoded) in self.iter() {
+            encoder.encode_any(&types::Any::new(encoded.to_vec()))?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<E: Encode> Encode for alloc::boxed::Box<E> {
     fn encode<EN: Encoder>(&self, encoder: &mut EN) -> Result<(), EN::Error> {
         E::encode(&*self, encoder)
@@ -242,18 +541,85 @@ impl<E: Encode> Encode for alloc::boxed::Box<E> {
     }
 }
 
+impl<E: Encode> Encode for alloc::rc::Rc<E> {
+    fn encode<EN: Encoder>(&self, encoder: &mut EN) -> Result<(), EN::Error> {
+        E::encode(&**self, encoder)
+    }
+
+    fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
+        E::encode_with_tag(&**self, encoder, tag)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<E: Encode> Encode for alloc::sync::Arc<E> {
+    fn encode<EN: Encoder>(&self, encoder: &mut EN) -> Result<(), EN::Error> {
+        E::encode(&**self, encoder)
+    }
+
+    fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
+        E::encode_with_tag(&**self, encoder, tag)
+    }
+}
+
 impl<E: Encode> Encode for alloc::vec::Vec<E> {
     fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
         encoder.encode_sequence_of(tag, self).map(drop)
     }
 }
 
+impl<E: Encode> Encode for alloc::collections::VecDeque<E> {
+    fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
+        encoder.encode_sequence_of_iter(tag, self).map(drop)
+    }
+}
+
 impl<E: Encode> Encode for alloc::collections::BTreeSet<E> {
     fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
         encoder.encode_set_of(tag, self).map(drop)
     }
 }
 
+/// The elements are sorted by their encodings for CER and DER, as a
+/// `HashSet` has no order of its own.
+#[cfg(feature = "std")]
+impl<E: Encode, S> Encode for std::collections::HashSet<E, S> {
+    fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
+        encoder.encode_set_of_iter(tag, self).map(drop)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const N: usize> Encode for arrayvec::ArrayString<N> {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_utf8_string(tag, self).map(drop)
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Encode for smallvec::SmallVec<A>
+where
+    A::Item: Encode,
+{
+    fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
+        encoder.encode_sequence_of(tag, self).map(drop)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<E: Encode, const N: usize> Encode for heapless::Vec<E, N> {
+    fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
+        encoder.encode_sequence_of(tag, self).map(drop)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> Encode for heapless::String<N> {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_utf8_string(tag, self).map(drop)
+    }
+}
+
 impl<E: Encode, const N: usize> Encode for [E; N] {
     fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
         encoder.encode_sequence_of(tag, self).map(drop)
@@ -1,19 +1,27 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+pub mod codec;
 pub mod de;
 pub mod enc;
+pub mod schema;
 pub mod types;
 
 // Data Formats
 
+#[cfg(feature = "ber")]
 pub mod ber;
+#[cfg(feature = "cer")]
 pub mod cer;
+#[cfg(feature = "der")]
 pub mod der;
 
 #[doc(inline)]
 pub use self::{
+    codec::Codec,
     de::{Decode, Decoder},
     enc::{Encode, Encoder},
     types::{AsnType, Tag, TagTree},
@@ -23,6 +31,7 @@ pub use self::{
 /// module.
 pub mod prelude {
     pub use crate::{
+        codec::Codec,
         de::{Decode, Decoder},
         enc::{Encode, Encoder},
         types::*,
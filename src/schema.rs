@@ -0,0 +1,134 @@
+//! # Runtime Schemas
+//! The `schema` module provides a way to describe the shape of an ASN.1 value
+//! at runtime, for when the type isn't known at compile-time (e.g. a schema
+//! loaded from a file in a generic validator). A [`Schema`] can then be
+//! checked against encoded data with [`ber::validate`][crate::ber::validate].
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::types::Tag;
+
+/// A description of an ASN.1 type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Schema {
+    /// Any valid ASN.1 value.
+    Any,
+    /// The `BOOLEAN` type.
+    Bool,
+    /// The `INTEGER` type.
+    Integer,
+    /// The `ENUMERATED` type.
+    Enumerated,
+    /// The `BIT STRING` type.
+    BitString,
+    /// The `OCTET STRING` type.
+    OctetString,
+    /// The `NULL` type.
+    Null,
+    /// The `OBJECT IDENTIFIER` type.
+    ObjectIdentifier,
+    /// The `UTF8String` type.
+    Utf8String,
+    /// The `UTCTime` type.
+    UtcTime,
+    /// The `GeneralizedTime` type.
+    GeneralizedTime,
+    /// A `SEQUENCE` containing `fields` in order.
+    Sequence(Vec<Field>),
+    /// A `SEQUENCE OF` the inner schema.
+    SequenceOf(Box<Schema>),
+    /// A `SET` containing `fields` in any order.
+    Set(Vec<Field>),
+    /// A `SET OF` the inner schema.
+    SetOf(Box<Schema>),
+    /// A `CHOICE` between the alternatives.
+    Choice(Vec<Field>),
+    /// The inner schema implicitly tagged with `Tag`.
+    Implicit(Tag, Box<Schema>),
+    /// The inner schema explicitly tagged with `Tag`.
+    Explicit(Tag, Box<Schema>),
+}
+
+impl Schema {
+    /// Creates a new schema implicitly tagging `schema` with `tag`.
+    pub fn implicit(tag: Tag, schema: Schema) -> Self {
+        Self::Implicit(tag, Box::new(schema))
+    }
+
+    /// Creates a new schema explicitly tagging `schema` with `tag`.
+    pub fn explicit(tag: Tag, schema: Schema) -> Self {
+        Self::Explicit(tag, Box::new(schema))
+    }
+
+    /// Creates a new `SEQUENCE OF` schema.
+    pub fn sequence_of(schema: Schema) -> Self {
+        Self::SequenceOf(Box::new(schema))
+    }
+
+    /// Creates a new `SET OF` schema.
+    pub fn set_of(schema: Schema) -> Self {
+        Self::SetOf(Box::new(schema))
+    }
+
+    /// The tag identifying the schema, this is [`Tag::EOC`] for `CHOICE` and
+    /// `ANY` schemas, as they don't have a tag of their own.
+    pub fn tag(&self) -> Tag {
+        match self {
+            Self::Any | Self::Choice(_) => Tag::EOC,
+            Self::Bool => Tag::BOOL,
+            Self::Integer => Tag::INTEGER,
+            Self::Enumerated => Tag::ENUMERATED,
+            Self::BitString => Tag::BIT_STRING,
+            Self::OctetString => Tag::OCTET_STRING,
+            Self::Null => Tag::NULL,
+            Self::ObjectIdentifier => Tag::OBJECT_IDENTIFIER,
+            Self::Utf8String => Tag::UTF8_STRING,
+            Self::UtcTime => Tag::UTC_TIME,
+            Self::GeneralizedTime => Tag::GENERALIZED_TIME,
+            Self::Sequence(_) | Self::SequenceOf(_) => Tag::SEQUENCE,
+            Self::Set(_) | Self::SetOf(_) => Tag::SET,
+            Self::Implicit(tag, _) | Self::Explicit(tag, _) => *tag,
+        }
+    }
+
+    /// Returns whether a value identified by `tag` could be a valid value of
+    /// this schema.
+    pub fn matches_tag(&self, tag: Tag) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Choice(alternatives) => alternatives.iter().any(|f| f.schema.matches_tag(tag)),
+            _ => self.tag() == tag,
+        }
+    }
+}
+
+/// A named component of a `SEQUENCE`, `SET`, or `CHOICE` schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    /// The name of the field, used when reporting errors.
+    pub name: String,
+    /// The schema of the field's value.
+    pub schema: Schema,
+    /// Whether the field is `OPTIONAL`.
+    pub optional: bool,
+}
+
+impl Field {
+    /// Creates a new required field.
+    pub fn new(name: impl Into<String>, schema: Schema) -> Self {
+        Self {
+            name: name.into(),
+            schema,
+            optional: false,
+        }
+    }
+
+    /// Creates a new `OPTIONAL` field.
+    pub fn optional(name: impl Into<String>, schema: Schema) -> Self {
+        Self {
+            name: name.into(),
+            schema,
+            optional: true,
+        }
+    }
+}
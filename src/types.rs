@@ -4,11 +4,27 @@
 //! ASN.1's terminology.
 
 mod any;
+mod ber_tolerant;
+mod bit_string;
+mod bit_vec;
+mod bounded;
+mod choice;
+mod constraints;
+#[cfg(feature = "der")]
+mod containing;
+mod duration;
+mod external;
+mod flags;
 mod instance;
+mod integer;
+mod ip_prefix;
+mod iri;
 pub(crate) mod oid;
 mod open;
 mod prefix;
 mod tag;
+mod unknown_fields;
+mod value;
 
 use alloc::boxed::Box;
 
@@ -19,13 +35,35 @@ pub use ::{
 
 pub use self::{
     any::Any,
+    ber_tolerant::BerTolerant,
+    bit_string::BitStringExt,
+    bit_vec::BitVec,
+    bounded::Bounded,
+    choice::Choice2,
+    constraints::{Constraints, Extensible, SizeRange, Unconstrained, ValueRange},
+    duration::{Duration, DurationIso, DurationSeconds, InvalidDuration},
+    external::{
+        EmbeddedPdv, EmbeddedPdvIdentification, External, ExternalEncoding, ObjectDescriptor,
+        PdvContextNegotiation, PdvSyntaxes,
+    },
+    flags::{Flag, Flags},
     instance::InstanceOf,
+    integer::IntegerExt,
+    ip_prefix::{IpPrefix, Ipv4Prefix, Ipv6Prefix},
+    iri::{InvalidIri, OidIri, RelativeOidIri},
     oid::{ConstOid, ObjectIdentifier, Oid},
     open::Open,
-    prefix::{Explicit, Implicit},
+    prefix::{AppTag, Application, ContextSpecific, ContextTag, Explicit, Implicit},
     tag::{Class, Tag, TagTree},
+    unknown_fields::UnknownFields,
+    value::Value,
 };
 
+#[cfg(feature = "der")]
+pub use self::containing::Containing;
+#[cfg(feature = "oid_registry")]
+pub use self::oid::{OidRegistry, WELL_KNOWN_OIDS};
+
 ///  The `BIT STRING` type.
 pub type BitString = bitvec::vec::BitVec<u8, bitvec::order::Msb0>;
 ///  The `Ia5String` type.
@@ -46,10 +84,18 @@ pub type NumericString = Implicit<tag::NUMERIC_STRING, Utf8String>;
 pub type SetOf<T> = alloc::collections::BTreeSet<T>;
 ///  The `UniversalString` type.
 pub type UniversalString = Implicit<tag::UNIVERSAL_STRING, Utf8String>;
-///  The `UTCTime` type.
+///  The `UTCTime` type, which can only encode the years 1950 to 2049.
 pub type UtcTime = chrono::DateTime<chrono::Utc>;
 ///  The `GeneralizedTime` type.
 pub type GeneralizedTime = chrono::DateTime<chrono::FixedOffset>;
+///  The `DATE` type, encoded as `YYYYMMDD`.
+pub type Date = chrono::NaiveDate;
+///  The `TIME-OF-DAY` type, encoded as `HHMMSS`, so it can't hold fractions
+///  of a second.
+pub type TimeOfDay = chrono::NaiveTime;
+///  The `DATE-TIME` type, in local time, encoded as `YYYYMMDDHHMMSS`, so it
+///  can't hold fractions of a second.
+pub type DateTime = chrono::NaiveDateTime;
 ///  The `SEQUENCE OF` type.
 pub type SequenceOf<T> = alloc::vec::Vec<T>;
 
@@ -78,6 +124,8 @@ macro_rules! asn_type {
 
 asn_type! {
     bool: BOOL,
+    f32: REAL,
+    f64: REAL,
     i8: INTEGER,
     i16: INTEGER,
     i32: INTEGER,
@@ -90,7 +138,20 @@ asn_type! {
     u64: INTEGER,
     u128: INTEGER,
     usize: INTEGER,
+    core::num::NonZeroI8: INTEGER,
+    core::num::NonZeroI16: INTEGER,
+    core::num::NonZeroI32: INTEGER,
+    core::num::NonZeroI64: INTEGER,
+    core::num::NonZeroI128: INTEGER,
+    core::num::NonZeroIsize: INTEGER,
+    core::num::NonZeroU8: INTEGER,
+    core::num::NonZeroU16: INTEGER,
+    core::num::NonZeroU32: INTEGER,
+    core::num::NonZeroU64: INTEGER,
+    core::num::NonZeroU128: INTEGER,
+    core::num::NonZeroUsize: INTEGER,
     Integer: INTEGER,
+    num_bigint::BigUint: INTEGER,
     OctetString: OCTET_STRING,
     ObjectIdentifier: OBJECT_IDENTIFIER,
     Oid: OBJECT_IDENTIFIER,
@@ -99,25 +160,83 @@ asn_type! {
     Utf8String: UTF8_STRING,
     UtcTime: UTC_TIME,
     GeneralizedTime: GENERALIZED_TIME,
+    Date: DATE,
+    TimeOfDay: TIME_OF_DAY,
+    DateTime: DATE_TIME,
+    Duration: DURATION,
+    OidIri: OID_IRI,
+    RelativeOidIri: RELATIVE_OID_IRI,
     (): NULL,
+    core::net::Ipv4Addr: OCTET_STRING,
+    core::net::Ipv6Addr: OCTET_STRING,
     &'_ str: UTF8_STRING
 
 }
 
+#[cfg(feature = "uuid")]
+impl AsnType for uuid::Uuid {
+    const TAG: Tag = Tag::OCTET_STRING;
+}
+
+#[cfg(feature = "time")]
+impl AsnType for time::OffsetDateTime {
+    const TAG: Tag = Tag::GENERALIZED_TIME;
+}
+
 impl<T: AsnType> AsnType for &'_ T {
     const TAG: Tag = T::TAG;
     const TAG_TREE: TagTree = T::TAG_TREE;
 }
 
+impl<T: AsnType> AsnType for &'_ mut T {
+    const TAG: Tag = T::TAG;
+    const TAG_TREE: TagTree = T::TAG_TREE;
+}
+
 impl<T: AsnType> AsnType for Box<T> {
     const TAG: Tag = T::TAG;
     const TAG_TREE: TagTree = T::TAG_TREE;
 }
 
+impl<T: AsnType> AsnType for alloc::rc::Rc<T> {
+    const TAG: Tag = T::TAG;
+    const TAG_TREE: TagTree = T::TAG_TREE;
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: AsnType> AsnType for alloc::sync::Arc<T> {
+    const TAG: Tag = T::TAG;
+    const TAG_TREE: TagTree = T::TAG_TREE;
+}
+
 impl<T: AsnType> AsnType for alloc::vec::Vec<T> {
     const TAG: Tag = Tag::SEQUENCE;
 }
 
+impl<T: AsnType> AsnType for alloc::collections::VecDeque<T> {
+    const TAG: Tag = Tag::SEQUENCE;
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const N: usize> AsnType for arrayvec::ArrayString<N> {
+    const TAG: Tag = Tag::UTF8_STRING;
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> AsnType for smallvec::SmallVec<A> {
+    const TAG: Tag = Tag::SEQUENCE;
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> AsnType for heapless::Vec<T, N> {
+    const TAG: Tag = Tag::SEQUENCE;
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> AsnType for heapless::String<N> {
+    const TAG: Tag = Tag::UTF8_STRING;
+}
+
 impl<T: AsnType> AsnType for Option<T> {
     const TAG: Tag = T::TAG;
     const TAG_TREE: TagTree = T::TAG_TREE;
@@ -127,6 +246,11 @@ impl<T> AsnType for alloc::collections::BTreeSet<T> {
     const TAG: Tag = Tag::SET;
 }
 
+#[cfg(feature = "std")]
+impl<T, S> AsnType for std::collections::HashSet<T, S> {
+    const TAG: Tag = Tag::SET;
+}
+
 impl<T: AsnType, const N: usize> AsnType for [T; N] {
     const TAG: Tag = Tag::SEQUENCE;
 }
@@ -139,3 +263,13 @@ impl AsnType for Any {
     const TAG: Tag = Tag::EOC;
     const TAG_TREE: TagTree = TagTree::Choice(&[]);
 }
+
+impl AsnType for Value {
+    const TAG: Tag = Tag::EOC;
+    const TAG_TREE: TagTree = TagTree::Choice(&[]);
+}
+
+impl AsnType for UnknownFields {
+    const TAG: Tag = Tag::EOC;
+    const TAG_TREE: TagTree = TagTree::Choice(&[]);
+}
@@ -22,6 +22,56 @@ impl Any {
     pub fn into_bytes(self) -> Vec<u8> {
         self.contents
     }
+
+    /// Returns the tag of the value, or an error if it doesn't start with a
+    /// valid identifier.
+    #[cfg(feature = "ber")]
+    pub fn tag(&self) -> Result<crate::Tag, crate::ber::de::Error> {
+        crate::ber::de::Decoder::new(&self.contents, crate::ber::de::DecoderOptions::ber())
+            .peek_tag()
+    }
+
+    /// Whether the value has one of `T`'s tags (any of its variants' for a
+    /// `CHOICE`), and so could be decoded as `T`, without decoding it.
+    #[cfg(feature = "ber")]
+    pub fn is<T: crate::AsnType>(&self) -> bool {
+        matches!(self.tag(), Ok(tag) if T::TAG_TREE.accepts(tag))
+    }
+
+    /// Decodes the value as `T` with BER, once its type is known, such as
+    /// from the [`ObjectIdentifier`][crate::types::ObjectIdentifier] of an
+    /// `ANY DEFINED BY` field. As the value is stored with its tag and
+    /// length, `T`'s tag is checked as normal.
+    #[cfg(feature = "ber")]
+    pub fn decode_inner<T: crate::Decode>(&self) -> Result<T, crate::ber::de::Error> {
+        crate::ber::decode(&self.contents)
+    }
+
+    /// Decodes the value as a dynamic [`Value`][crate::types::Value] tree,
+    /// for walking a value whose type is only partly known. Re-encoding the
+    /// tree with DER reproduces the original bytes for DER encoded values.
+    /// ```
+    /// use rasn::types::{Any, Value};
+    ///
+    /// let any = Any::new(vec![0x30, 0x03, 0x02, 0x01, 0x05]);
+    /// let value = any.decode_as_value().unwrap();
+    ///
+    /// assert_eq!(Value::Sequence(vec![Value::Integer(5.into())]), value);
+    /// assert_eq!(any.as_bytes(), &*rasn::der::encode(&value).unwrap());
+    /// ```
+    #[cfg(feature = "ber")]
+    pub fn decode_as_value(&self) -> Result<crate::types::Value, crate::ber::de::Error> {
+        self.decode_inner()
+    }
+
+    /// Encodes `value` with DER and captures the complete encoding, tag and
+    /// length included, such as for an `ANY DEFINED BY` field whose type is
+    /// only known at runtime. Encoding the `Any` writes the captured bytes
+    /// unchanged.
+    #[cfg(feature = "der")]
+    pub fn from_encodable<T: crate::Encode>(value: &T) -> Result<Self, crate::ber::enc::Error> {
+        crate::der::encode(value).map(Self::new)
+    }
 }
 
 impl AsRef<[u8]> for Any {
@@ -35,3 +85,114 @@ impl From<Vec<u8>> for Any {
         Any::new(value)
     }
 }
+
+#[cfg(all(test, feature = "der"))]
+mod tests {
+    use super::*;
+    use crate::{types::ObjectIdentifier, AsnType, Decode, Encode};
+
+    #[derive(AsnType, Decode, Encode)]
+    #[rasn(crate_root = "crate")]
+    struct AlgorithmIdentifier {
+        algorithm: ObjectIdentifier,
+        parameters: Option<Any>,
+    }
+
+    #[test]
+    fn decode_inner() {
+        // rsaEncryption with NULL parameters.
+        let rsa = &[
+            0x30, 0x0D, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01, 0x05,
+            0x00,
+        ];
+        // id-ecPublicKey with the prime256v1 curve as parameters.
+        let ec = &[
+            0x30, 0x13, 0x06, 0x07, 0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x02, 0x01, 0x06, 0x08, 0x2A,
+            0x86, 0x48, 0xCE, 0x3D, 0x03, 0x01, 0x07,
+        ];
+
+        let rsa: AlgorithmIdentifier = crate::der::decode(rsa).unwrap();
+        let parameters = rsa.parameters.unwrap();
+        assert_eq!(crate::Tag::NULL, parameters.tag().unwrap());
+        assert!(parameters.is::<()>());
+        assert!(!parameters.is::<ObjectIdentifier>());
+        assert_eq!((), parameters.decode_inner::<()>().unwrap());
+        assert!(parameters.decode_inner::<ObjectIdentifier>().is_err());
+
+        let ec: AlgorithmIdentifier = crate::der::decode(ec).unwrap();
+        let parameters = ec.parameters.unwrap();
+        assert_eq!(
+            &[1, 2, 840, 10045, 3, 1, 7][..],
+            &**parameters.decode_inner::<ObjectIdentifier>().unwrap()
+        );
+        assert!(parameters.decode_inner::<()>().is_err());
+    }
+
+    #[test]
+    fn is_choice() {
+        #[derive(AsnType, Decode, Encode, Debug, PartialEq)]
+        #[rasn(choice, crate_root = "crate")]
+        enum Time {
+            Utc(crate::types::UtcTime),
+            General(crate::types::GeneralizedTime),
+        }
+
+        let utc = Any::new(alloc::vec![
+            0x17, 0x0D, b'2', b'3', b'1', b'0', b'1', b'4', b'2', b'0', b'3', b'4', b'5', b'6',
+            b'Z',
+        ]);
+        assert!(utc.is::<Time>());
+        assert!(matches!(utc.decode_inner::<Time>(), Ok(Time::Utc(_))));
+        assert!(utc.is::<Any>());
+        assert!(!Any::new(alloc::vec![0x05, 0x00]).is::<Time>());
+    }
+
+    #[test]
+    fn decode_as_value() {
+        use crate::types::{Class, Tag, Value};
+
+        let data = alloc::vec![
+            0x30, 0x0E, 0x06, 0x03, 0x2A, 0x03, 0x04, 0xA1, 0x07, 0x31, 0x05, 0x0C, 0x03, b'a',
+            b'b', b'c',
+        ];
+        let parameters = Any::new(data.clone());
+        let value = parameters.decode_as_value().unwrap();
+
+        assert_eq!(
+            Value::Sequence(alloc::vec![
+                Value::ObjectIdentifier(ObjectIdentifier::new(alloc::vec![1, 2, 3, 4]).unwrap()),
+                Value::Tagged(
+                    Tag::new(Class::Context, 1),
+                    alloc::boxed::Box::new(Value::Set(alloc::vec![Value::Utf8String(
+                        "abc".into()
+                    )]))
+                ),
+            ]),
+            value
+        );
+        assert_eq!(data, crate::der::encode(&value).unwrap());
+        assert!(Any::new(alloc::vec![0x30, 0x02, 0x02])
+            .decode_as_value()
+            .is_err());
+    }
+
+    #[test]
+    fn from_encodable() {
+        let curve = ObjectIdentifier::new(alloc::vec![1, 2, 840, 10045, 3, 1, 7]).unwrap();
+        let parameters = Any::from_encodable(&curve).unwrap();
+        assert_eq!(crate::der::encode(&curve).unwrap(), parameters.as_bytes());
+
+        let ec = AlgorithmIdentifier {
+            algorithm: ObjectIdentifier::new(alloc::vec![1, 2, 840, 10045, 2, 1]).unwrap(),
+            parameters: Some(parameters),
+        };
+        let encoded = crate::der::encode(&ec).unwrap();
+        assert_eq!(
+            &[
+                0x30, 0x13, 0x06, 0x07, 0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x02, 0x01, 0x06, 0x08, 0x2A,
+                0x86, 0x48, 0xCE, 0x3D, 0x03, 0x01, 0x07,
+            ][..],
+            &*encoded
+        );
+    }
+}
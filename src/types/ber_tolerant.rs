@@ -0,0 +1,137 @@
+use super::{AsnType, Tag, TagTree};
+use crate::{Decode, Decoder, Encode, Encoder};
+
+/// A value that's decoded with BER's rules even inside of a DER (or CER)
+/// value, for the odd field of a real-world structure that isn't valid DER,
+/// such as one with an indefinite length or a non-minimal `INTEGER`, without
+/// relaxing the rules for the rest of the structure.
+///
+/// Encoding is unchanged, so re-encoding the value with DER produces valid
+/// DER.
+/// ```
+/// use rasn::{types::BerTolerant, AsnType, Decode, Encode};
+///
+/// #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+/// struct Reading {
+///     id: u8,
+///     value: BerTolerant<u32>,
+/// }
+///
+/// // `value` has a redundant leading zero byte.
+/// let encoded = [0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x02, 0x00, 0x05];
+/// let reading: Reading = rasn::der::decode(&encoded).unwrap();
+/// assert_eq!(5, *reading.value);
+///
+/// let reencoded = rasn::der::encode(&reading).unwrap();
+/// assert_eq!(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x05][..], &*reencoded);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BerTolerant<T> {
+    /// The inner value.
+    pub value: T,
+}
+
+impl<T> BerTolerant<T> {
+    /// Create a wrapper from `value`.
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+}
+
+impl<T> From<T> for BerTolerant<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> core::ops::Deref for BerTolerant<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> core::ops::DerefMut for BerTolerant<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T: AsnType> AsnType for BerTolerant<T> {
+    const TAG: Tag = T::TAG;
+    const TAG_TREE: TagTree = T::TAG_TREE;
+}
+
+impl<T: Decode> Decode for BerTolerant<T> {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error> {
+        decoder.decode_leniently(T::decode).map(Self::new)
+    }
+
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder
+            .decode_leniently(|decoder| T::decode_with_tag(decoder, tag))
+            .map(Self::new)
+    }
+}
+
+impl<T: Encode> Encode for BerTolerant<T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
+        self.value.encode(encoder)
+    }
+
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        self.value.encode_with_tag(encoder, tag)
+    }
+}
+
+#[cfg(all(test, feature = "der"))]
+mod tests {
+    use super::*;
+    use crate::{
+        der::{decode, encode},
+        types::{Integer, OctetString},
+    };
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(crate_root = "crate")]
+    struct Record {
+        serial: Integer,
+        #[rasn(tag(0))]
+        payload: BerTolerant<OctetString>,
+        flag: bool,
+    }
+
+    #[test]
+    fn only_the_field_is_lenient() {
+        let record = Record {
+            serial: 1.into(),
+            payload: OctetString::from_static(&[1, 2, 3]).into(),
+            flag: true,
+        };
+        let der = &[
+            0x30, 0x0B, 0x02, 0x01, 0x01, 0x80, 0x03, 1, 2, 3, 0x01, 0x01, 0xFF,
+        ];
+        assert_eq!(der, &*encode(&record).unwrap());
+
+        // An indefinite length, constructed `payload`.
+        let ber_payload = &[
+            0x30, 0x11, 0x02, 0x01, 0x01, 0xA0, 0x80, 0x04, 0x02, 1, 2, 0x04, 0x01, 3, 0x00, 0x00,
+            0x01, 0x01, 0xFF,
+        ];
+        let decoded: Record = decode(ber_payload).unwrap();
+        assert_eq!(record, decoded);
+        assert_eq!(der, &*encode(&decoded).unwrap());
+
+        // The other fields have to be DER.
+        let ber_serial = &[
+            0x30, 0x0C, 0x02, 0x02, 0x00, 0x01, 0x80, 0x03, 1, 2, 3, 0x01, 0x01, 0xFF,
+        ];
+        let ber_flag = &[
+            0x30, 0x0B, 0x02, 0x01, 0x01, 0x80, 0x03, 1, 2, 3, 0x01, 0x01, 0x01,
+        ];
+        assert!(decode::<Record>(ber_serial).is_err());
+        assert!(decode::<Record>(ber_flag).is_err());
+        assert!(crate::ber::decode::<Record>(ber_flag).is_ok());
+    }
+}
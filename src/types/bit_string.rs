@@ -0,0 +1,86 @@
+use super::BitString;
+
+/// Conversions between a [`BitString`] and an integer bitmask.
+///
+/// ASN.1 numbers the bits of a `BIT STRING` from the start of the string, so
+/// bit `0` is the **most** significant bit of the first byte. These
+/// conversions keep that ordering, bit `0` of the `BIT STRING` is always the
+/// most significant bit of the `u32` (i.e. `0x8000_0000`), bit `1` is
+/// `0x4000_0000`, and so on, regardless of the length of the string.
+/// ```
+/// use rasn::types::{BitString, BitStringExt};
+///
+/// // KeyUsage ::= BIT STRING { digitalSignature (0), keyCertSign (5), ... }
+/// let usage = BitString::from_u32_be(0x8400_0000, 6);
+/// assert!(usage[0] && usage[5]);
+/// assert_eq!(Some(0x8400_0000), usage.to_u32_be());
+/// ```
+pub trait BitStringExt: Sized {
+    /// Returns the bits as a `u32` with bit `0` as the most significant bit,
+    /// and any bits past the end of the string set to zero. Returns `None`
+    /// if the string is longer than 32 bits.
+    fn to_u32_be(&self) -> Option<u32>;
+
+    /// Creates a string of `len` bits from the most significant bits of
+    /// `bits`, so bit `0` of the string is the most significant bit of
+    /// `bits`. Any bits in `bits` past `len` are ignored.
+    ///
+    /// # Panics
+    /// If `len` is greater than 32.
+    fn from_u32_be(bits: u32, len: usize) -> Self;
+}
+
+impl BitStringExt for BitString {
+    fn to_u32_be(&self) -> Option<u32> {
+        if self.len() > 32 {
+            return None;
+        }
+
+        Some(
+            self.iter()
+                .enumerate()
+                .filter(|(_, bit)| **bit)
+                .fold(0, |mask, (index, _)| mask | (0x8000_0000 >> index)),
+        )
+    }
+
+    fn from_u32_be(bits: u32, len: usize) -> Self {
+        assert!(len <= 32, "`len` must be at most 32 bits, found {}", len);
+
+        let mut string = BitString::from_slice(&bits.to_be_bytes());
+        string.truncate(len);
+        string
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let string = BitString::from_u32_be(0x8480_0000, 9);
+
+        assert_eq!(9, string.len());
+        assert_eq!(
+            alloc::vec![0, 5, 8],
+            string.iter_ones().collect::<alloc::vec::Vec<_>>()
+        );
+        assert_eq!(Some(0x8480_0000), string.to_u32_be());
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(Some(0), BitString::new().to_u32_be());
+        assert!(BitString::from_u32_be(u32::MAX, 0).is_empty());
+        assert_eq!(
+            Some(0xF000_0000),
+            BitString::from_u32_be(u32::MAX, 4).to_u32_be()
+        );
+        assert_eq!(
+            Some(u32::MAX),
+            BitString::from_u32_be(u32::MAX, 32).to_u32_be()
+        );
+        assert_eq!(None, BitString::repeat(false, 33).to_u32_be());
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+
+use super::{AsnType, Tag};
+use crate::{Decode, Decoder, Encode, Encoder};
+
+/// A `BIT STRING` as a plain `Vec<bool>`, where index `0` is the first bit,
+/// for when [`BitString`][super::BitString]'s `bitvec` API is more than is
+/// needed.
+///
+/// A bare `Vec<bool>` is still a `SEQUENCE OF BOOLEAN`, so this wrapper is
+/// needed to get a `BIT STRING`.
+/// ```
+/// use rasn::types::BitVec;
+///
+/// let bits = BitVec::from(vec![true, false, true, false]);
+/// let encoded = rasn::der::encode(&bits).unwrap();
+/// assert_eq!(&[0x03, 0x02, 0x04, 0xA0][..], &*encoded);
+/// assert_eq!(bits, rasn::der::decode(&encoded).unwrap());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitVec(pub Vec<bool>);
+
+impl From<Vec<bool>> for BitVec {
+    fn from(bits: Vec<bool>) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<BitVec> for Vec<bool> {
+    fn from(bits: BitVec) -> Self {
+        bits.0
+    }
+}
+
+impl core::iter::FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl core::ops::Deref for BitVec {
+    type Target = Vec<bool>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for BitVec {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsnType for BitVec {
+    const TAG: Tag = Tag::BIT_STRING;
+}
+
+impl Decode for BitVec {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        Ok(decoder.decode_bit_string(tag)?.iter().by_vals().collect())
+    }
+}
+
+impl Encode for BitVec {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        let bits: super::BitString = self.0.iter().copied().collect();
+        encoder.encode_bit_string(tag, &bits).map(drop)
+    }
+}
+
+#[cfg(all(test, feature = "der"))]
+mod tests {
+    use super::*;
+    use crate::der::{decode, encode};
+    use alloc::vec;
+
+    #[test]
+    fn exact_length() {
+        let trailing_zeros = BitVec::from(vec![
+            true, false, false, false, false, false, false, false, false,
+        ]);
+        let encoded = encode(&trailing_zeros).unwrap();
+        assert_eq!(&[0x03, 0x03, 0x07, 0x80, 0x00][..], &*encoded);
+        assert_eq!(trailing_zeros, decode(&encoded).unwrap());
+
+        let empty = BitVec::default();
+        assert_eq!(&[0x03, 0x01, 0x00][..], &*encode(&empty).unwrap());
+        assert_eq!(empty, decode(&[0x03, 0x01, 0x00]).unwrap());
+        assert!(decode::<BitVec>(&[0x03, 0x01, 0x01]).is_err());
+        assert!(decode::<BitVec>(&[0x03, 0x02, 0x08, 0x00]).is_err());
+
+        let constructed = [0x23, 0x08, 0x03, 0x02, 0x00, 0x80, 0x03, 0x02, 0x07, 0x00];
+        assert_eq!(trailing_zeros, crate::ber::decode(&constructed).unwrap());
+
+        // A bare `Vec<bool>` stays a `SEQUENCE OF BOOLEAN`.
+        assert_eq!(
+            &[0x30, 0x03, 0x01, 0x01, 0xFF][..],
+            &*encode(&vec![true]).unwrap()
+        );
+    }
+}
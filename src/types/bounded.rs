@@ -0,0 +1,113 @@
+use core::ops;
+
+use super::{AsnType, Constraints, Tag};
+use crate::{Decode, Decoder, Encode, Encoder};
+
+/// An `INTEGER` constrained to the values from `MIN` to `MAX` inclusive,
+/// e.g. `INTEGER (0..255)`, which is checked when creating and decoding the
+/// value. Codecs that need the bounds can get them from its [`Constraints`].
+/// ```
+/// use rasn::types::{Bounded, Constraints};
+///
+/// type Percentage = Bounded<0, 100>;
+///
+/// let value = Percentage::new(42).unwrap();
+/// assert_eq!(42, *value);
+/// assert!(Percentage::new(101).is_none());
+/// assert_eq!(Some((0, 100)), Percentage::value_range());
+///
+/// assert_eq!(value, rasn::der::decode(&[0x02, 0x01, 42]).unwrap());
+/// assert!(rasn::der::decode::<Percentage>(&[0x02, 0x01, 101]).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bounded<const MIN: i128, const MAX: i128>(i128);
+
+impl<const MIN: i128, const MAX: i128> Bounded<MIN, MAX> {
+    /// The smallest permitted value.
+    pub const MIN: Self = Self(MIN);
+    /// The largest permitted value.
+    pub const MAX: Self = Self(MAX);
+
+    /// Creates a new bounded integer from `value`, returning `None` if it's
+    /// outside of `MIN..=MAX`.
+    pub const fn new(value: i128) -> Option<Self> {
+        if MIN <= value && value <= MAX {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as an `i128`.
+    pub const fn get(self) -> i128 {
+        self.0
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> ops::Deref for Bounded<MIN, MAX> {
+    type Target = i128;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> From<Bounded<MIN, MAX>> for i128 {
+    fn from(value: Bounded<MIN, MAX>) -> Self {
+        value.0
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> Constraints for Bounded<MIN, MAX> {
+    const VALUE_RANGE: Option<(i128, i128)> = Some((MIN, MAX));
+}
+
+impl<const MIN: i128, const MAX: i128> AsnType for Bounded<MIN, MAX> {
+    const TAG: Tag = Tag::INTEGER;
+}
+
+impl<const MIN: i128, const MAX: i128> Decode for Bounded<MIN, MAX> {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        let value = i128::decode_with_tag(decoder, tag)?;
+
+        Self::new(value).ok_or_else(|| {
+            crate::de::Error::custom(alloc::format!(
+                "Integer `{}` is outside of the range `({}..{})`",
+                value,
+                MIN,
+                MAX
+            ))
+        })
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> Encode for Bounded<MIN, MAX> {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        self.0.encode_with_tag(encoder, tag)
+    }
+}
+
+#[cfg(all(test, feature = "der"))]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn bounds() {
+        type Negative = Bounded<{ i128::MIN }, -1>;
+
+        assert_eq!(Some(-1), Negative::new(-1).map(Bounded::get));
+        assert_eq!(None, Negative::new(0));
+        assert_eq!(i128::MIN, *Negative::MIN);
+        assert_eq!(
+            Negative::MAX,
+            crate::der::decode(&[0x02, 0x01, 0xFF]).unwrap()
+        );
+        assert_eq!(
+            "Integer `0` is outside of the range `(-170141183460469231731687303715884105728..-1)`",
+            crate::der::decode::<Negative>(&[0x02, 0x01, 0x00])
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
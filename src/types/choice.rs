@@ -0,0 +1,300 @@
+use super::{AsnType, Class, Tag, TagTree};
+use crate::{Decode, Decoder, Encode, Encoder};
+
+/// A `CHOICE` of two alternatives, implicitly tagged with the context tags
+/// `FIRST` and `SECOND`, which default to `[0]` and `[1]`.
+///
+/// This is useful for the common pattern of modelling a response as either a
+/// result or an error, without defining a new `CHOICE` for every response.
+/// ```
+/// use rasn::types::{Choice2, Utf8String};
+///
+/// // Response ::= CHOICE { result [0] INTEGER, error [1] UTF8String }
+/// type Response = Choice2<u32, Utf8String>;
+///
+/// let encoded = rasn::der::encode(&Response::First(5)).unwrap();
+/// assert_eq!(&[0x80, 0x01, 0x05][..], &*encoded);
+/// assert_eq!(Response::First(5), rasn::der::decode(&encoded).unwrap());
+///
+/// // Error ::= CHOICE { code [2] INTEGER, message [3] UTF8String }
+/// type Error = Choice2<u32, Utf8String, 2, 3>;
+///
+/// let encoded = rasn::der::encode(&Error::Second("Denied".into())).unwrap();
+/// assert_eq!(0x83, encoded[0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Choice2<A, B, const FIRST: u32 = 0, const SECOND: u32 = 1> {
+    /// The alternative tagged with `FIRST`.
+    First(A),
+    /// The alternative tagged with `SECOND`.
+    Second(B),
+}
+
+impl<A, B, const FIRST: u32, const SECOND: u32> Choice2<A, B, FIRST, SECOND> {
+    const FIRST_TAG: Tag = Tag::new(Class::Context, FIRST);
+    const SECOND_TAG: Tag = Tag::new(Class::Context, SECOND);
+}
+
+impl<A, B, const FIRST: u32, const SECOND: u32> AsnType for Choice2<A, B, FIRST, SECOND> {
+    const TAG: Tag = Tag::CHOICE;
+    const TAG_TREE: TagTree = {
+        const fn assert_unique(tree: TagTree) -> TagTree {
+            assert!(
+                tree.is_unique(),
+                "Choice2's alternatives must have unique tags."
+            );
+            tree
+        }
+
+        assert_unique(TagTree::Choice(&[
+            TagTree::Leaf(Self::FIRST_TAG),
+            TagTree::Leaf(Self::SECOND_TAG),
+        ]))
+    };
+}
+
+impl<A: Decode, B: Decode, const FIRST: u32, const SECOND: u32> Decode
+    for Choice2<A, B, FIRST, SECOND>
+{
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error> {
+        if let Ok(value) = A::decode_with_tag(decoder, Self::FIRST_TAG) {
+            return Ok(Self::First(value));
+        }
+
+        if let Ok(value) = B::decode_with_tag(decoder, Self::SECOND_TAG) {
+            return Ok(Self::Second(value));
+        }
+
+        Err(crate::de::Error::no_valid_choice("Choice2"))
+    }
+
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_explicit_prefix(tag)
+    }
+}
+
+impl<A: Encode, B: Encode, const FIRST: u32, const SECOND: u32> Encode
+    for Choice2<A, B, FIRST, SECOND>
+{
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
+        match self {
+            Self::First(value) => value.encode_with_tag(encoder, Self::FIRST_TAG),
+            Self::Second(value) => value.encode_with_tag(encoder, Self::SECOND_TAG),
+        }
+    }
+
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_explicit_prefix(tag, self).map(drop)
+    }
+}
+
+/// `Either` is a `CHOICE` of its two sides, with the same tagging as
+/// [`Choice2`]'s defaults: `Left` is implicitly tagged `[0]` and `Right` is
+/// implicitly tagged `[1]`, whatever the tags of `L` and `R` are, so the two
+/// sides can always be told apart. A side that's a `CHOICE` itself is
+/// explicitly tagged instead, as with any tagged `CHOICE`. For other tags,
+/// convert to and from a `Choice2` with the tags you need.
+/// ```
+/// use either::Either;
+///
+/// let encoded = rasn::der::encode(&Either::<u8, bool>::Right(true)).unwrap();
+/// assert_eq!(&[0x81, 0x01, 0xFF][..], &*encoded);
+/// assert_eq!(Either::<u8, bool>::Right(true), rasn::der::decode(&encoded).unwrap());
+/// ```
+#[cfg(feature = "either")]
+impl<L, R> AsnType for either::Either<L, R> {
+    const TAG: Tag = Tag::CHOICE;
+    const TAG_TREE: TagTree = <Choice2<L, R>>::TAG_TREE;
+}
+
+#[cfg(feature = "either")]
+impl<L: Decode, R: Decode> Decode for either::Either<L, R> {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error> {
+        <Choice2<L, R>>::decode(decoder).map(Self::from)
+    }
+
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_explicit_prefix(tag)
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L: Encode, R: Encode> Encode for either::Either<L, R> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
+        match self {
+            Self::Left(value) => value.encode_with_tag(encoder, <Choice2<L, R>>::FIRST_TAG),
+            Self::Right(value) => value.encode_with_tag(encoder, <Choice2<L, R>>::SECOND_TAG),
+        }
+    }
+
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder.encode_explicit_prefix(tag, self).map(drop)
+    }
+}
+
+#[cfg(feature = "either")]
+impl<A, B, const FIRST: u32, const SECOND: u32> From<either::Either<A, B>>
+    for Choice2<A, B, FIRST, SECOND>
+{
+    fn from(value: either::Either<A, B>) -> Self {
+        match value {
+            either::Either::Left(value) => Self::First(value),
+            either::Either::Right(value) => Self::Second(value),
+        }
+    }
+}
+
+#[cfg(feature = "either")]
+impl<A, B, const FIRST: u32, const SECOND: u32> From<Choice2<A, B, FIRST, SECOND>>
+    for either::Either<A, B>
+{
+    fn from(value: Choice2<A, B, FIRST, SECOND>) -> Self {
+        match value {
+            Choice2::First(value) => Self::Left(value),
+            Choice2::Second(value) => Self::Right(value),
+        }
+    }
+}
+
+/// With the `result_choice` feature, a `Result` is a `CHOICE` of its
+/// outcomes, with the same tagging as [`Choice2`]'s defaults: `Ok` is
+/// implicitly tagged `[0]` and `Err` is implicitly tagged `[1]`, such as for
+/// modelling the outcome of a remote call. An outcome that's a `CHOICE`
+/// itself is explicitly tagged instead, as with any tagged `CHOICE`.
+/// ```
+/// // Outcome ::= CHOICE { ok [0] INTEGER, err [1] UTF8String }
+/// type Outcome = Result<u8, rasn::types::Utf8String>;
+///
+/// let encoded = rasn::der::encode(&Outcome::Err("Denied".into())).unwrap();
+/// assert_eq!(&[0x81, 0x06][..], &encoded[..2]);
+/// assert_eq!(Outcome::Err("Denied".into()), rasn::der::decode(&encoded).unwrap());
+/// ```
+#[cfg(feature = "result_choice")]
+impl<T, E> AsnType for Result<T, E> {
+    const TAG: Tag = Tag::CHOICE;
+    const TAG_TREE: TagTree = <Choice2<T, E>>::TAG_TREE;
+}
+
+#[cfg(feature = "result_choice")]
+impl<T: Decode, E: Decode> Decode for Result<T, E> {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error> {
+        <Choice2<T, E>>::decode(decoder).map(Self::from)
+    }
+
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_explicit_prefix(tag)
+    }
+}
+
+#[cfg(feature = "result_choice")]
+impl<T: Encode, E: Encode> Encode for Result<T, E> {
+    fn encode<EN: Encoder>(&self, encoder: &mut EN) -> Result<(), EN::Error> {
+        match self {
+            Ok(value) => value.encode_with_tag(encoder, <Choice2<T, E>>::FIRST_TAG),
+            Err(value) => value.encode_with_tag(encoder, <Choice2<T, E>>::SECOND_TAG),
+        }
+    }
+
+    fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
+        encoder.encode_explicit_prefix(tag, self).map(drop)
+    }
+}
+
+impl<T, E, const FIRST: u32, const SECOND: u32> From<Result<T, E>>
+    for Choice2<T, E, FIRST, SECOND>
+{
+    fn from(value: Result<T, E>) -> Self {
+        match value {
+            Ok(value) => Self::First(value),
+            Err(value) => Self::Second(value),
+        }
+    }
+}
+
+impl<T, E, const FIRST: u32, const SECOND: u32> From<Choice2<T, E, FIRST, SECOND>>
+    for Result<T, E>
+{
+    fn from(value: Choice2<T, E, FIRST, SECOND>) -> Self {
+        match value {
+            Choice2::First(value) => Ok(value),
+            Choice2::Second(value) => Err(value),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "der"))]
+mod tests {
+    use super::*;
+    use crate::der::{decode, encode};
+
+    #[test]
+    fn alternatives() {
+        type Response = Choice2<bool, u8, 4, 5>;
+
+        let first = Response::First(true);
+        let second = Response::Second(7);
+
+        assert_eq!(&[0x84, 0x01, 0xFF][..], &*encode(&first).unwrap());
+        assert_eq!(&[0x85, 0x01, 0x07][..], &*encode(&second).unwrap());
+        assert_eq!(first, decode(&encode(&first).unwrap()).unwrap());
+        assert_eq!(second, decode(&encode(&second).unwrap()).unwrap());
+        assert!(decode::<Response>(&[0x80, 0x01, 0x07]).is_err());
+    }
+
+    #[test]
+    fn explicitly_tagged() {
+        type Tagged = crate::types::Explicit<crate::types::ContextSpecific<2>, Choice2<bool, u8>>;
+
+        let value = Tagged::new(Choice2::Second(7));
+        let encoded = encode(&value).unwrap();
+
+        assert_eq!(&[0xA2, 0x03, 0x81, 0x01, 0x07][..], &*encoded);
+        assert_eq!(value, decode(&encoded).unwrap());
+    }
+
+    #[cfg(feature = "either")]
+    #[test]
+    fn either() {
+        use either::Either;
+
+        // Both sides are `INTEGER`s, but are told apart by their tags.
+        type Id = Either<u8, u32>;
+
+        let left = Id::Left(1);
+        let right = Id::Right(1);
+
+        assert_eq!(&[0x80, 0x01, 0x01][..], &*encode(&left).unwrap());
+        assert_eq!(&[0x81, 0x01, 0x01][..], &*encode(&right).unwrap());
+        assert_eq!(left, decode(&encode(&left).unwrap()).unwrap());
+        assert_eq!(right, decode(&encode(&right).unwrap()).unwrap());
+        assert!(decode::<Id>(&[0x02, 0x01, 0x01]).is_err());
+
+        let nested = Either::<Id, bool>::Left(right);
+        let encoded = encode(&nested).unwrap();
+        assert_eq!(&[0xA0, 0x03, 0x81, 0x01, 0x01][..], &*encoded);
+        assert_eq!(nested, decode(&encoded).unwrap());
+
+        assert_eq!(Choice2::<u8, u32, 4, 5>::Second(1), Choice2::from(right));
+    }
+
+    #[cfg(feature = "result_choice")]
+    #[test]
+    fn result() {
+        // Both outcomes are `BOOLEAN`s, but are told apart by their tags.
+        type Outcome = Result<bool, bool>;
+
+        let ok = Outcome::Ok(true);
+        let err = Outcome::Err(false);
+
+        assert_eq!(&[0x80, 0x01, 0xFF][..], &*encode(&ok).unwrap());
+        assert_eq!(&[0x81, 0x01, 0x00][..], &*encode(&err).unwrap());
+        assert_eq!(ok, decode(&encode(&ok).unwrap()).unwrap());
+        assert_eq!(err, decode(&encode(&err).unwrap()).unwrap());
+        assert!(decode::<Outcome>(&[0x01, 0x01, 0xFF]).is_err());
+
+        let nested = Result::<Outcome, u8>::Ok(err);
+        let encoded = encode(&nested).unwrap();
+        assert_eq!(&[0xA0, 0x03, 0x81, 0x01, 0x00][..], &*encoded);
+        assert_eq!(nested, decode(&encoded).unwrap());
+    }
+}
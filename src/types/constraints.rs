@@ -0,0 +1,132 @@
+use core::marker::PhantomData;
+
+/// A type describing the subtype constraints of an ASN.1 type, such as the
+/// range of permitted values of an `INTEGER`, or the size of an
+/// `OCTET STRING`. Each bound is available both as an associated constant
+/// and as a function, and a `None` bound means the type is unconstrained in
+/// that respect.
+///
+/// Codecs that need the bounds of a type (such as PER or OER) can query them
+/// from the constraints type.
+/// ```
+/// use rasn::types::{Constraints, Extensible, SizeRange, ValueRange};
+///
+/// assert_eq!(Some((0, 7)), ValueRange::<0, 7>::value_range());
+/// assert_eq!(Some((1, Some(64))), SizeRange::<1, 64>::size_range());
+/// assert!(Extensible::<ValueRange<0, 7>>::is_extensible());
+/// ```
+pub trait Constraints {
+    /// The inclusive range of permitted values.
+    const VALUE_RANGE: Option<(i128, i128)> = None;
+    /// The minimum and (if bounded) maximum permitted size.
+    const SIZE_RANGE: Option<(usize, Option<usize>)> = None;
+    /// The set of characters permitted in a string.
+    const PERMITTED_ALPHABET: Option<&'static [char]> = None;
+    /// Whether the constraints have an extension marker (`...`), allowing
+    /// values outside of the constraints.
+    const EXTENSIBLE: bool = false;
+
+    /// Returns [`Constraints::VALUE_RANGE`].
+    fn value_range() -> Option<(i128, i128)>
+    where
+        Self: Sized,
+    {
+        Self::VALUE_RANGE
+    }
+
+    /// Returns [`Constraints::SIZE_RANGE`].
+    fn size_range() -> Option<(usize, Option<usize>)>
+    where
+        Self: Sized,
+    {
+        Self::SIZE_RANGE
+    }
+
+    /// Returns [`Constraints::PERMITTED_ALPHABET`].
+    fn permitted_alphabet() -> Option<&'static [char]>
+    where
+        Self: Sized,
+    {
+        Self::PERMITTED_ALPHABET
+    }
+
+    /// Returns [`Constraints::EXTENSIBLE`].
+    fn is_extensible() -> bool
+    where
+        Self: Sized,
+    {
+        Self::EXTENSIBLE
+    }
+}
+
+/// The absence of any constraints.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Unconstrained;
+
+impl Constraints for Unconstrained {}
+
+/// A value range constraint, permitting values from `START` to `END`
+/// inclusive, e.g. `INTEGER (0..7)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValueRange<const START: i128, const END: i128>;
+
+impl<const START: i128, const END: i128> Constraints for ValueRange<START, END> {
+    const VALUE_RANGE: Option<(i128, i128)> = Some((START, END));
+}
+
+/// A size constraint, permitting sizes from `MIN` to `MAX` inclusive, e.g.
+/// `OCTET STRING (SIZE(1..64))`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SizeRange<const MIN: usize, const MAX: usize>;
+
+impl<const MIN: usize, const MAX: usize> Constraints for SizeRange<MIN, MAX> {
+    const SIZE_RANGE: Option<(usize, Option<usize>)> = Some((MIN, Some(MAX)));
+}
+
+/// Marks the constraints of `C` as extensible, e.g. `INTEGER (0..7, ...)`.
+pub struct Extensible<C>(PhantomData<C>);
+
+impl<C: Constraints> Constraints for Extensible<C> {
+    const VALUE_RANGE: Option<(i128, i128)> = C::VALUE_RANGE;
+    const SIZE_RANGE: Option<(usize, Option<usize>)> = C::SIZE_RANGE;
+    const PERMITTED_ALPHABET: Option<&'static [char]> = C::PERMITTED_ALPHABET;
+    const EXTENSIBLE: bool = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Digits;
+
+    impl Constraints for Digits {
+        const SIZE_RANGE: Option<(usize, Option<usize>)> = Some((1, None));
+        const PERMITTED_ALPHABET: Option<&'static [char]> =
+            Some(&['0', '1', '2', '3', '4', '5', '6', '7', '8', '9']);
+    }
+
+    #[test]
+    fn unconstrained() {
+        assert_eq!(None, Unconstrained::value_range());
+        assert_eq!(None, Unconstrained::size_range());
+        assert_eq!(None, Unconstrained::permitted_alphabet());
+        assert!(!Unconstrained::is_extensible());
+    }
+
+    #[test]
+    fn custom() {
+        assert_eq!(None, Digits::value_range());
+        assert_eq!(Some((1, None)), Digits::size_range());
+        assert_eq!(Some(10), Digits::permitted_alphabet().map(<[_]>::len));
+
+        assert_eq!(Some((1, None)), Extensible::<Digits>::size_range());
+        assert!(Extensible::<Digits>::is_extensible());
+    }
+
+    #[test]
+    fn negative_range() {
+        const RANGE: Option<(i128, i128)> = <ValueRange<-128, 127>>::VALUE_RANGE;
+
+        assert_eq!(Some((-128, 127)), RANGE);
+    }
+}
@@ -0,0 +1,139 @@
+use alloc::vec::Vec;
+
+use super::{AsnType, BitString, OctetString, Tag};
+use crate::{Decode, Decoder, Encode, Encoder};
+
+/// A string whose contents are the DER encoding of a `T`, such as
+/// `OCTET STRING (CONTAINING Certificate)`, or with `S` as [`BitString`],
+/// `BIT STRING (CONTAINING SubjectPublicKey)`.
+///
+/// Decoding decodes the string, and then `T` from its contents, and encoding
+/// does the reverse. A `BIT STRING` containing a value is always a whole
+/// number of bytes, so one with any unused bits fails to decode.
+/// ```
+/// use rasn::types::{BitString, Containing};
+///
+/// let encoded = [0x03, 0x04, 0x00, 0x02, 0x01, 0x05];
+/// let value: Containing<u8, BitString> = rasn::der::decode(&encoded).unwrap();
+/// assert_eq!(5, *value);
+/// assert_eq!(&encoded[..], &*rasn::der::encode(&value).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Containing<T, S = OctetString> {
+    _string: core::marker::PhantomData<S>,
+    /// The contained value.
+    pub value: T,
+}
+
+impl<T, S> Containing<T, S> {
+    /// Create a wrapper from `value`.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            _string: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, S> From<T> for Containing<T, S> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, S> core::ops::Deref for Containing<T, S> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, S> core::ops::DerefMut for Containing<T, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T, S: AsnType> AsnType for Containing<T, S> {
+    const TAG: Tag = S::TAG;
+}
+
+/// Decodes `T` from the DER encoded `contents` of a string.
+fn decode_contents<T: Decode, E: crate::de::Error>(contents: &[u8]) -> Result<T, E> {
+    crate::der::decode(contents)
+        .map_err(|error| E::custom(alloc::format!("Invalid contained value: {}", error)))
+}
+
+/// Encodes `value` with DER as the contents of a string.
+fn encode_contents<T: Encode, E: crate::enc::Error>(value: &T) -> Result<Vec<u8>, E> {
+    let mut encoder = crate::ber::enc::Encoder::new(crate::ber::enc::EncoderOptions::der());
+    value.encode(&mut encoder).map_err(E::custom)?;
+
+    Ok(encoder.output())
+}
+
+impl<T: Decode> Decode for Containing<T, OctetString> {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decode_contents(&decoder.decode_octet_string(tag)?).map(Self::new)
+    }
+}
+
+impl<T: Encode> Encode for Containing<T, OctetString> {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        encoder
+            .encode_octet_string(tag, &encode_contents(&self.value)?)
+            .map(drop)
+    }
+}
+
+impl<T: Decode> Decode for Containing<T, BitString> {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        let bits = decoder.decode_bit_string(tag)?;
+        if bits.len() % 8 != 0 {
+            return Err(crate::de::Error::custom(
+                "A `BIT STRING` containing a value must have no unused bits",
+            ));
+        }
+
+        decode_contents(bits.as_raw_slice()).map(Self::new)
+    }
+}
+
+impl<T: Encode> Encode for Containing<T, BitString> {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        let bits = BitString::from_vec(encode_contents(&self.value)?);
+        encoder.encode_bit_string(tag, &bits).map(drop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::der::{decode, encode};
+
+    #[test]
+    fn octet_string() {
+        let value = Containing::<_>::new(alloc::vec![true, false]);
+        let encoded = encode(&value).unwrap();
+
+        assert_eq!(
+            &[0x04, 0x08, 0x30, 0x06, 0x01, 0x01, 0xFF, 0x01, 0x01, 0x00][..],
+            &*encoded
+        );
+        assert_eq!(value, decode(&encoded).unwrap());
+        assert!(decode::<Containing<bool>>(&[0x04, 0x03, 0x01, 0x01, 0x01]).is_err());
+    }
+
+    #[test]
+    fn bit_string() {
+        // The contained `NULL` ends in a zero byte, which must be kept.
+        let value = Containing::<(), BitString>::new(());
+        let encoded = encode(&value).unwrap();
+
+        assert_eq!(&[0x03, 0x03, 0x00, 0x05, 0x00][..], &*encoded);
+        assert_eq!(value, decode(&encoded).unwrap());
+        assert!(decode::<Containing<(), BitString>>(&[0x03, 0x03, 0x01, 0x05, 0x00]).is_err());
+        assert!(decode::<Containing<(), BitString>>(&[0x03, 0x00]).is_err());
+    }
+}
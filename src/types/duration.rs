@@ -0,0 +1,368 @@
+use core::{fmt, str::FromStr};
+
+use super::{AsnType, Tag};
+use crate::{Decode, Decoder, Encode, Encoder};
+
+/// The `DURATION` type, a period of time written as an ISO 8601 duration
+/// (e.g. `P1Y2M3DT4H5M6S`). Only whole numbers are supported for each
+/// component.
+///
+/// ISO 8601 doesn't permit weeks to be combined with any other component, so
+/// when `weeks` isn't the only non-zero component it's written as days.
+/// ```
+/// use rasn::types::Duration;
+///
+/// let duration: Duration = "P1DT12H".parse().unwrap();
+/// assert_eq!(1, duration.days);
+/// assert_eq!(12, duration.hours);
+/// assert_eq!("P1DT12H", duration.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration {
+    /// The number of years.
+    pub years: u32,
+    /// The number of months.
+    pub months: u32,
+    /// The number of weeks.
+    pub weeks: u32,
+    /// The number of days.
+    pub days: u32,
+    /// The number of hours.
+    pub hours: u32,
+    /// The number of minutes.
+    pub minutes: u32,
+    /// The number of seconds.
+    pub seconds: u32,
+}
+
+impl Duration {
+    fn components_mut(&mut self) -> [&mut u32; 7] {
+        [
+            &mut self.years,
+            &mut self.months,
+            &mut self.weeks,
+            &mut self.days,
+            &mut self.hours,
+            &mut self.minutes,
+            &mut self.seconds,
+        ]
+    }
+}
+
+/// The error returned when a string isn't a valid ISO 8601 duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDuration;
+
+impl fmt::Display for InvalidDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Invalid ISO 8601 duration")
+    }
+}
+
+impl FromStr for Duration {
+    type Err = InvalidDuration;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        const WEEKS: usize = 2;
+        const HOURS: usize = 4;
+
+        let mut rest = string.strip_prefix('P').ok_or(InvalidDuration)?;
+        let mut duration = Self::default();
+        let mut is_time = false;
+        let mut next = 0;
+        let mut components = 0;
+
+        while !rest.is_empty() {
+            if let Some(time) = rest.strip_prefix('T') {
+                if is_time || time.is_empty() {
+                    return Err(InvalidDuration);
+                }
+
+                is_time = true;
+                next = HOURS;
+                rest = time;
+                continue;
+            }
+
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .filter(|digits| *digits != 0)
+                .ok_or(InvalidDuration)?;
+            let value = rest[..digits].parse().map_err(|_| InvalidDuration)?;
+
+            let position = match (is_time, rest.as_bytes()[digits]) {
+                (false, b'Y') => 0,
+                (false, b'M') => 1,
+                (false, b'W') => WEEKS,
+                (false, b'D') => 3,
+                (true, b'H') => HOURS,
+                (true, b'M') => 5,
+                (true, b'S') => 6,
+                _ => return Err(InvalidDuration),
+            };
+
+            // Each component may only appear once, and in order.
+            if position < next {
+                return Err(InvalidDuration);
+            }
+
+            *duration.components_mut()[position] = value;
+            next = position + 1;
+            components += 1;
+            rest = &rest[digits + 1..];
+        }
+
+        let only_weeks = duration.weeks != 0 && components == 1;
+        if components == 0 || (duration.weeks != 0 && !only_weeks) {
+            return Err(InvalidDuration);
+        }
+
+        Ok(duration)
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let only_weeks = *self
+            == Self {
+                weeks: self.weeks,
+                ..<_>::default()
+            };
+        let (weeks, days) = if only_weeks {
+            (self.weeks, self.days)
+        } else {
+            (0, self.days.saturating_add(self.weeks.saturating_mul(7)))
+        };
+
+        f.write_str("P")?;
+
+        for (value, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (weeks, 'W'),
+            (days, 'D'),
+        ] {
+            if value != 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+
+        if self.hours != 0 || self.minutes != 0 || self.seconds != 0 {
+            f.write_str("T")?;
+
+            for (value, designator) in [(self.hours, 'H'), (self.minutes, 'M'), (self.seconds, 'S')]
+            {
+                if value != 0 {
+                    write!(f, "{}{}", value, designator)?;
+                }
+            }
+        } else if *self == Self::default() {
+            f.write_str("T0S")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A [`core::time::Duration`] encoded as an `INTEGER` number of whole
+/// seconds.
+///
+/// Only whole seconds can be represented, so encoding a duration with a
+/// fractional number of seconds is an error, rather than silently rounding.
+/// ```
+/// use core::time::Duration;
+/// use rasn::types::DurationSeconds;
+///
+/// let timeout = DurationSeconds(Duration::from_secs(30));
+/// assert_eq!(&[0x02, 0x01, 30][..], &*rasn::der::encode(&timeout).unwrap());
+/// assert!(rasn::der::encode(&DurationSeconds(Duration::from_millis(1500))).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DurationSeconds(pub core::time::Duration);
+
+impl AsnType for DurationSeconds {
+    const TAG: Tag = Tag::INTEGER;
+}
+
+impl Decode for DurationSeconds {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        u64::decode_with_tag(decoder, tag)
+            .map(core::time::Duration::from_secs)
+            .map(Self)
+    }
+}
+
+impl Encode for DurationSeconds {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        whole_seconds::<E>(self.0)?.encode_with_tag(encoder, tag)
+    }
+}
+
+/// A [`core::time::Duration`] encoded as an ISO 8601 `DURATION` (e.g.
+/// `PT1H30M`), written with days, hours, minutes, and seconds.
+///
+/// Only whole seconds can be represented, so encoding a duration with a
+/// fractional number of seconds is an error, rather than silently rounding.
+/// As years and months don't have a fixed length, decoding a `DURATION` with
+/// either is an error, while weeks and days are taken as 7 days and 24 hours.
+/// ```
+/// use core::time::Duration;
+/// use rasn::types::DurationIso;
+///
+/// let interval = DurationIso(Duration::from_secs(5400));
+/// assert_eq!(b"\x1F\x22\x07PT1H30M"[..], *rasn::der::encode(&interval).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DurationIso(pub core::time::Duration);
+
+impl AsnType for DurationIso {
+    const TAG: Tag = Tag::DURATION;
+}
+
+impl Decode for DurationIso {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        let duration = Duration::decode_with_tag(decoder, tag)?;
+
+        if duration.years != 0 || duration.months != 0 {
+            return Err(crate::de::Error::custom(alloc::format!(
+                "`DURATION` `{}` with years or months doesn't have a fixed length",
+                duration
+            )));
+        }
+
+        let seconds = [
+            (duration.weeks, 7 * 24 * 60 * 60),
+            (duration.days, 24 * 60 * 60),
+            (duration.hours, 60 * 60),
+            (duration.minutes, 60),
+            (duration.seconds, 1),
+        ]
+        .iter()
+        .map(|(value, seconds)| u64::from(*value) * seconds)
+        .sum();
+
+        Ok(Self(core::time::Duration::from_secs(seconds)))
+    }
+}
+
+impl Encode for DurationIso {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        let seconds = whole_seconds::<E>(self.0)?;
+        let days: u32 =
+            core::convert::TryFrom::try_from(seconds / (24 * 60 * 60)).map_err(|_| {
+                crate::enc::Error::custom("Duration has too many days to encode as a `DURATION`")
+            })?;
+
+        Duration {
+            days,
+            hours: (seconds / (60 * 60) % 24) as u32,
+            minutes: (seconds / 60 % 60) as u32,
+            seconds: (seconds % 60) as u32,
+            ..<_>::default()
+        }
+        .encode_with_tag(encoder, tag)
+    }
+}
+
+/// Returns the number of seconds in `duration`, or an error if it has a
+/// fractional number of seconds.
+fn whole_seconds<E: Encoder>(duration: core::time::Duration) -> Result<u64, E::Error> {
+    if duration.subsec_nanos() == 0 {
+        Ok(duration.as_secs())
+    } else {
+        Err(crate::enc::Error::custom(alloc::format!(
+            "Duration `{:?}` isn't a whole number of seconds",
+            duration
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Ok(Duration {
+                years: 1,
+                months: 2,
+                days: 3,
+                hours: 4,
+                minutes: 5,
+                seconds: 6,
+                ..<_>::default()
+            }),
+            "P1Y2M3DT4H5M6S".parse()
+        );
+        assert_eq!(
+            Ok(Duration {
+                minutes: 30,
+                ..<_>::default()
+            }),
+            "PT30M".parse()
+        );
+        assert_eq!(
+            Ok(Duration {
+                weeks: 2,
+                ..<_>::default()
+            }),
+            "P2W".parse()
+        );
+
+        for invalid in &[
+            "", "P", "PT", "P1DT", "1D", "P1H", "PT1D", "P1D1Y", "P1M1M", "P2W1D", "PD", "P-1D",
+            "P1.5D",
+        ] {
+            assert_eq!(
+                Err(InvalidDuration),
+                invalid.parse::<Duration>(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        for duration in &["P1Y2M3DT4H5M6S", "PT30M", "P2W", "P1M", "PT0S"] {
+            assert_eq!(*duration, duration.parse::<Duration>().unwrap().to_string());
+        }
+
+        let combined = Duration {
+            weeks: 1,
+            days: 1,
+            hours: 1,
+            ..<_>::default()
+        };
+        assert_eq!("P8DT1H", combined.to_string());
+    }
+
+    #[cfg(feature = "der")]
+    #[test]
+    fn core_durations() {
+        use crate::der::{decode, encode};
+        use core::time::Duration;
+
+        let seconds = DurationSeconds(Duration::from_secs(300));
+        assert_eq!(&[0x02, 0x02, 0x01, 0x2C][..], &*encode(&seconds).unwrap());
+        assert_eq!(seconds, decode(&encode(&seconds).unwrap()).unwrap());
+        assert!(decode::<DurationSeconds>(&[0x02, 0x01, 0xFF]).is_err());
+
+        let iso = DurationIso(Duration::from_secs(90061));
+        let encoded = encode(&iso).unwrap();
+        assert_eq!(b"\x1F\x22\x0AP1DT1H1M1S"[..], *encoded);
+        assert_eq!(iso, decode(&encoded).unwrap());
+        assert_eq!(
+            DurationIso(Duration::from_secs(2 * 7 * 24 * 60 * 60)),
+            decode(b"\x1F\x22\x03P2W").unwrap()
+        );
+        assert_eq!(
+            b"\x1F\x22\x04PT0S"[..],
+            *encode(&DurationIso(Duration::ZERO)).unwrap()
+        );
+        assert!(decode::<DurationIso>(b"\x1F\x22\x03P1M").is_err());
+        assert!(encode(&DurationIso(Duration::from_nanos(1))).is_err());
+    }
+}
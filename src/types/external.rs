@@ -0,0 +1,190 @@
+use super::*;
+use crate::{Decode, Encode};
+
+///  The `ObjectDescriptor` type, a human readable description of an object.
+pub type ObjectDescriptor = Implicit<tag::OBJECT_DESCRIPTOR, Utf8String>;
+
+/// The `EXTERNAL` type, a value of a type that's defined outside of the
+/// current schema, in the form X.690 encodes it (section 8.18).
+///
+/// The value's type (its abstract and transfer syntax) is identified by
+/// `direct_reference`, by `indirect_reference` (a presentation context), or
+/// both, and it's contained in `encoding`.
+/// ```
+/// use rasn::types::{External, ExternalEncoding, ObjectIdentifier, OctetString};
+///
+/// let external = External {
+///     direct_reference: ObjectIdentifier::new(vec![2, 1, 1]),
+///     indirect_reference: None,
+///     data_value_descriptor: None,
+///     encoding: ExternalEncoding::OctetAligned(OctetString::from_static(&[1])),
+/// };
+///
+/// let encoded = rasn::der::encode(&external).unwrap();
+/// assert_eq!(&[0x28, 0x07, 0x06, 0x02, 0x51, 0x01, 0x81, 0x01, 0x01][..], &*encoded);
+/// assert_eq!(external, rasn::der::decode(&encoded).unwrap());
+/// ```
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(crate_root = "crate")]
+#[rasn(tag(universal, 8))]
+pub struct External {
+    /// The object identifier of the value's type.
+    pub direct_reference: Option<ObjectIdentifier>,
+    /// The presentation context identifying the value's type.
+    pub indirect_reference: Option<Integer>,
+    /// A description of the value.
+    pub data_value_descriptor: Option<ObjectDescriptor>,
+    /// The value.
+    pub encoding: ExternalEncoding,
+}
+
+/// How the value of an [`External`] is encoded.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(crate_root = "crate")]
+#[rasn(choice)]
+pub enum ExternalEncoding {
+    /// A single ASN.1 value, with the same encoding rules as the `EXTERNAL`.
+    #[rasn(tag(explicit(0)))]
+    SingleAsn1Type(Any),
+    /// Any other encoding that's a whole number of octets.
+    #[rasn(tag(1))]
+    OctetAligned(OctetString),
+    /// Any other encoding.
+    #[rasn(tag(2))]
+    Arbitrary(BitVec),
+}
+
+/// The `EMBEDDED PDV` type, a value of a type that's defined outside of the
+/// current schema, encoded as an octet string. Its fields are automatically
+/// tagged, as X.680 defines it in a module with `AUTOMATIC TAGS`, except for
+/// the `data-value-descriptor`, which is always absent.
+/// ```
+/// use rasn::types::{EmbeddedPdv, EmbeddedPdvIdentification, OctetString};
+///
+/// let pdv = EmbeddedPdv {
+///     identification: EmbeddedPdvIdentification::Fixed,
+///     data_value: OctetString::from_static(&[0xFF]),
+/// };
+///
+/// let encoded = rasn::der::encode(&pdv).unwrap();
+/// assert_eq!(&[0x2B, 0x07, 0xA0, 0x02, 0x85, 0x00, 0x82, 0x01, 0xFF][..], &*encoded);
+/// assert_eq!(pdv, rasn::der::decode(&encoded).unwrap());
+/// ```
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(crate_root = "crate")]
+#[rasn(tag(universal, 11))]
+pub struct EmbeddedPdv {
+    /// The abstract and transfer syntaxes of the value.
+    #[rasn(tag(0))]
+    pub identification: EmbeddedPdvIdentification,
+    /// The encoded value.
+    #[rasn(tag(2))]
+    pub data_value: OctetString,
+}
+
+/// How the abstract and transfer syntaxes of an [`EmbeddedPdv`] are
+/// identified.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(crate_root = "crate")]
+#[rasn(choice)]
+pub enum EmbeddedPdvIdentification {
+    /// The object identifiers of the abstract and transfer syntaxes.
+    #[rasn(tag(0))]
+    Syntaxes(PdvSyntaxes),
+    /// The object identifier of a single syntax.
+    #[rasn(tag(1))]
+    Syntax(ObjectIdentifier),
+    /// A presentation context that was negotiated by the presentation layer.
+    #[rasn(tag(2))]
+    PresentationContextId(Integer),
+    /// A presentation context that's being negotiated.
+    #[rasn(tag(3))]
+    ContextNegotiation(PdvContextNegotiation),
+    /// The object identifier of the transfer syntax, with the abstract
+    /// syntax known from context.
+    #[rasn(tag(4))]
+    TransferSyntax(ObjectIdentifier),
+    /// Both syntaxes are known from context.
+    #[rasn(tag(5))]
+    Fixed,
+}
+
+/// The abstract and transfer syntaxes of an [`EmbeddedPdv`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(crate_root = "crate")]
+pub struct PdvSyntaxes {
+    /// The abstract syntax, which is the value's type.
+    #[rasn(tag(0))]
+    pub r#abstract: ObjectIdentifier,
+    /// The transfer syntax, which is how the value's encoded.
+    #[rasn(tag(1))]
+    pub transfer: ObjectIdentifier,
+}
+
+/// A presentation context that's being negotiated for an [`EmbeddedPdv`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(crate_root = "crate")]
+pub struct PdvContextNegotiation {
+    /// The identifier of the presentation context.
+    #[rasn(tag(0))]
+    pub presentation_context_id: Integer,
+    /// The transfer syntax proposed for the presentation context.
+    #[rasn(tag(1))]
+    pub transfer_syntax: ObjectIdentifier,
+}
+
+#[cfg(all(test, feature = "der"))]
+mod tests {
+    use super::*;
+    use crate::der::{decode, encode};
+
+    #[test]
+    fn external_encodings() {
+        let external = |encoding| External {
+            direct_reference: None,
+            indirect_reference: Some(3.into()),
+            data_value_descriptor: Some(Implicit::new("Data".into())),
+            encoding,
+        };
+
+        let single = external(ExternalEncoding::SingleAsn1Type(Any::new(alloc::vec![
+            0x05, 0x00
+        ])));
+        let encoded = encode(&single).unwrap();
+        assert_eq!(
+            &[
+                0x28, 0x0D, 0x02, 0x01, 0x03, 0x07, 0x04, b'D', b'a', b't', b'a', 0xA0, 0x02, 0x05,
+                0x00
+            ][..],
+            &*encoded
+        );
+        assert_eq!(single, decode(&encoded).unwrap());
+
+        // The trailing zero bits of an arbitrary encoding are kept.
+        let arbitrary = external(ExternalEncoding::Arbitrary(BitVec::from(alloc::vec![
+            true, false, false
+        ])));
+        let encoded = encode(&arbitrary).unwrap();
+        assert_eq!(&[0x82, 0x02, 0x05, 0x80][..], &encoded[encoded.len() - 4..]);
+        assert_eq!(arbitrary, decode(&encoded).unwrap());
+
+        assert!(decode::<External>(&[0x28, 0x03, 0x02, 0x01, 0x03]).is_err());
+    }
+
+    #[test]
+    fn embedded_pdv_identification() {
+        let pdv = EmbeddedPdv {
+            identification: EmbeddedPdvIdentification::Syntaxes(PdvSyntaxes {
+                r#abstract: ObjectIdentifier::new(alloc::vec![2, 1]).unwrap(),
+                transfer: ObjectIdentifier::new(alloc::vec![2, 2]).unwrap(),
+            }),
+            data_value: OctetString::new(),
+        };
+        let encoded = encode(&pdv).unwrap();
+        assert_eq!(
+            &[0x2B, 0x0C, 0xA0, 0x08, 0xA0, 0x06, 0x80, 0x01, 0x51, 0x81, 0x01, 0x52, 0x82, 0x00][..],
+            &*encoded
+        );
+        assert_eq!(pdv, decode(&encoded).unwrap());
+    }
+}
@@ -0,0 +1,219 @@
+use core::{iter::FromIterator, marker::PhantomData};
+
+use super::{AsnType, BitString, Tag};
+use crate::{Decode, Decoder, Encode, Encoder};
+
+/// A type representing the position of a single named bit in a [`Flags`]
+/// set, usually implemented by a field-less enum.
+/// ```
+/// use rasn::types::{Flag, Flags};
+///
+/// #[derive(Clone, Copy)]
+/// enum KeyUsage {
+///     DigitalSignature = 0,
+///     KeyCertSign = 5,
+/// }
+///
+/// impl Flag for KeyUsage {
+///     fn bit(self) -> usize {
+///         self as usize
+///     }
+/// }
+///
+/// let mut usage = Flags::new();
+/// usage.insert(KeyUsage::KeyCertSign);
+/// assert!(usage.contains(KeyUsage::KeyCertSign));
+/// assert!(!usage.contains(KeyUsage::DigitalSignature));
+/// ```
+pub trait Flag: Copy {
+    /// The position of the bit in the `BIT STRING`, starting from zero.
+    fn bit(self) -> usize;
+}
+
+/// Removes the unset bits after the last set bit. `BitSlice::last_one` isn't
+/// used to find it, as it overflows when no bits are set.
+fn trim_unset_bits(bits: &mut BitString) {
+    let length = bits
+        .iter()
+        .rposition(|bit| *bit)
+        .map_or(0, |index| index + 1);
+    bits.truncate(length);
+}
+
+/// A set of [`Flag`]s, encoded as a `BIT STRING` where each flag is a named
+/// bit. Trailing zero bits are never stored, so the set is always encoded
+/// in its canonical (DER) form.
+pub struct Flags<E> {
+    bits: BitString,
+    _flag: PhantomData<fn() -> E>,
+}
+
+impl<E: Flag> Flags<E> {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::from_bit_string(BitString::new())
+    }
+
+    /// Creates a set from the bits in `bits`.
+    pub fn from_bit_string(mut bits: BitString) -> Self {
+        trim_unset_bits(&mut bits);
+
+        Self {
+            bits,
+            _flag: PhantomData,
+        }
+    }
+
+    /// Whether `flag` is set.
+    pub fn contains(&self, flag: E) -> bool {
+        matches!(self.bits.get(flag.bit()).as_deref(), Some(true))
+    }
+
+    /// Sets `flag`, returning whether it was previously unset.
+    pub fn insert(&mut self, flag: E) -> bool {
+        let index = flag.bit();
+
+        if index >= self.bits.len() {
+            self.bits.resize(index + 1, false);
+        }
+
+        !self.bits.replace(index, true)
+    }
+
+    /// Unsets `flag`, returning whether it was previously set.
+    pub fn remove(&mut self, flag: E) -> bool {
+        if !self.contains(flag) {
+            return false;
+        }
+
+        self.bits.set(flag.bit(), false);
+        trim_unset_bits(&mut self.bits);
+        true
+    }
+
+    /// Whether no flags are set.
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    /// Returns the underlying bits of the set.
+    pub fn as_bit_string(&self) -> &BitString {
+        &self.bits
+    }
+}
+
+impl<E: Flag> Default for Flags<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Clone for Flags<E> {
+    fn clone(&self) -> Self {
+        Self {
+            bits: self.bits.clone(),
+            _flag: PhantomData,
+        }
+    }
+}
+
+impl<E> core::fmt::Debug for Flags<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_tuple("Flags").field(&self.bits).finish()
+    }
+}
+
+impl<E> PartialEq for Flags<E> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.bits == rhs.bits
+    }
+}
+
+impl<E> Eq for Flags<E> {}
+
+impl<E: Flag> FromIterator<E> for Flags<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        let mut flags = Self::new();
+
+        for flag in iter {
+            flags.insert(flag);
+        }
+
+        flags
+    }
+}
+
+impl<E> AsnType for Flags<E> {
+    const TAG: Tag = Tag::BIT_STRING;
+}
+
+impl<E: Flag> Decode for Flags<E> {
+    fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        decoder.decode_bit_string(tag).map(Self::from_bit_string)
+    }
+}
+
+impl<E> Encode for Flags<E> {
+    fn encode_with_tag<EN: Encoder>(&self, encoder: &mut EN, tag: Tag) -> Result<(), EN::Error> {
+        encoder.encode_bit_string(tag, &self.bits).map(drop)
+    }
+}
+
+#[cfg(all(test, feature = "der"))]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    enum Bit {
+        Zero = 0,
+        One = 1,
+        Nine = 9,
+    }
+
+    impl Flag for Bit {
+        fn bit(self) -> usize {
+            self as usize
+        }
+    }
+
+    #[test]
+    fn spans_two_bytes() {
+        let flags: Flags<Bit> = [Bit::Zero, Bit::Nine].iter().copied().collect();
+        let encoded = crate::der::encode(&flags).unwrap();
+
+        assert_eq!(&[0x03, 0x03, 0x06, 0x80, 0x40][..], &*encoded);
+
+        let decoded: Flags<Bit> = crate::der::decode(&encoded).unwrap();
+        assert!(decoded.contains(Bit::Zero));
+        assert!(!decoded.contains(Bit::One));
+        assert!(decoded.contains(Bit::Nine));
+        assert_eq!(flags, decoded);
+    }
+
+    #[test]
+    fn trims_trailing_bits() {
+        let mut flags = Flags::new();
+        assert!(flags.insert(Bit::Zero));
+        assert!(flags.insert(Bit::Nine));
+        assert!(!flags.insert(Bit::Nine));
+        assert!(flags.remove(Bit::Nine));
+        assert!(!flags.remove(Bit::One));
+
+        assert_eq!(1, flags.as_bit_string().len());
+        assert_eq!(
+            &[0x03, 0x02, 0x07, 0x80][..],
+            &*crate::der::encode(&flags).unwrap()
+        );
+
+        assert!(flags.remove(Bit::Zero));
+        assert!(flags.is_empty());
+        assert!(Flags::<Bit>::from_bit_string(BitString::repeat(false, 9)).is_empty());
+        assert_eq!(
+            &[0x03, 0x01, 0x00][..],
+            &*crate::der::encode(&flags).unwrap()
+        );
+        assert!(crate::der::decode::<Flags<Bit>>(&[0x03, 0x02, 0x00, 0x00])
+            .unwrap()
+            .is_empty());
+    }
+}
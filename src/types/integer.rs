@@ -0,0 +1,115 @@
+use alloc::vec::Vec;
+
+use super::Integer;
+
+/// Conversions between an [`Integer`] and the bytes of its encoding, for
+/// integers that are really binary data, such as an RSA modulus.
+///
+/// An `INTEGER`'s contents are its value in big-endian two's complement, using
+/// as few bytes as possible. So a positive value whose most significant bit is
+/// set, which is common for moduli, has an extra leading `0x00` byte, which
+/// keeps it from being read as negative.
+/// ```
+/// use rasn::types::{Integer, IntegerExt};
+///
+/// let modulus = Integer::from(0xC0FFEE);
+/// assert_eq!(vec![0x00, 0xC0, 0xFF, 0xEE], modulus.to_der_contents());
+/// assert_eq!(Some(vec![0xC0, 0xFF, 0xEE]), modulus.to_unsigned_bytes_be());
+/// ```
+pub trait IntegerExt: Sized {
+    /// Returns the magnitude of a non-negative integer as big-endian bytes,
+    /// which is its contents octets without the leading `0x00` sign byte, if
+    /// any. Zero is a single `0x00` byte. Returns `None` if the integer is
+    /// negative, as it has no unsigned representation.
+    fn to_unsigned_bytes_be(&self) -> Option<Vec<u8>>;
+
+    /// Returns the exact contents octets of the integer's DER (and BER, as
+    /// there's only one valid encoding of each value) encoding: the value in
+    /// big-endian two's complement with no redundant leading `0x00` or `0xFF`
+    /// bytes. This is the same as `to_signed_bytes_be`.
+    fn to_der_contents(&self) -> Vec<u8>;
+
+    /// Creates a non-negative integer from its magnitude as big-endian
+    /// bytes, so that it's encoded with a leading `0x00` byte if needed. Any
+    /// leading zero bytes in `bytes` are ignored.
+    fn from_unsigned_bytes_be(bytes: &[u8]) -> Self;
+}
+
+impl IntegerExt for Integer {
+    fn to_unsigned_bytes_be(&self) -> Option<Vec<u8>> {
+        match self.to_bytes_be() {
+            (num_bigint::Sign::Minus, _) => None,
+            (_, magnitude) => Some(magnitude),
+        }
+    }
+
+    fn to_der_contents(&self) -> Vec<u8> {
+        self.to_signed_bytes_be()
+    }
+
+    fn from_unsigned_bytes_be(bytes: &[u8]) -> Self {
+        Integer::from_bytes_be(num_bigint::Sign::Plus, bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn sign_byte() {
+        let high_bit = Integer::from_unsigned_bytes_be(&[0x80, 0x00]);
+        assert_eq!(vec![0x00, 0x80, 0x00], high_bit.to_der_contents());
+        assert_eq!(Some(vec![0x80, 0x00]), high_bit.to_unsigned_bytes_be());
+
+        let low_bits = Integer::from_unsigned_bytes_be(&[0x00, 0x7F]);
+        assert_eq!(vec![0x7F], low_bits.to_der_contents());
+        assert_eq!(Some(vec![0x7F]), low_bits.to_unsigned_bytes_be());
+
+        assert_eq!(Some(vec![0x00]), Integer::from(0).to_unsigned_bytes_be());
+        assert_eq!(vec![0x00], Integer::from(0).to_der_contents());
+        assert_eq!(None, Integer::from(-128).to_unsigned_bytes_be());
+        assert_eq!(vec![0x80], Integer::from(-128).to_der_contents());
+    }
+
+    #[cfg(feature = "der")]
+    #[test]
+    fn modulus() {
+        let mut modulus = vec![0xFF; 256];
+        modulus[255] = 0xF1;
+        let integer = Integer::from_unsigned_bytes_be(&modulus);
+        let encoded = crate::der::encode(&integer).unwrap();
+
+        assert_eq!(&[0x02, 0x82, 0x01, 0x01, 0x00][..], &encoded[..5]);
+        assert_eq!(integer.to_der_contents(), &encoded[4..]);
+
+        let decoded: Integer = crate::der::decode(&encoded).unwrap();
+        assert_eq!(Some(modulus), decoded.to_unsigned_bytes_be());
+    }
+
+    #[cfg(feature = "der")]
+    #[test]
+    fn big_uint() {
+        use num_bigint::BigUint;
+
+        let high_bit = BigUint::from(0x80_00u32);
+        let encoded = crate::der::encode(&high_bit).unwrap();
+        assert_eq!(&[0x02, 0x03, 0x00, 0x80, 0x00][..], &*encoded);
+        assert_eq!(high_bit, crate::der::decode(&encoded).unwrap());
+
+        let low_bits = BigUint::from(0x7Fu32);
+        assert_eq!(
+            &[0x02, 0x01, 0x7F][..],
+            &*crate::der::encode(&low_bits).unwrap()
+        );
+        assert_eq!(
+            &[0x02, 0x01, 0x00][..],
+            &*crate::der::encode(&BigUint::from(0u32)).unwrap()
+        );
+
+        assert!(crate::der::decode::<BigUint>(&[0x02, 0x02, 0x80, 0x00]).is_err());
+        // A redundant leading `0x00` is still rejected by DER.
+        assert!(crate::der::decode::<BigUint>(&[0x02, 0x02, 0x00, 0x7F]).is_err());
+    }
+}
@@ -0,0 +1,265 @@
+use core::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use super::{AsnType, Tag};
+use crate::{Decode, Decoder, Encode, Encoder};
+
+macro_rules! ip_prefix {
+    ($(#[$doc:meta])* $name:ident, $addr:ident, $bits:ty, $family:literal) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name {
+            address: $addr,
+            len: u8,
+        }
+
+        impl $name {
+            /// The number of bits in an address, and the longest prefix.
+            pub const MAX_LEN: u8 = <$bits>::BITS as u8;
+
+            /// Creates a new prefix of the first `len` bits of `address`,
+            /// returning `None` if `len` is longer than an address, or any of
+            /// `address`'s bits after the prefix aren't zero.
+            pub fn new(address: $addr, len: u8) -> Option<Self> {
+                let host_bits = <$bits>::from(address).checked_shl(u32::from(len)).unwrap_or(0);
+
+                (len <= Self::MAX_LEN && host_bits == 0).then(|| Self { address, len })
+            }
+
+            /// Returns the address, with every bit after the prefix set to zero.
+            pub fn address(&self) -> $addr {
+                self.address
+            }
+
+            /// Returns the length of the prefix in bits.
+            pub fn len(&self) -> u8 {
+                self.len
+            }
+
+            /// Returns whether the prefix is zero bits long, matching every
+            /// address.
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}/{}", self.address, self.len)
+            }
+        }
+
+        impl AsnType for $name {
+            const TAG: Tag = Tag::BIT_STRING;
+        }
+
+        impl Decode for $name {
+            fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+                let bits = decoder.decode_bit_string(tag)?;
+                if bits.len() > usize::from(Self::MAX_LEN) {
+                    return Err(crate::de::Error::custom(alloc::format!(
+                        "`BIT STRING` isn't a valid {} prefix",
+                        $family
+                    )));
+                }
+
+                let address = bits.iter_ones().fold(0, |address: $bits, index| {
+                    address | 1 << (usize::from(Self::MAX_LEN) - 1 - index)
+                });
+
+                Ok(Self {
+                    address: address.into(),
+                    len: bits.len() as u8,
+                })
+            }
+        }
+
+        impl Encode for $name {
+            fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+                let mut bits = super::BitString::from_slice(&self.address.octets());
+                bits.truncate(usize::from(self.len));
+
+                encoder.encode_bit_string(tag, &bits).map(drop)
+            }
+        }
+    };
+}
+
+ip_prefix! {
+    /// An IPv4 address prefix, such as `192.0.2.0/24`, encoded as a
+    /// `BIT STRING` of the prefix's bits, as in RFC 3779's `IPAddress`.
+    /// ```
+    /// use core::net::Ipv4Addr;
+    /// use rasn::types::Ipv4Prefix;
+    ///
+    /// let prefix = Ipv4Prefix::new(Ipv4Addr::new(192, 0, 2, 0), 24).unwrap();
+    /// let encoded = rasn::der::encode(&prefix).unwrap();
+    /// assert_eq!(&[0x03, 0x04, 0x00, 0xC0, 0x00, 0x02][..], &*encoded);
+    /// assert_eq!(prefix, rasn::der::decode(&encoded).unwrap());
+    ///
+    /// assert!(Ipv4Prefix::new(Ipv4Addr::new(192, 0, 2, 1), 24).is_none());
+    /// assert!(Ipv4Prefix::new(Ipv4Addr::UNSPECIFIED, 33).is_none());
+    /// ```
+    Ipv4Prefix,
+    Ipv4Addr,
+    u32,
+    "IPv4"
+}
+
+ip_prefix! {
+    /// An IPv6 address prefix, such as `2001:db8::/32`, encoded as a
+    /// `BIT STRING` of the prefix's bits, as in RFC 3779's `IPAddress`.
+    Ipv6Prefix,
+    Ipv6Addr,
+    u128,
+    "IPv6"
+}
+
+/// Either an [`Ipv4Prefix`] or an [`Ipv6Prefix`].
+///
+/// The `BIT STRING` only contains the prefix's bits, so it doesn't say which
+/// address family the prefix belongs to, which instead has to come from
+/// elsewhere in the schema (such as the `addressFamily` of RFC 3779's
+/// `IPAddressFamily`). That's why this type can only be encoded; decode an
+/// [`Ipv4Prefix`] or [`Ipv6Prefix`] and convert it instead.
+/// ```
+/// use core::net::{IpAddr, Ipv6Addr};
+/// use rasn::types::{IpPrefix, Ipv6Prefix};
+///
+/// let encoded = [0x03, 0x02, 0x05, 0x20];
+/// let prefix = IpPrefix::from(rasn::der::decode::<Ipv6Prefix>(&encoded).unwrap());
+///
+/// let address = Ipv6Addr::new(0x2000, 0, 0, 0, 0, 0, 0, 0);
+/// assert_eq!((IpAddr::V6(address), 3), prefix.into_parts());
+/// assert_eq!(&encoded[..], &*rasn::der::encode(&prefix).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IpPrefix {
+    /// An IPv4 prefix.
+    V4(Ipv4Prefix),
+    /// An IPv6 prefix.
+    V6(Ipv6Prefix),
+}
+
+impl IpPrefix {
+    /// Creates a new prefix of the first `len` bits of `address`, returning
+    /// `None` if `len` is longer than an address, or any of `address`'s bits
+    /// after the prefix aren't zero.
+    pub fn new(address: IpAddr, len: u8) -> Option<Self> {
+        match address {
+            IpAddr::V4(address) => Ipv4Prefix::new(address, len).map(Self::V4),
+            IpAddr::V6(address) => Ipv6Prefix::new(address, len).map(Self::V6),
+        }
+    }
+
+    /// Returns the address, with every bit after the prefix set to zero.
+    pub fn address(&self) -> IpAddr {
+        match self {
+            Self::V4(prefix) => prefix.address().into(),
+            Self::V6(prefix) => prefix.address().into(),
+        }
+    }
+
+    /// Returns the length of the prefix in bits.
+    pub fn len(&self) -> u8 {
+        match self {
+            Self::V4(prefix) => prefix.len(),
+            Self::V6(prefix) => prefix.len(),
+        }
+    }
+
+    /// Returns whether the prefix is zero bits long, matching every address.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Converts `self` into its address and the length of the prefix.
+    pub fn into_parts(self) -> (IpAddr, u8) {
+        (self.address(), self.len())
+    }
+}
+
+impl From<Ipv4Prefix> for IpPrefix {
+    fn from(prefix: Ipv4Prefix) -> Self {
+        Self::V4(prefix)
+    }
+}
+
+impl From<Ipv6Prefix> for IpPrefix {
+    fn from(prefix: Ipv6Prefix) -> Self {
+        Self::V6(prefix)
+    }
+}
+
+impl fmt::Display for IpPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::V4(prefix) => prefix.fmt(f),
+            Self::V6(prefix) => prefix.fmt(f),
+        }
+    }
+}
+
+impl AsnType for IpPrefix {
+    const TAG: Tag = Tag::BIT_STRING;
+}
+
+impl Encode for IpPrefix {
+    fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+        match self {
+            Self::V4(prefix) => prefix.encode_with_tag(encoder, tag),
+            Self::V6(prefix) => prefix.encode_with_tag(encoder, tag),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "der"))]
+mod tests {
+    use super::*;
+    use crate::der::{decode, encode};
+
+    #[test]
+    fn prefixes() {
+        let v4 = Ipv4Prefix::new(Ipv4Addr::new(10, 64, 0, 0), 10).unwrap();
+        let encoded = encode(&v4).unwrap();
+        assert_eq!(&[0x03, 0x03, 0x06, 0x0A, 0x40][..], &*encoded);
+        assert_eq!(v4, decode(&encoded).unwrap());
+        assert_eq!("10.64.0.0/10", alloc::string::ToString::to_string(&v4));
+
+        for len in [0u8, 1, 31, 32] {
+            let address = u32::MAX.checked_shl(32 - u32::from(len)).unwrap_or(0);
+            let v4 = Ipv4Prefix::new(address.into(), len).unwrap();
+            assert_eq!(v4, decode(&encode(&v4).unwrap()).unwrap());
+        }
+
+        let v6 = Ipv6Prefix::new(Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 1), 128).unwrap();
+        assert_eq!(v6, decode(&encode(&v6).unwrap()).unwrap());
+        assert!(Ipv6Prefix::new(Ipv6Addr::UNSPECIFIED, 129).is_none());
+        assert!(Ipv6Prefix::new(Ipv6Addr::LOCALHOST, 127).is_none());
+
+        let empty = IpPrefix::new(Ipv6Addr::UNSPECIFIED.into(), 0).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(&[0x03, 0x01, 0x00][..], &*encode(&empty).unwrap());
+
+        let trailing_zeros = Ipv4Prefix::new(Ipv4Addr::new(10, 0, 0, 0), 16).unwrap();
+        let encoded = encode(&trailing_zeros).unwrap();
+        assert_eq!(&[0x03, 0x03, 0x00, 0x0A, 0x00][..], &*encoded);
+        assert_eq!(trailing_zeros, decode(&encoded).unwrap());
+
+        // Unused bits can be anything in BER, but must be zero in DER.
+        let unused_bits_set = Ipv4Prefix::new(Ipv4Addr::new(10, 0, 0, 0), 7).unwrap();
+        assert_eq!(
+            unused_bits_set,
+            crate::ber::decode(&[0x03, 0x02, 0x01, 0x0B]).unwrap()
+        );
+        assert!(decode::<Ipv4Prefix>(&[0x03, 0x02, 0x01, 0x0B]).is_err());
+
+        let too_long = encode(&v6).unwrap();
+        assert!(decode::<Ipv4Prefix>(&too_long).is_err());
+        assert!(decode::<Ipv4Prefix>(&[0x03, 0x00]).is_err());
+        assert!(decode::<Ipv4Prefix>(&[0x03, 0x01, 0x01]).is_err());
+        assert!(decode::<Ipv4Prefix>(&[0x03, 0x02, 0x08, 0x00]).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+
+/// Whether `arcs` is a `/` separated list of one or more non-empty arcs.
+fn is_valid_arcs(arcs: &str) -> bool {
+    !arcs.is_empty() && arcs.split('/').all(|arc| !arc.is_empty())
+}
+
+/// The error returned when a string isn't a valid OID internationalized
+/// resource identifier (IRI).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidIri;
+
+impl fmt::Display for InvalidIri {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Invalid OID internationalized resource identifier")
+    }
+}
+
+macro_rules! iri {
+    ($(#[$doc:meta])* $name:ident, $is_valid:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Creates a new IRI from `iri`, returning `None` if it isn't a
+            /// valid IRI.
+            pub fn new(iri: impl Into<String>) -> Option<Self> {
+                let iri = iri.into();
+                ($is_valid)(&*iri).then(|| Self(iri))
+            }
+
+            /// Returns the IRI as a string.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Converts `self` into the IRI's string.
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = InvalidIri;
+
+            fn from_str(iri: &str) -> Result<Self, Self::Err> {
+                Self::new(iri).ok_or(InvalidIri)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+    };
+}
+
+iri! {
+    /// The `OID-IRI` type, an object identifier written as a path of arcs
+    /// from the root of the OID tree, beginning with `/` (e.g.
+    /// `/ISO/Registration_Authority/19785.CBEFF`).
+    /// ```
+    /// use rasn::types::OidIri;
+    ///
+    /// let iri: OidIri = "/Joint-ISO-ITU-T/Example".parse().unwrap();
+    /// assert_eq!("/Joint-ISO-ITU-T/Example", iri.as_str());
+    /// assert!(OidIri::new("Joint-ISO-ITU-T/Example").is_none());
+    /// ```
+    OidIri,
+    |iri: &str| matches!(iri.strip_prefix('/'), Some(arcs) if is_valid_arcs(arcs))
+}
+
+iri! {
+    /// The `RELATIVE-OID-IRI` type, an object identifier written as a path of
+    /// arcs relative to a known node, which unlike [`OidIri`] doesn't begin
+    /// with `/` (e.g. `Registration_Authority/19785.CBEFF`).
+    RelativeOidIri,
+    is_valid_arcs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validation() {
+        for valid in &["/ISO", "/ISO/Registration_Authority/19785.CBEFF"] {
+            assert!(OidIri::new(*valid).is_some(), "{}", valid);
+            assert!(RelativeOidIri::new(*valid).is_none(), "{}", valid);
+        }
+
+        for valid in &["ISO", "Registration_Authority/19785.CBEFF"] {
+            assert!(RelativeOidIri::new(*valid).is_some(), "{}", valid);
+            assert!(OidIri::new(*valid).is_none(), "{}", valid);
+        }
+
+        for invalid in &["", "/", "//ISO", "/ISO/", "ISO//1"] {
+            assert_eq!(Err(InvalidIri), invalid.parse::<OidIri>(), "{}", invalid);
+            assert_eq!(
+                Err(InvalidIri),
+                invalid.parse::<RelativeOidIri>(),
+                "{}",
+                invalid
+            );
+        }
+    }
+}
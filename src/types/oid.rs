@@ -1,6 +1,13 @@
+#[cfg(feature = "oid_registry")]
+mod registry;
+
 use core::ops;
 
+#[cfg(feature = "oid_registry")]
+pub use self::registry::{OidRegistry, WELL_KNOWN_OIDS};
+
 pub(crate) const MAX_OID_FIRST_OCTET: u32 = 2;
+#[cfg(feature = "ber")]
 pub(crate) const MAX_OID_SECOND_OCTET: u32 = 39;
 
 const fn is_valid_oid(slice: &[u32]) -> bool {
@@ -8,9 +15,39 @@ const fn is_valid_oid(slice: &[u32]) -> bool {
 }
 
 /// A temporary workaround for [`Oid`] not currently being `const` compatible.
+///
+/// Use the [`oid!`](crate::oid) macro to create one from its arcs, which are
+/// checked when compiling. A `ConstOid` can be compared with any other object
+/// identifier, such as one that was decoded.
+/// ```
+/// use rasn::types::{ConstOid, ObjectIdentifier};
+///
+/// const SHA256: ConstOid = rasn::oid!(2, 16, 840, 1, 101, 3, 4, 2, 1);
+/// const SHA512: ConstOid = rasn::oid!(2, 16, 840, 1, 101, 3, 4, 2, 3);
+///
+/// let decoded = ObjectIdentifier::new(vec![2, 16, 840, 1, 101, 3, 4, 2, 1]).unwrap();
+/// let digest = match decoded {
+///     oid if oid == SHA256 => "SHA-256",
+///     oid if oid == SHA512 => "SHA-512",
+///     _ => "unknown",
+/// };
+/// assert_eq!("SHA-256", digest);
+/// ```
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct ConstOid(pub &'static [u32]);
 
+impl ConstOid {
+    /// Creates a new object identifier from `arcs`.
+    ///
+    /// # Panics
+    /// If `arcs` contains less than two components or the first component is
+    /// greater than 2. When used in a constant this is a compile error.
+    pub const fn new(arcs: &'static [u32]) -> Self {
+        assert!(is_valid_oid(arcs), "Invalid object identifier");
+        Self(arcs)
+    }
+}
+
 impl AsRef<[u32]> for ConstOid {
     fn as_ref(&self) -> &[u32] {
         self.0
@@ -236,12 +273,42 @@ impl PartialEq<ObjectIdentifier> for ConstOid {
     }
 }
 
+impl PartialEq<ConstOid> for ObjectIdentifier {
+    fn eq(&self, rhs: &ConstOid) -> bool {
+        **self == *rhs
+    }
+}
+
+impl PartialEq<ConstOid> for Oid {
+    fn eq(&self, rhs: &ConstOid) -> bool {
+        self.0 == *rhs.0
+    }
+}
+
 impl PartialEq<[u32]> for ObjectIdentifier {
     fn eq(&self, rhs: &[u32]) -> bool {
         &*self == rhs
     }
 }
 
+/// Creates a [`ConstOid`](crate::types::ConstOid) from a list of arcs,
+/// failing to compile if they aren't a valid object identifier.
+/// ```
+/// const INTERNET: rasn::types::ConstOid = rasn::oid!(1, 3, 6, 1);
+///
+/// assert_eq!(*rasn::types::Oid::new(&[1, 3, 6, 1]).unwrap(), INTERNET);
+/// ```
+/// ```compile_fail
+/// const INVALID: rasn::types::ConstOid = rasn::oid!(3, 1);
+/// ```
+#[macro_export]
+macro_rules! oid {
+    ($($arc:expr),+ $(,)?) => {{
+        const OID: $crate::types::ConstOid = $crate::types::ConstOid::new(&[$($arc),+]);
+        OID
+    }};
+}
+
 macro_rules! oids {
     ($($name:ident => $($num:literal),+ $(,)?);+ $(;)?) => {
         impl Oid {
@@ -620,7 +687,7 @@ oids! {
 
 #[cfg(test)]
 mod test {
-    use super::ObjectIdentifier;
+    use super::*;
 
     #[test]
     fn transmute() {
@@ -630,4 +697,16 @@ mod test {
         oid.reverse();
         assert_eq!([6u32, 3, 1][..], *oid);
     }
+
+    #[test]
+    fn const_oid() {
+        const RSA: ConstOid = crate::oid!(1, 2, 840, 113549, 1, 1, 1);
+        let decoded = ObjectIdentifier::new(alloc::vec![1, 2, 840, 113549, 1, 1, 1]).unwrap();
+
+        assert_eq!(Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA, RSA);
+        assert_eq!(decoded, RSA);
+        assert_eq!(RSA, decoded);
+        assert_eq!(*decoded, RSA);
+        assert_ne!(decoded, Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1);
+    }
 }
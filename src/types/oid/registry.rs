@@ -0,0 +1,223 @@
+use alloc::{borrow::Cow, collections::BTreeMap};
+
+use super::{ConstOid, ObjectIdentifier, Oid};
+
+/// A table of commonly used object identifiers and their names, as they
+/// appear in their respective standards.
+pub const WELL_KNOWN_OIDS: &[(ConstOid, &str)] = &[
+    // PKCS #1
+    (ConstOid(&[1, 2, 840, 113549, 1, 1, 1]), "rsaEncryption"),
+    (
+        ConstOid(&[1, 2, 840, 113549, 1, 1, 4]),
+        "md5WithRSAEncryption",
+    ),
+    (
+        ConstOid(&[1, 2, 840, 113549, 1, 1, 5]),
+        "sha1WithRSAEncryption",
+    ),
+    (ConstOid(&[1, 2, 840, 113549, 1, 1, 7]), "id-RSAES-OAEP"),
+    (ConstOid(&[1, 2, 840, 113549, 1, 1, 10]), "id-RSASSA-PSS"),
+    (
+        ConstOid(&[1, 2, 840, 113549, 1, 1, 11]),
+        "sha256WithRSAEncryption",
+    ),
+    (
+        ConstOid(&[1, 2, 840, 113549, 1, 1, 12]),
+        "sha384WithRSAEncryption",
+    ),
+    (
+        ConstOid(&[1, 2, 840, 113549, 1, 1, 13]),
+        "sha512WithRSAEncryption",
+    ),
+    // PKCS #7 & #9
+    (ConstOid(&[1, 2, 840, 113549, 1, 7, 1]), "data"),
+    (ConstOid(&[1, 2, 840, 113549, 1, 7, 2]), "signedData"),
+    (ConstOid(&[1, 2, 840, 113549, 1, 7, 3]), "envelopedData"),
+    (ConstOid(&[1, 2, 840, 113549, 1, 9, 1]), "emailAddress"),
+    (ConstOid(&[1, 2, 840, 113549, 1, 9, 3]), "contentType"),
+    (ConstOid(&[1, 2, 840, 113549, 1, 9, 4]), "messageDigest"),
+    (ConstOid(&[1, 2, 840, 113549, 1, 9, 5]), "signingTime"),
+    // ANSI X9.62
+    (ConstOid(&[1, 2, 840, 10045, 2, 1]), "id-ecPublicKey"),
+    (ConstOid(&[1, 2, 840, 10045, 3, 1, 7]), "prime256v1"),
+    (ConstOid(&[1, 2, 840, 10045, 4, 1]), "ecdsa-with-SHA1"),
+    (ConstOid(&[1, 2, 840, 10045, 4, 3, 2]), "ecdsa-with-SHA256"),
+    (ConstOid(&[1, 2, 840, 10045, 4, 3, 3]), "ecdsa-with-SHA384"),
+    (ConstOid(&[1, 2, 840, 10045, 4, 3, 4]), "ecdsa-with-SHA512"),
+    (ConstOid(&[1, 3, 132, 0, 34]), "secp384r1"),
+    (ConstOid(&[1, 3, 132, 0, 35]), "secp521r1"),
+    // Hash algorithms
+    (ConstOid(&[1, 3, 14, 3, 2, 26]), "id-sha1"),
+    (ConstOid(&[2, 16, 840, 1, 101, 3, 4, 2, 1]), "id-sha256"),
+    (ConstOid(&[2, 16, 840, 1, 101, 3, 4, 2, 2]), "id-sha384"),
+    (ConstOid(&[2, 16, 840, 1, 101, 3, 4, 2, 3]), "id-sha512"),
+    // RFC 8410
+    (ConstOid(&[1, 3, 101, 110]), "id-X25519"),
+    (ConstOid(&[1, 3, 101, 111]), "id-X448"),
+    (ConstOid(&[1, 3, 101, 112]), "id-Ed25519"),
+    (ConstOid(&[1, 3, 101, 113]), "id-Ed448"),
+    // X.520 attribute types
+    (ConstOid(&[2, 5, 4, 3]), "commonName"),
+    (ConstOid(&[2, 5, 4, 5]), "serialNumber"),
+    (ConstOid(&[2, 5, 4, 6]), "countryName"),
+    (ConstOid(&[2, 5, 4, 7]), "localityName"),
+    (ConstOid(&[2, 5, 4, 8]), "stateOrProvinceName"),
+    (ConstOid(&[2, 5, 4, 10]), "organizationName"),
+    (ConstOid(&[2, 5, 4, 11]), "organizationalUnitName"),
+    (
+        ConstOid(&[0, 9, 2342, 19200300, 100, 1, 25]),
+        "domainComponent",
+    ),
+    // X.509 certificate extensions
+    (ConstOid(&[2, 5, 29, 14]), "subjectKeyIdentifier"),
+    (ConstOid(&[2, 5, 29, 15]), "keyUsage"),
+    (ConstOid(&[2, 5, 29, 17]), "subjectAltName"),
+    (ConstOid(&[2, 5, 29, 18]), "issuerAltName"),
+    (ConstOid(&[2, 5, 29, 19]), "basicConstraints"),
+    (ConstOid(&[2, 5, 29, 30]), "nameConstraints"),
+    (ConstOid(&[2, 5, 29, 31]), "cRLDistributionPoints"),
+    (ConstOid(&[2, 5, 29, 32]), "certificatePolicies"),
+    (ConstOid(&[2, 5, 29, 35]), "authorityKeyIdentifier"),
+    (ConstOid(&[2, 5, 29, 37]), "extKeyUsage"),
+    // PKIX
+    (
+        ConstOid(&[1, 3, 6, 1, 5, 5, 7, 1, 1]),
+        "authorityInfoAccess",
+    ),
+    (ConstOid(&[1, 3, 6, 1, 5, 5, 7, 3, 1]), "serverAuth"),
+    (ConstOid(&[1, 3, 6, 1, 5, 5, 7, 3, 2]), "clientAuth"),
+    (ConstOid(&[1, 3, 6, 1, 5, 5, 7, 3, 3]), "codeSigning"),
+    (ConstOid(&[1, 3, 6, 1, 5, 5, 7, 3, 4]), "emailProtection"),
+    (ConstOid(&[1, 3, 6, 1, 5, 5, 7, 3, 8]), "timeStamping"),
+    (ConstOid(&[1, 3, 6, 1, 5, 5, 7, 3, 9]), "OCSPSigning"),
+    (ConstOid(&[1, 3, 6, 1, 5, 5, 7, 48, 1]), "id-ad-ocsp"),
+    (ConstOid(&[1, 3, 6, 1, 5, 5, 7, 48, 2]), "id-ad-caIssuers"),
+];
+
+impl Oid {
+    /// Returns the name of the object identifier if it's present in
+    /// [`WELL_KNOWN_OIDS`].
+    /// ```
+    /// use rasn::types::Oid;
+    ///
+    /// let oid = Oid::new(&[1, 2, 840, 113549, 1, 1, 11]).unwrap();
+    /// assert_eq!(Some("sha256WithRSAEncryption"), oid.well_known_name());
+    /// ```
+    pub fn well_known_name(&self) -> Option<&'static str> {
+        WELL_KNOWN_OIDS
+            .iter()
+            .find(|(oid, _)| oid == self)
+            .map(|(_, name)| *name)
+    }
+
+    /// Returns the object identifier named `name` in [`WELL_KNOWN_OIDS`].
+    pub fn from_well_known_name(name: &str) -> Option<&'static Oid> {
+        WELL_KNOWN_OIDS
+            .iter()
+            .find(|(_, known)| *known == name)
+            .map(|(oid, _)| Oid::new_unchecked(oid.0))
+    }
+}
+
+/// A registry of object identifier names, containing [`WELL_KNOWN_OIDS`] as
+/// well as any names added at runtime with [`OidRegistry::register`]. Names
+/// registered at runtime take priority over the well-known names.
+#[derive(Debug, Default, Clone)]
+pub struct OidRegistry {
+    names: BTreeMap<ObjectIdentifier, Cow<'static, str>>,
+    oids: BTreeMap<Cow<'static, str>, ObjectIdentifier>,
+}
+
+impl OidRegistry {
+    /// Creates a new registry containing only the well-known names.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Associates `name` with `oid`, replacing any previously registered
+    /// name for `oid`, and moving `name` if it was registered for another
+    /// object identifier.
+    pub fn register(
+        &mut self,
+        oid: impl Into<ObjectIdentifier>,
+        name: impl Into<Cow<'static, str>>,
+    ) {
+        let oid = oid.into();
+        let name = name.into();
+
+        if let Some(previous) = self.names.insert(oid.clone(), name.clone()) {
+            self.oids.remove(&previous);
+        }
+
+        if let Some(previous) = self.oids.insert(name, oid.clone()) {
+            if previous != oid {
+                self.names.remove(&previous);
+            }
+        }
+    }
+
+    /// Returns the name of `oid`, if it is registered or well-known.
+    pub fn name(&self, oid: &Oid) -> Option<&str> {
+        self.names
+            .get(oid)
+            .map(|name| &**name)
+            .or_else(|| oid.well_known_name())
+    }
+
+    /// Returns the object identifier named `name`, if it is registered or
+    /// well-known.
+    pub fn lookup(&self, name: &str) -> Option<&Oid> {
+        self.oids
+            .get(name)
+            .map(|oid| &**oid)
+            .or_else(|| Oid::from_well_known_name(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn well_known() {
+        let oid = ObjectIdentifier::from(ConstOid(&[1, 2, 840, 113549, 1, 1, 11]));
+
+        assert_eq!(Some("sha256WithRSAEncryption"), oid.well_known_name());
+        assert_eq!(
+            Some(&*oid),
+            Oid::from_well_known_name("sha256WithRSAEncryption")
+        );
+        assert_eq!(None, Oid::from_well_known_name("notAnAlgorithm"));
+    }
+
+    #[test]
+    fn registry() {
+        let custom = ConstOid(&[1, 3, 6, 1, 4, 1, 99999, 1]);
+        let mut registry = OidRegistry::new();
+        registry.register(custom, "myCustomOid");
+
+        assert_eq!(
+            Some("myCustomOid"),
+            registry.name(Oid::new(&custom).unwrap())
+        );
+        assert_eq!(
+            Some(&*custom),
+            registry.lookup("myCustomOid").map(|oid| &**oid)
+        );
+        assert_eq!(
+            Some("commonName"),
+            registry.name(Oid::new(&[2, 5, 4, 3]).unwrap())
+        );
+
+        registry.register(custom, "renamed");
+        assert_eq!(None, registry.lookup("myCustomOid"));
+        assert_eq!(Some("renamed"), registry.name(Oid::new(&custom).unwrap()));
+
+        // Registering a name for another object identifier moves it.
+        let other = ConstOid(&[1, 3, 6, 1, 4, 1, 99999, 2]);
+        registry.register(other, "renamed");
+        assert_eq!(None, registry.name(Oid::new(&custom).unwrap()));
+        assert_eq!(Some("renamed"), registry.name(Oid::new(&other).unwrap()));
+        assert_eq!(Some(&*other), registry.lookup("renamed").map(|oid| &**oid));
+    }
+}
@@ -21,3 +21,85 @@ pub enum Open {
     VisibleString(VisibleString),
     InstanceOf(alloc::boxed::Box<InstanceOf<Open>>),
 }
+
+impl Open {
+    /// Returns the tag of the value.
+    pub fn tag(&self) -> Tag {
+        match self {
+            Self::BitString(_) => BitString::TAG,
+            Self::BmpString(_) => BmpString::TAG,
+            Self::Bool(_) => bool::TAG,
+            Self::GeneralizedTime(_) => GeneralizedTime::TAG,
+            Self::Ia5String(_) => Ia5String::TAG,
+            Self::Integer(_) => Integer::TAG,
+            Self::Null => <()>::TAG,
+            Self::ObjectIdentifier(_) => ObjectIdentifier::TAG,
+            Self::OctetString(_) => OctetString::TAG,
+            Self::PrintableString(_) => PrintableString::TAG,
+            Self::UniversalString(_) => UniversalString::TAG,
+            Self::UtcTime(_) => UtcTime::TAG,
+            Self::VisibleString(_) => VisibleString::TAG,
+            Self::InstanceOf(_) => InstanceOf::<Open>::TAG,
+        }
+    }
+
+    /// Whether the value has one of `T`'s tags (any of its variants' for a
+    /// `CHOICE`), and so could be decoded as `T`, without decoding it.
+    pub fn is<T: AsnType>(&self) -> bool {
+        T::TAG_TREE.accepts(self.tag())
+    }
+
+    /// Decodes the value as `T`, by re-encoding it with DER and decoding the
+    /// result. If the value doesn't have one of `T`'s tags, the error is a
+    /// [`MismatchedTag`][crate::ber::de::Error::MismatchedTag] with both tags.
+    /// ```
+    /// use rasn::types::{Integer, Open, Utf8String};
+    ///
+    /// let open = Open::Integer(5.into());
+    /// assert!(open.is::<Integer>());
+    /// assert_eq!(5, open.decode_as::<u8>().unwrap());
+    /// assert_eq!(
+    ///     "Expected Tag { class: Universal, value: 12 } tag, actual tag: Tag { class: Universal, value: 2 }",
+    ///     open.decode_as::<Utf8String>().unwrap_err().to_string(),
+    /// );
+    ///
+    /// // A `CHOICE` matches the tags of all of its variants.
+    /// #[derive(rasn::AsnType, rasn::Decode, rasn::Encode, Debug, PartialEq)]
+    /// #[rasn(choice)]
+    /// enum Number {
+    ///     Integer(Integer),
+    ///     Text(Utf8String),
+    /// }
+    ///
+    /// assert!(open.is::<Number>());
+    /// assert_eq!(Number::Integer(5.into()), open.decode_as::<Number>().unwrap());
+    /// assert!(!Open::Bool(true).is::<Number>());
+    /// ```
+    #[cfg(feature = "der")]
+    pub fn decode_as<T: Decode>(&self) -> Result<T, crate::ber::de::Error> {
+        if !self.is::<T>() {
+            return Err(crate::ber::de::Error::MismatchedTag {
+                expected: T::TAG,
+                actual: self.tag(),
+            });
+        }
+
+        let encoded = crate::der::encode(self).map_err(crate::de::Error::custom)?;
+        crate::der::decode(&encoded)
+    }
+
+    /// Decodes the value as a dynamic [`Value`] tree, by re-encoding it with
+    /// DER and decoding the result, for walking a value such as an
+    /// `INSTANCE OF` without knowing its type.
+    /// ```
+    /// use rasn::types::{Open, Value};
+    ///
+    /// let open = Open::Integer(5.into());
+    /// assert_eq!(Value::Integer(5.into()), open.decode_as_value().unwrap());
+    /// ```
+    #[cfg(feature = "der")]
+    pub fn decode_as_value(&self) -> Result<Value, crate::ber::de::Error> {
+        let encoded = crate::der::encode(self).map_err(crate::de::Error::custom)?;
+        crate::der::decode(&encoded)
+    }
+}
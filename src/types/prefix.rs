@@ -1,4 +1,4 @@
-use crate::{AsnType, Tag};
+use crate::{types::Class, AsnType, Tag};
 
 /// A newtype wrapper that will explicitly tag its value with `T`'s tag.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -62,3 +62,28 @@ impl<T: AsnType, V> AsnType for Implicit<T, V> {
 impl<T: AsnType, V> AsnType for Explicit<T, V> {
     const TAG: Tag = T::TAG;
 }
+
+/// A marker type for the context-specific tag `N`, for use with [`Implicit`]
+/// and [`Explicit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContextSpecific<const N: u32>;
+
+impl<const N: u32> AsnType for ContextSpecific<N> {
+    const TAG: Tag = Tag::new(Class::Context, N);
+}
+
+/// A marker type for the application tag `N`, for use with [`Implicit`] and
+/// [`Explicit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Application<const N: u32>;
+
+impl<const N: u32> AsnType for Application<N> {
+    const TAG: Tag = Tag::new(Class::Application, N);
+}
+
+/// Implicitly tags `V` with the context-specific tag `N`, e.g. `[0] INTEGER`.
+pub type ContextTag<const N: u32, V> = Implicit<ContextSpecific<N>, V>;
+
+/// Implicitly tags `V` with the application tag `N`, e.g.
+/// `[APPLICATION 3] INTEGER`.
+pub type AppTag<const N: u32, V> = Implicit<Application<N>, V>;
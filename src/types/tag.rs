@@ -85,6 +85,7 @@ consts! {
     EMBEDDED_PDV = 11,
     UTF8_STRING = 12,
     RELATIVE_OID = 13,
+    TIME = 14,
     SEQUENCE = 16,
     SET = 17,
     NUMERIC_STRING = 18,
@@ -99,7 +100,13 @@ consts! {
     GENERAL_STRING = 27,
     UNIVERSAL_STRING = 28,
     CHARACTER_STRING = 29,
-    BMP_STRING = 30
+    BMP_STRING = 30,
+    DATE = 31,
+    TIME_OF_DAY = 32,
+    DATE_TIME = 33,
+    DURATION = 34,
+    OID_IRI = 35,
+    RELATIVE_OID_IRI = 36
 }
 
 impl Tag {
@@ -193,6 +200,20 @@ impl TagTree {
         true
     }
 
+    /// Whether `tag` is present in the tree.
+    pub const fn contains(&self, tag: Tag) -> bool {
+        match self {
+            Self::Choice(nodes) => Self::tag_contains(&tag, nodes),
+            Self::Leaf(leaf) => leaf.const_eq(&tag),
+        }
+    }
+
+    /// Whether a value with `tag` could be of a type with this tree, which
+    /// is any tag for an open type's empty `Choice`.
+    pub(crate) const fn accepts(&self, tag: Tag) -> bool {
+        matches!(self, Self::Choice(&[])) || self.contains(tag)
+    }
+
     /// Whether any `Leaf` in `needle` matches any `Leaf`s in `nodes`.
     const fn tree_contains(needle: &TagTree, nodes: &'static [TagTree]) -> bool {
         match needle {
@@ -0,0 +1,53 @@
+use alloc::vec::Vec;
+
+use super::Tag;
+
+/// The components of a `SEQUENCE` that were present in the encoding but
+/// aren't known to the type, such as extension additions from a newer
+/// version of a specification. Each component is stored as its tag and its
+/// complete encoding, and is re-encoded unchanged in the order it was
+/// decoded.
+///
+/// Use `#[rasn(unknown_fields)]` on the last field of a derived `SEQUENCE`
+/// to collect any trailing components that aren't otherwise decoded.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UnknownFields(Vec<(Tag, Vec<u8>)>);
+
+impl UnknownFields {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a component with `tag` and its complete encoding.
+    pub fn push(&mut self, tag: Tag, encoded: Vec<u8>) {
+        self.0.push((tag, encoded));
+    }
+
+    /// Iterates over the tag and encoding of each component, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (Tag, &[u8])> + '_ {
+        self.0.iter().map(|(tag, encoded)| (*tag, &**encoded))
+    }
+
+    /// The number of unknown components.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there were no unknown components.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Vec<(Tag, Vec<u8>)>> for UnknownFields {
+    fn from(fields: Vec<(Tag, Vec<u8>)>) -> Self {
+        Self(fields)
+    }
+}
+
+impl From<UnknownFields> for Vec<(Tag, Vec<u8>)> {
+    fn from(fields: UnknownFields) -> Self {
+        fields.0
+    }
+}
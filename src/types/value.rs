@@ -0,0 +1,120 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use super::*;
+
+/// A dynamically typed ASN.1 value, for when the structure of the data isn't
+/// known at compile-time. Decoding a [`Value`] succeeds for any valid
+/// encoding, and produces a full tree of the data.
+///
+/// Types that aren't represented by a specific variant are kept as their
+/// tag and encoded contents, so re-encoding a decoded value with the same
+/// encoding rules produces the same output for DER encoded data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// A `BOOLEAN` value.
+    Bool(bool),
+    /// An `INTEGER` value.
+    Integer(Integer),
+    /// An `ENUMERATED` value.
+    Enumerated(isize),
+    /// A `BIT STRING` value.
+    BitString(BitString),
+    /// An `OCTET STRING` value.
+    OctetString(OctetString),
+    /// The `NULL` value.
+    Null,
+    /// An `OBJECT IDENTIFIER` value.
+    ObjectIdentifier(ObjectIdentifier),
+    /// A `UTF8String` value.
+    Utf8String(Utf8String),
+    /// A `UTCTime` value.
+    UtcTime(UtcTime),
+    /// A `GeneralizedTime` value.
+    GeneralizedTime(GeneralizedTime),
+    /// A `SEQUENCE` or `SEQUENCE OF` value.
+    Sequence(Vec<Value>),
+    /// A `SET` or `SET OF` value, in the order it was encoded.
+    Set(Vec<Value>),
+    /// A constructed, non-universal value containing a single value, such as
+    /// an explicitly tagged value.
+    Tagged(Tag, Box<Value>),
+    /// Any other constructed value.
+    Constructed(Tag, Vec<Value>),
+    /// Any other primitive value, containing its encoded contents.
+    Primitive(Tag, OctetString),
+}
+
+impl Value {
+    /// The tag of the value.
+    pub fn tag(&self) -> Tag {
+        match self {
+            Self::Bool(_) => Tag::BOOL,
+            Self::Integer(_) => Tag::INTEGER,
+            Self::Enumerated(_) => Tag::ENUMERATED,
+            Self::BitString(_) => Tag::BIT_STRING,
+            Self::OctetString(_) => Tag::OCTET_STRING,
+            Self::Null => Tag::NULL,
+            Self::ObjectIdentifier(_) => Tag::OBJECT_IDENTIFIER,
+            Self::Utf8String(_) => Tag::UTF8_STRING,
+            Self::UtcTime(_) => Tag::UTC_TIME,
+            Self::GeneralizedTime(_) => Tag::GENERALIZED_TIME,
+            Self::Sequence(_) => Tag::SEQUENCE,
+            Self::Set(_) => Tag::SET,
+            Self::Tagged(tag, _) | Self::Constructed(tag, _) | Self::Primitive(tag, _) => *tag,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "der"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_tree() {
+        let data = &[
+            0x30, 0x0C, 0x02, 0x01, 0x05, 0xA0, 0x03, 0x01, 0x01, 0xFF, 0x80, 0x02, 0xAB, 0xCD,
+        ];
+
+        let value: Value = crate::der::decode(data).unwrap();
+
+        assert_eq!(
+            Value::Sequence(alloc::vec![
+                Value::Integer(5.into()),
+                Value::Tagged(Tag::new(Class::Context, 0), Box::new(Value::Bool(true))),
+                Value::Primitive(
+                    Tag::new(Class::Context, 0),
+                    OctetString::from_static(&[0xAB, 0xCD])
+                ),
+            ]),
+            value
+        );
+        assert_eq!(data, &*crate::der::encode(&value).unwrap());
+    }
+
+    #[test]
+    fn bit_string_is_byte_stable() {
+        let data = &[0x03, 0x03, 0x04, 0x80, 0x00];
+
+        let value: Value = crate::der::decode(data).unwrap();
+
+        assert!(matches!(&value, Value::BitString(bits) if bits.len() == 12));
+        assert_eq!(data, &*crate::der::encode(&value).unwrap());
+    }
+
+    #[cfg(feature = "cer")]
+    #[test]
+    fn long_values_in_cer() {
+        // Only strings are split into segments in CER, and each segment has
+        // the string's own universal tag.
+        let bits = Value::BitString(crate::types::BitString::repeat(true, 1001 * 8));
+        let encoded = crate::cer::encode(&bits).unwrap();
+        assert_eq!(&[0x23, 0x80, 0x03, 0x82, 0x03, 0xE8, 0x00], &encoded[..7]);
+        assert_eq!(bits, crate::cer::decode(&encoded).unwrap());
+
+        let contents = alloc::vec![0xAB; 1001];
+        let primitive = Value::Primitive(Tag::new(Class::Context, 0), contents.into());
+        let encoded = crate::cer::encode(&primitive).unwrap();
+        assert_eq!(&[0x80, 0x82, 0x03, 0xE9, 0xAB], &encoded[..5]);
+        assert_eq!(primitive, crate::cer::decode(&encoded).unwrap());
+    }
+}
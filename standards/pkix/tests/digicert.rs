@@ -12,6 +12,15 @@ fn it_works() {
     assert_eq!(contents.contents, rasn::der::encode(&cert).unwrap());
 }
 
+#[test]
+fn dynamic_value() {
+    let contents = pem::parse(include_bytes!("data/DigiCertAssuredIDTLSCA.crt.pem")).unwrap();
+
+    let value: rasn::types::Value = rasn::der::decode(&contents.contents).unwrap();
+
+    assert_eq!(contents.contents, rasn::der::encode(&value).unwrap());
+}
+
 #[test]
 fn extensions() {
     let basic_usage = rasn::der::encode_scope(|encoder| {
@@ -0,0 +1,121 @@
+#![cfg(feature = "borrowed_decoder")]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use rasn::{
+    ber::de::{BorrowedDecoder, DecoderOptions},
+    types::Tag,
+};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+// Only one test is run in this file, so that no other thread can allocate
+// while the test is checking the allocation count.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(rasn::AsnType, rasn::Encode)]
+struct Reading {
+    id: i64,
+    payload: rasn::types::OctetString,
+}
+
+#[test]
+fn round_trip_without_allocating() {
+    let encoded = rasn::der::encode(&Reading {
+        id: -129,
+        payload: rasn::types::OctetString::from_static(&[1, 2, 3, 4]),
+    })
+    .unwrap();
+
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+
+    let mut decoder = BorrowedDecoder::new(&encoded, DecoderOptions::der());
+    let mut sequence = decoder.decode_sequence(Tag::SEQUENCE).unwrap();
+    let id = sequence.decode_i64(Tag::INTEGER).unwrap();
+    let payload = sequence
+        .decode_octet_string_borrowed(Tag::OCTET_STRING)
+        .unwrap();
+    sequence.finish().unwrap();
+
+    assert_eq!(before, ALLOCATIONS.load(Ordering::SeqCst));
+    assert!(decoder.is_empty());
+    assert_eq!(-129, id);
+    assert_eq!(&[1, 2, 3, 4], payload);
+
+    let tags: Vec<_> = BorrowedDecoder::new(&encoded, DecoderOptions::der())
+        .decode_sequence(Tag::SEQUENCE)
+        .unwrap()
+        .map(|field| field.unwrap().0.tag)
+        .collect();
+
+    assert_eq!(vec![Tag::INTEGER, Tag::OCTET_STRING], tags);
+
+    #[cfg(feature = "heapless")]
+    {
+        type Readings = heapless::Vec<heapless::String<8>, 2>;
+
+        let readings: Readings = ["north", "south"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let encoded = rasn::der::encode(&readings).unwrap();
+        assert_eq!(readings, rasn::der::decode::<Readings>(&encoded).unwrap());
+        assert!(rasn::der::decode::<heapless::Vec<heapless::String<8>, 1>>(&encoded).is_err());
+        assert!(rasn::der::decode::<heapless::Vec<heapless::String<4>, 2>>(&encoded).is_err());
+
+        let before = ALLOCATIONS.load(Ordering::SeqCst);
+
+        let decoded: Readings = BorrowedDecoder::new(&encoded, DecoderOptions::der())
+            .decode_heapless_vec(Tag::SEQUENCE, |decoder| {
+                decoder.decode_heapless_string(Tag::UTF8_STRING)
+            })
+            .unwrap();
+
+        assert_eq!(before, ALLOCATIONS.load(Ordering::SeqCst));
+        assert_eq!(readings, decoded);
+        assert!(matches!(
+            BorrowedDecoder::new(&encoded, DecoderOptions::der())
+                .decode_heapless_vec::<_, 1>(Tag::SEQUENCE, |decoder| {
+                    decoder.decode_heapless_string::<8>(Tag::UTF8_STRING)
+                }),
+            Err(rasn::ber::de::Error::ExceedsMaxLength { length: 1 })
+        ));
+    }
+
+    #[cfg(feature = "arrayvec")]
+    {
+        let encoded =
+            rasn::der::encode(&arrayvec::ArrayString::<8>::from("north").unwrap()).unwrap();
+
+        let before = ALLOCATIONS.load(Ordering::SeqCst);
+
+        let decoded = BorrowedDecoder::new(&encoded, DecoderOptions::der())
+            .decode_array_string::<8>(Tag::UTF8_STRING)
+            .unwrap();
+
+        assert_eq!(before, ALLOCATIONS.load(Ordering::SeqCst));
+        assert_eq!("north", &*decoded);
+        assert!(matches!(
+            BorrowedDecoder::new(&encoded, DecoderOptions::der())
+                .decode_array_string::<4>(Tag::UTF8_STRING),
+            Err(rasn::ber::de::Error::ExceedsMaxLength { length: 4 })
+        ));
+    }
+}
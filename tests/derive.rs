@@ -19,6 +19,154 @@ fn enumerated() {
     assert_eq!(drei, ber::decode(&ber::encode(&drei).unwrap()).unwrap());
 }
 
+#[test]
+fn enumerated_discriminants() {
+    #[derive(AsnType, Clone, Copy, Debug, Encode, Decode, PartialEq)]
+    #[rasn(enumerated)]
+    enum Offset {
+        Behind = -2,
+        Level = 0,
+        Ahead = 7,
+    }
+
+    assert_eq!(
+        &[0x0A, 0x01, 0xFE][..],
+        &*der::encode(&Offset::Behind).unwrap()
+    );
+    assert_eq!(Offset::Behind, der::decode(&[0x0A, 0x01, 0xFE]).unwrap());
+    assert_eq!(Offset::Level, der::decode(&[0x0A, 0x01, 0x00]).unwrap());
+    assert_eq!(Offset::Ahead, der::decode(&[0x0A, 0x01, 0x07]).unwrap());
+    assert!(der::decode::<Offset>(&[0x0A, 0x01, 0x01]).is_err());
+    assert!(der::decode::<Offset>(&[0x0A, 0x01, 0xFF]).is_err());
+
+    let mut decoder = ber::de::Decoder::new(&[0x0A, 0x01, 0xFE], ber::de::DecoderOptions::der());
+    assert_eq!(
+        1,
+        Decoder::decode_enumerated_in(&mut decoder, Tag::ENUMERATED, &[7, -2]).unwrap()
+    );
+}
+
+#[test]
+fn enumerated_repr() {
+    #[derive(AsnType, Clone, Copy, Debug, Encode, Decode, PartialEq)]
+    #[rasn(enumerated)]
+    #[repr(u8)]
+    enum Status {
+        Ok = 0,
+        Fail = 1,
+        Retry = 200,
+    }
+
+    assert_eq!(
+        &[0x0A, 0x01, 0x01][..],
+        &*der::encode(&Status::Fail).unwrap()
+    );
+    assert_eq!(
+        &[0x0A, 0x02, 0x00, 0xC8][..],
+        &*der::encode(&Status::Retry).unwrap()
+    );
+    assert_eq!(Status::Ok, der::decode(&[0x0A, 0x01, 0x00]).unwrap());
+    assert_eq!(
+        Status::Retry,
+        der::decode(&[0x0A, 0x02, 0x00, 0xC8]).unwrap()
+    );
+    assert!(der::decode::<Status>(&[0x0A, 0x01, 0x02]).is_err());
+    // 200 as an `i8` rather than a `u8`.
+    assert!(der::decode::<Status>(&[0x0A, 0x01, 0xC8]).is_err());
+}
+
+#[test]
+fn enumerated_and_integer_tags() {
+    #[derive(AsnType, Clone, Copy, Debug, Encode, Decode, PartialEq)]
+    #[rasn(enumerated)]
+    enum Kind {
+        Leaf = 3,
+        Branch = 5,
+    }
+
+    #[derive(AsnType, Debug, Encode, Decode, PartialEq)]
+    struct Entry {
+        kind: Kind,
+        count: i32,
+    }
+
+    let entry = Entry {
+        kind: Kind::Branch,
+        count: 5,
+    };
+    let encoded = ber::encode(&entry).unwrap();
+
+    assert_eq!(
+        &[0x30, 0x06, 0x0A, 0x01, 0x05, 0x02, 0x01, 0x05][..],
+        &*encoded
+    );
+    assert_eq!(entry, ber::decode(&encoded).unwrap());
+    // An `INTEGER` isn't accepted in place of an `ENUMERATED`, or vice versa.
+    assert!(ber::decode::<Kind>(&[0x02, 0x01, 0x05]).is_err());
+    assert!(ber::decode::<i32>(&[0x0A, 0x01, 0x05]).is_err());
+}
+
+#[test]
+fn integer_named_values() {
+    #[derive(AsnType, Clone, Copy, Debug, Encode, Decode, PartialEq)]
+    #[rasn(integer)]
+    #[repr(i64)]
+    enum Version {
+        V1 = 0,
+        V2 = 1,
+        V3 = 2,
+        Other(i64),
+    }
+
+    #[derive(AsnType, Debug, Encode, Decode, PartialEq)]
+    struct Record {
+        #[rasn(tag(explicit(0)))]
+        version: Version,
+    }
+
+    assert_eq!(Version::V1, der::decode(&[0x02, 0x01, 0x00]).unwrap());
+    assert_eq!(Version::V3, der::decode(&[0x02, 0x01, 0x02]).unwrap());
+    assert_eq!(
+        &[0x02, 0x01, 0x01][..],
+        &*der::encode(&Version::V2).unwrap()
+    );
+
+    // Unlike an enumerated, any other value is accepted.
+    assert_eq!(Version::Other(7), der::decode(&[0x02, 0x01, 0x07]).unwrap());
+    assert_eq!(
+        Version::Other(-1),
+        der::decode(&[0x02, 0x01, 0xFF]).unwrap()
+    );
+    assert_eq!(
+        &[0x02, 0x01, 0x07][..],
+        &*der::encode(&Version::Other(7)).unwrap()
+    );
+    assert!(der::decode::<Version>(&[0x0A, 0x01, 0x00]).is_err());
+
+    let record = Record {
+        version: Version::V3,
+    };
+    let encoded = der::encode(&record).unwrap();
+    assert_eq!(&[0x30, 0x05, 0xA0, 0x03, 0x02, 0x01, 0x02][..], &*encoded);
+    assert_eq!(record, der::decode(&encoded).unwrap());
+
+    // Without a catch-all, other values fail to decode.
+    #[derive(AsnType, Clone, Copy, Debug, Encode, Decode, PartialEq)]
+    #[rasn(integer)]
+    enum Strict {
+        Low = 1,
+        High = 10,
+    }
+
+    assert_eq!(Strict::High, der::decode(&[0x02, 0x01, 0x0A]).unwrap());
+    assert_eq!(
+        "Integer `2` isn't a named value of `Strict`.",
+        der::decode::<Strict>(&[0x02, 0x01, 0x02])
+            .unwrap_err()
+            .to_string()
+    );
+}
+
 #[test]
 fn choice() {
     #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
@@ -62,6 +210,116 @@ fn choice() {
     assert_eq!(bar, ber::decode(&ber::encode(&bar).unwrap()).unwrap());
 }
 
+#[test]
+fn choice_in_sequence() {
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+    #[rasn(choice)]
+    enum Id {
+        Number(u8),
+        Name(Utf8String),
+    }
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+    struct Record {
+        id: Id,
+        #[rasn(tag(0))]
+        alias: Id,
+    }
+
+    let record = Record {
+        id: Id::Number(5),
+        alias: Id::Name("a".into()),
+    };
+    let encoded = der::encode(&record).unwrap();
+
+    // The tagged CHOICE is wrapped in an explicit tag, instead of replacing
+    // the tag of the chosen variant.
+    assert_eq!(
+        &[0x30, 0x08, 0x02, 0x01, 0x05, 0xA0, 0x03, 0x0C, 0x01, b'a'][..],
+        &*encoded
+    );
+    assert_eq!(record, der::decode(&encoded).unwrap());
+    assert!(der::decode::<Record>(&[0x30, 0x06, 0x02, 0x01, 0x05, 0x80, 0x01, b'a']).is_err());
+}
+
+#[test]
+fn tagged_choice_fields_are_explicit() {
+    /// A hand written `CHOICE` that doesn't treat tags as explicit itself.
+    #[derive(Debug, PartialEq)]
+    struct Flag(bool);
+
+    impl AsnType for Flag {
+        const TAG: Tag = Tag::CHOICE;
+        const TAG_TREE: TagTree = TagTree::Choice(&[TagTree::Leaf(Tag::BOOL)]);
+    }
+
+    impl Decode for Flag {
+        fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error> {
+            bool::decode(decoder).map(Self)
+        }
+
+        fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+            bool::decode_with_tag(decoder, tag).map(Self)
+        }
+    }
+
+    impl Encode for Flag {
+        fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
+            self.0.encode(encoder)
+        }
+
+        fn encode_with_tag<E: Encoder>(&self, encoder: &mut E, tag: Tag) -> Result<(), E::Error> {
+            self.0.encode_with_tag(encoder, tag)
+        }
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Tagged {
+        #[rasn(tag(0))]
+        flag: Flag,
+        #[rasn(tag(1))]
+        optional: Option<Flag>,
+        #[rasn(tag(2))]
+        number: u8,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(automatic_tags)]
+    struct Automatic {
+        flag: Flag,
+        number: u8,
+    }
+
+    let tagged = Tagged {
+        flag: Flag(true),
+        optional: None,
+        number: 5,
+    };
+    let encoded = der::encode(&tagged).unwrap();
+    assert_eq!(
+        &[0x30, 0x08, 0xA0, 0x03, 0x01, 0x01, 0xFF, 0x82, 0x01, 0x05][..],
+        &*encoded
+    );
+    assert_eq!(tagged, der::decode(&encoded).unwrap());
+
+    let tagged = Tagged {
+        optional: Some(Flag(false)),
+        ..tagged
+    };
+    assert_eq!(tagged, der::decode(&der::encode(&tagged).unwrap()).unwrap());
+
+    let automatic = Automatic {
+        flag: Flag(false),
+        number: 5,
+    };
+    let encoded = der::encode(&automatic).unwrap();
+    assert_eq!(
+        &[0x30, 0x08, 0xA0, 0x03, 0x01, 0x01, 0x00, 0x81, 0x01, 0x05][..],
+        &*encoded
+    );
+    assert_eq!(automatic, der::decode(&encoded).unwrap());
+}
+
 #[test]
 fn sequence() {
     #[derive(AsnType, Debug, Default, Decode, Encode, PartialEq)]
@@ -141,3 +399,396 @@ pub enum ExplicitChoice {
     #[rasn(tag(explicit(2)))]
     ByKey,
 }
+
+#[test]
+fn sequence_component_order() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Optionals {
+        #[rasn(tag(0))]
+        first: Option<bool>,
+        #[rasn(tag(1))]
+        second: Option<bool>,
+    }
+
+    let in_order = &[0x30, 0x06, 0x80, 0x01, 0xFF, 0x81, 0x01, 0x00];
+    let out_of_order = &[0x30, 0x06, 0x81, 0x01, 0x00, 0x80, 0x01, 0xFF];
+    let expected = Optionals {
+        first: Some(true),
+        second: Some(false),
+    };
+
+    assert_eq!(expected, der::decode(in_order).unwrap());
+    assert!(matches!(
+        der::decode::<Optionals>(out_of_order),
+        Err(ber::de::Error::OutOfOrder { .. })
+    ));
+    assert!(ber::decode::<Optionals>(out_of_order).is_err());
+}
+
+#[test]
+fn recursive() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[allow(clippy::vec_box)]
+    struct Node {
+        value: u8,
+        children: Vec<Box<Node>>,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Shared {
+        node: std::rc::Rc<Node>,
+        other: std::sync::Arc<Node>,
+    }
+
+    let leaf = |value| {
+        Box::new(Node {
+            value,
+            children: Vec::new(),
+        })
+    };
+    let tree = Node {
+        value: 0,
+        children: vec![
+            leaf(1),
+            Box::new(Node {
+                value: 2,
+                children: vec![leaf(3)],
+            }),
+        ],
+    };
+
+    let encoded = der::encode(&tree).unwrap();
+    assert_eq!(tree, der::decode(&encoded).unwrap());
+
+    let shared = Shared {
+        node: (*leaf(4)).into(),
+        other: (*leaf(5)).into(),
+    };
+    assert_eq!(shared, der::decode(&der::encode(&shared).unwrap()).unwrap());
+}
+
+#[test]
+fn error_context() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Certificate {
+        version: u8,
+        extension: Extension,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Extension {
+        critical: bool,
+    }
+
+    let invalid = &[0x30, 0x08, 0x02, 0x01, 0x02, 0x30, 0x03, 0x01, 0x01, 0x05];
+    let error = der::decode::<Certificate>(invalid).unwrap_err();
+
+    assert_eq!(
+        "Certificate.extension > Extension.critical: BOOL value is not `0` or `0xFF`.",
+        error.to_string()
+    );
+    assert!(matches!(
+        error,
+        ber::de::Error::Context { ref path, ref error }
+            if path == &["Extension.critical", "Certificate.extension"]
+                && matches!(**error, ber::de::Error::InvalidBool)
+    ));
+}
+
+#[test]
+fn named_bits() {
+    #[derive(AsnType, Debug, Default, Decode, Encode, PartialEq)]
+    #[rasn(named_bits)]
+    struct KeyUsage {
+        digital_signature: bool,
+        non_repudiation: bool,
+        key_encipherment: bool,
+        #[rasn(bit = 5)]
+        key_cert_sign: bool,
+        crl_sign: bool,
+        #[rasn(bit = 8)]
+        decipher_only: bool,
+    }
+
+    let usage = KeyUsage {
+        digital_signature: true,
+        key_cert_sign: true,
+        crl_sign: true,
+        ..<_>::default()
+    };
+    let encoded = der::encode(&usage).unwrap();
+
+    assert_eq!(&[0x03, 0x02, 0x01, 0x86][..], &*encoded);
+    assert_eq!(usage, der::decode(&encoded).unwrap());
+    assert_eq!(
+        KeyUsage::default(),
+        der::decode(&der::encode(&KeyUsage::default()).unwrap()).unwrap()
+    );
+
+    let decipher_only = KeyUsage {
+        decipher_only: true,
+        ..<_>::default()
+    };
+    assert_eq!(
+        &[0x03, 0x03, 0x07, 0x00, 0x80][..],
+        &*der::encode(&decipher_only).unwrap()
+    );
+    assert_eq!(
+        decipher_only,
+        der::decode(&[0x03, 0x03, 0x07, 0x00, 0x80]).unwrap()
+    );
+}
+
+#[test]
+fn constrained_optional_fields() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Dial {
+        #[rasn(value("0..=9"))]
+        digit: Option<u8>,
+        #[rasn(size("1..=4"))]
+        label: Option<Box<OctetString>>,
+    }
+
+    let present = Dial {
+        digit: Some(7),
+        label: Some(Box::new(OctetString::from_static(b"dial"))),
+    };
+    let encoded = der::encode(&present).unwrap();
+    assert_eq!(present, der::decode(&encoded).unwrap());
+
+    let absent = Dial {
+        digit: None,
+        label: None,
+    };
+    assert_eq!(&[0x30, 0x00][..], &*der::encode(&absent).unwrap());
+    assert_eq!(absent, der::decode(&[0x30, 0x00]).unwrap());
+
+    let out_of_range = &[0x30, 0x03, 0x02, 0x01, 0x0A];
+    assert_eq!(
+        "Dial.digit: Field is outside of the value constraint `0..=9`",
+        der::decode::<Dial>(out_of_range).unwrap_err().to_string()
+    );
+    assert!(der::encode(&Dial {
+        digit: Some(10),
+        label: None,
+    })
+    .is_err());
+    assert!(der::decode::<Dial>(&[0x30, 0x07, 0x04, 0x05, 0x01, 0x02, 0x03, 0x04, 0x05]).is_err());
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(set)]
+    struct DialSet {
+        #[rasn(value("0..=9"))]
+        digit: Option<u8>,
+    }
+
+    assert_eq!(
+        DialSet { digit: Some(9) },
+        der::decode(&[0x31, 0x03, 0x02, 0x01, 0x09]).unwrap()
+    );
+    assert!(der::decode::<DialSet>(&[0x31, 0x03, 0x02, 0x01, 0x0A]).is_err());
+}
+
+#[test]
+fn optional_null() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Request {
+        flag: Option<()>,
+        count: u8,
+    }
+
+    let present = Request {
+        flag: Some(()),
+        count: 1,
+    };
+    let present_encoded = &[0x30, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01];
+    assert_eq!(present_encoded, &*der::encode(&present).unwrap());
+    assert_eq!(present, der::decode(present_encoded).unwrap());
+
+    let absent = Request {
+        flag: None,
+        count: 1,
+    };
+    let absent_encoded = &[0x30, 0x03, 0x02, 0x01, 0x01];
+    assert_eq!(absent_encoded, &*der::encode(&absent).unwrap());
+    assert_eq!(absent, der::decode(absent_encoded).unwrap());
+
+    let malformed = &[0x30, 0x06, 0x05, 0x01, 0x00, 0x02, 0x01, 0x01];
+    assert!(der::decode::<Request>(malformed).is_err());
+}
+
+#[test]
+fn tagged_optional_fields_are_distinct() {
+    // Without the tags `min` and `max` could have the same tag as each
+    // other, which is a compile time error naming both fields.
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Reading {
+        #[rasn(tag(0))]
+        min: Option<u8>,
+        #[rasn(tag(1))]
+        max: Option<u8>,
+        value: u8,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(set)]
+    struct Range {
+        #[rasn(tag(0))]
+        start: u8,
+        #[rasn(tag(1))]
+        end: u8,
+    }
+
+    let reading = Reading {
+        min: None,
+        max: Some(9),
+        value: 5,
+    };
+    let encoded = der::encode(&reading).unwrap();
+    assert_eq!(
+        &[0x30, 0x06, 0x81, 0x01, 0x09, 0x02, 0x01, 0x05][..],
+        &*encoded
+    );
+    assert_eq!(reading, der::decode(&encoded).unwrap());
+
+    let range = Range { start: 1, end: 2 };
+    assert_eq!(range, der::decode(&der::encode(&range).unwrap()).unwrap());
+}
+
+#[test]
+fn optional_field_with_the_same_tag_as_the_next() {
+    // `offset` and `value` are both `INTEGER`s, so whether `offset` is
+    // present depends on whether there's an `INTEGER` left for `value`.
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Sample {
+        offset: Option<i32>,
+        value: i32,
+        flag: bool,
+    }
+
+    let absent = Sample {
+        offset: None,
+        value: 5,
+        flag: true,
+    };
+    let absent_encoded = &[0x30, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF];
+    assert_eq!(absent_encoded, &*der::encode(&absent).unwrap());
+    assert_eq!(absent, der::decode(absent_encoded).unwrap());
+
+    let present = Sample {
+        offset: Some(1),
+        value: 5,
+        flag: true,
+    };
+    let present_encoded = &[
+        0x30, 0x09, 0x02, 0x01, 0x01, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF,
+    ];
+    assert_eq!(present_encoded, &*der::encode(&present).unwrap());
+    assert_eq!(present, der::decode(present_encoded).unwrap());
+    assert_eq!(
+        present,
+        ber::decode(&[
+            0x30, 0x80, 0x02, 0x01, 0x01, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF, 0x00, 0x00
+        ])
+        .unwrap()
+    );
+
+    // Without a value for `value` after it, the `INTEGER` is `value`.
+    assert!(der::decode::<Sample>(&[0x30, 0x06, 0x02, 0x01, 0x05, 0x02, 0x01, 0x06]).is_err());
+}
+
+#[test]
+fn empty_sequence() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Empty;
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Braces {}
+
+    assert_eq!(Tag::SEQUENCE, Empty::TAG);
+    assert_eq!(&[0x30, 0x00][..], &*der::encode(&Empty).unwrap());
+    assert_eq!(Empty, der::decode(&[0x30, 0x00]).unwrap());
+    assert_eq!(Empty, ber::decode(&[0x30, 0x80, 0x00, 0x00]).unwrap());
+    assert!(der::decode::<Empty>(&der::encode(&()).unwrap()).is_err());
+    assert!(der::decode::<Empty>(&[0x30, 0x03, 0x02, 0x01, 0x01]).is_err());
+
+    assert_eq!(&[0x30, 0x00][..], &*der::encode(&Braces {}).unwrap());
+    assert_eq!(Braces {}, der::decode(&[0x30, 0x00]).unwrap());
+}
+
+#[test]
+fn mandatory_optional_and_default_fields() {
+    // `note` is `OPTIONAL`, as it's an `Option`, and `retries` is `DEFAULT`,
+    // so each is left out when it's `None` or the default respectively.
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Job {
+        id: u8,
+        #[rasn(tag(0))]
+        note: Option<Utf8String>,
+        #[rasn(tag(1), default)]
+        retries: u8,
+    }
+
+    let minimal = Job {
+        id: 7,
+        note: None,
+        retries: 0,
+    };
+    let encoded = der::encode(&minimal).unwrap();
+    assert_eq!(&[0x30, 0x03, 0x02, 0x01, 0x07][..], &*encoded);
+    assert_eq!(minimal, der::decode(&encoded).unwrap());
+
+    let full = Job {
+        id: 7,
+        note: Some("hi".into()),
+        retries: 3,
+    };
+    let encoded = der::encode(&full).unwrap();
+    assert_eq!(
+        &[0x30, 0x0A, 0x02, 0x01, 0x07, 0x80, 0x02, b'h', b'i', 0x81, 0x01, 0x03][..],
+        &*encoded
+    );
+    assert_eq!(full, der::decode(&encoded).unwrap());
+
+    assert!(der::decode::<Job>(&[0x30, 0x03, 0x80, 0x01, b'h']).is_err());
+}
+
+#[test]
+fn missing_trailing_optional_fields() {
+    // Version 2 appended `expires` and `issuer` to version 1's `serial`.
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Ticket {
+        serial: u8,
+        #[rasn(tag(0))]
+        expires: Option<u32>,
+        #[rasn(tag(1))]
+        issuer: Option<Utf8String>,
+    }
+
+    let version_1 = Ticket {
+        serial: 5,
+        expires: None,
+        issuer: None,
+    };
+    assert_eq!(
+        version_1,
+        der::decode(&[0x30, 0x03, 0x02, 0x01, 0x05]).unwrap()
+    );
+    assert_eq!(
+        version_1,
+        ber::decode(&[0x30, 0x80, 0x02, 0x01, 0x05, 0x00, 0x00]).unwrap()
+    );
+
+    let version_2 = Ticket {
+        serial: 5,
+        expires: None,
+        issuer: Some("CA".into()),
+    };
+    assert_eq!(
+        version_2,
+        der::decode(&[0x30, 0x07, 0x02, 0x01, 0x05, 0x81, 0x02, b'C', b'A']).unwrap()
+    );
+
+    // Values that aren't one of the fields are still an error.
+    assert!(der::decode::<Ticket>(&[0x30, 0x06, 0x02, 0x01, 0x05, 0x82, 0x01, 0x00]).is_err());
+}
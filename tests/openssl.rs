@@ -0,0 +1,223 @@
+//! Decodes and re-encodes DER generated by OpenSSL, which has to produce the
+//! exact same bytes.
+//!
+//! The files in `tests/data/openssl` were generated with OpenSSL 3.0:
+//!
+//! - `ca.crt.pem`: a self-signed RSA CA certificate.
+//! - `leaf.crt.pem`: an EC certificate issued by the CA, with a serial whose
+//!   top bit is set and a subject alternative name.
+//! - `ca.crl.pem`: a CRL from the CA that revokes the leaf certificate.
+//! - `signed.cms.pem`: a CMS signed message with its content and both
+//!   certificates, from `openssl cms -sign -nodetach`.
+//! - `detached.cms.der`: a CMS signature without its content, from
+//!   `openssl cms -sign -outform DER`.
+
+use rasn::{types::Value, Decode, Encode};
+
+const CA_CERTIFICATE: &[u8] = include_bytes!("data/openssl/ca.crt.pem");
+const LEAF_CERTIFICATE: &[u8] = include_bytes!("data/openssl/leaf.crt.pem");
+const CRL: &[u8] = include_bytes!("data/openssl/ca.crl.pem");
+const SIGNED_CMS: &[u8] = include_bytes!("data/openssl/signed.cms.pem");
+const DETACHED_CMS: &[u8] = include_bytes!("data/openssl/detached.cms.der");
+
+/// Returns the DER inside a PEM file, checking the label of its wrapper
+/// (e.g. `CERTIFICATE` for `-----BEGIN CERTIFICATE-----`).
+fn load_pem(pem: &[u8], label: &str) -> Vec<u8> {
+    let pem = pem::parse(pem).expect("Invalid PEM");
+    assert_eq!(label, pem.tag, "Unexpected PEM label");
+    pem.contents
+}
+
+/// Decodes `der` as a `T` and as a dynamic `Value`, and checks that both
+/// are encoded to the same bytes, returning the decoded `T`.
+#[track_caller]
+fn assert_round_trip<T: Decode + Encode>(der: &[u8]) -> T {
+    let value: T = rasn::der::decode(der).expect("Failed to decode");
+    assert_same_der(der, &rasn::der::encode(&value).unwrap());
+
+    let dynamic: Value = rasn::der::decode(der).expect("Failed to decode as a `Value`");
+    assert_same_der(der, &rasn::der::encode(&dynamic).unwrap());
+
+    value
+}
+
+#[track_caller]
+fn assert_same_der(expected: &[u8], actual: &[u8]) {
+    if expected == actual {
+        return;
+    }
+
+    let offset = expected
+        .iter()
+        .zip(actual)
+        .position(|(expected, actual)| expected != actual)
+        .unwrap_or_else(|| expected.len().min(actual.len()));
+
+    panic!(
+        "Encodings differ from byte {offset:#x} (expected {} bytes, found {})\n\
+         \n-- expected --\n{}\n-- found --\n{}",
+        expected.len(),
+        actual.len(),
+        dump_tlv(expected, offset),
+        dump_tlv(actual, offset),
+    );
+}
+
+/// Formats each TLV in `der` on its own line, indented by its depth, with
+/// the TLV that contains `offset` (and the byte at `offset`) marked. This
+/// parses the TLVs itself, so that it still works when the codec doesn't.
+fn dump_tlv(der: &[u8], offset: usize) -> String {
+    let mut output = String::new();
+    dump(der, 0, offset, 0, &mut output);
+    output
+}
+
+fn dump(der: &[u8], start: usize, offset: usize, depth: usize, output: &mut String) {
+    let indent = depth * 2;
+    let mut position = 0;
+
+    while position < der.len() {
+        let (class, constructed, tag, contents) = match parse_tlv(&der[position..]) {
+            Some(tlv) => tlv,
+            None => {
+                output.push_str(&format!(
+                    "{:6x}: {:indent$}<invalid: {:02X?}>\n",
+                    start + position,
+                    "",
+                    &der[position..der.len().min(position + 8)],
+                ));
+                return;
+            }
+        };
+        let header = contents.as_ptr() as usize - der[position..].as_ptr() as usize;
+        let contents_start = start + position + header;
+        let end = contents_start + contents.len();
+        let class = ["UNIVERSAL", "APPLICATION", "CONTEXT", "PRIVATE"][usize::from(class)];
+        let marker = match (start + position..end).contains(&offset) {
+            true => " <--",
+            false => "",
+        };
+
+        output.push_str(&format!(
+            "{:6x}: {:indent$}[{} {}] {} bytes{}",
+            start + position,
+            "",
+            class,
+            tag,
+            contents.len(),
+            marker,
+        ));
+
+        if constructed {
+            output.push('\n');
+            dump(contents, contents_start, offset, depth + 1, output);
+        } else {
+            output.push_str(" =");
+            for (index, byte) in contents.iter().enumerate().take(16) {
+                let marker = if contents_start + index == offset {
+                    '>'
+                } else {
+                    ' '
+                };
+                output.push_str(&format!("{}{:02X}", marker, byte));
+            }
+            if contents.len() > 16 {
+                output.push_str(" ..");
+            }
+            output.push('\n');
+        }
+
+        position = end - start;
+    }
+}
+
+/// Parses the class, whether it's constructed, the tag number, and the
+/// contents of the definite length TLV at the start of `der`.
+fn parse_tlv(der: &[u8]) -> Option<(u8, bool, u32, &[u8])> {
+    let (&identifier, mut rest) = der.split_first()?;
+    let mut tag = u32::from(identifier & 0x1F);
+
+    if tag == 0x1F {
+        tag = 0;
+        loop {
+            let (&byte, remaining) = rest.split_first()?;
+            rest = remaining;
+            tag = tag.checked_mul(128)? | u32::from(byte & 0x7F);
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+    }
+
+    let (&length, mut rest) = rest.split_first()?;
+    let length = match length {
+        0..=0x7F => usize::from(length),
+        0x81..=0x84 => {
+            let (bytes, remaining) = rest.split_at(usize::from(length & 0x7F).min(rest.len()));
+            rest = remaining;
+            bytes
+                .iter()
+                .fold(0, |length, byte| length << 8 | usize::from(*byte))
+        }
+        _ => return None,
+    };
+
+    Some((
+        identifier >> 6,
+        identifier & 0x20 != 0,
+        tag,
+        rest.get(..length)?,
+    ))
+}
+
+#[test]
+fn certificates() {
+    let ca: rasn_pkix::Certificate = assert_round_trip(&load_pem(CA_CERTIFICATE, "CERTIFICATE"));
+    let leaf: rasn_pkix::Certificate =
+        assert_round_trip(&load_pem(LEAF_CERTIFICATE, "CERTIFICATE"));
+
+    assert_eq!(ca.tbs_certificate.subject, leaf.tbs_certificate.issuer);
+    assert_eq!(
+        Some(vec![0x8F, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66]),
+        rasn::types::IntegerExt::to_unsigned_bytes_be(&leaf.tbs_certificate.serial_number)
+    );
+}
+
+#[test]
+fn crl() {
+    let crl: rasn_pkix::CertificateList = assert_round_trip(&load_pem(CRL, "X509 CRL"));
+    let leaf: rasn_pkix::Certificate =
+        rasn::der::decode(&load_pem(LEAF_CERTIFICATE, "CERTIFICATE")).unwrap();
+
+    let revoked = crl.tbs_cert_list.revoked_certificates;
+    assert_eq!(1, revoked.len());
+    assert_eq!(
+        leaf.tbs_certificate.serial_number,
+        revoked[0].user_certificate
+    );
+}
+
+#[test]
+fn cms() {
+    for der in [load_pem(SIGNED_CMS, "CMS"), DETACHED_CMS.to_vec()] {
+        let info: rasn_cms::ContentInfo = assert_round_trip(&der);
+        assert_eq!(rasn_cms::CONTENT_SIGNED_DATA, info.content_type);
+
+        let signed: rasn_cms::SignedData = assert_round_trip(info.content.as_bytes());
+        assert_eq!(1, signed.signer_infos.len());
+    }
+}
+
+#[test]
+fn dump_marks_difference() {
+    let expected = [0x30, 0x06, 0x02, 0x01, 0x05, 0x04, 0x01, 0xAA];
+    let dump = dump_tlv(&expected, 7);
+
+    assert_eq!(
+        "     0: [UNIVERSAL 16] 6 bytes <--\n     \
+         2:   [UNIVERSAL 2] 1 bytes = 05\n     \
+         5:   [UNIVERSAL 4] 1 bytes <-- =>AA\n",
+        dump
+    );
+    assert!(std::panic::catch_unwind(|| assert_same_der(&expected, &expected[..5])).is_err());
+}
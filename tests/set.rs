@@ -50,3 +50,31 @@ fn decode() {
             .unwrap(),
     );
 }
+
+#[test]
+fn output_limits() {
+    use rasn::ber::de::{Decoder, DecoderOptions, Error};
+
+    #[derive(AsnType, Decode, Debug)]
+    #[rasn(set)]
+    struct Nulls {
+        sequence: Vec<()>,
+        set: SetOf<()>,
+    }
+
+    // Each field is fifty `NULL`s, which are limited by the number of
+    // elements, along with the fields themselves.
+    let mut data = vec![0x31, 0x81, 0xCC];
+    for tag in [0x30, 0x31] {
+        data.extend_from_slice(&[tag, 100]);
+        data.extend(std::iter::repeat_n([0x05, 0x00], 50).flatten());
+    }
+    let decode_with = |options: DecoderOptions| Nulls::decode(&mut Decoder::new(&data, options));
+
+    let nulls = decode_with(DecoderOptions::ber().max_output_elements(102)).unwrap();
+    assert_eq!((50, 1), (nulls.sequence.len(), nulls.set.len()));
+    assert!(matches!(
+        decode_with(DecoderOptions::ber().max_output_elements(101)),
+        Err(Error::ExceedsOutputLimit { limit: 101, .. })
+    ));
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "trace")]
+
+use std::sync::Mutex;
+
+use rasn::{types::*, *};
+
+struct Recorder(Mutex<Vec<String>>);
+
+impl log::Log for Recorder {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        self.0.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+static RECORDER: Recorder = Recorder(Mutex::new(Vec::new()));
+
+// Only one test is run in this file, as the logger is global.
+#[test]
+fn logs_calls() {
+    log::set_logger(&RECORDER).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    #[derive(AsnType, Decode, Encode)]
+    struct Pair {
+        flag: bool,
+        number: u8,
+    }
+
+    ber::encode(&Pair {
+        flag: true,
+        number: 7,
+    })
+    .unwrap();
+    assert!(ber::decode::<Pair>(&[0x30, 0x06, 0x01, 0x01, 0xFF, 0x01, 0x01, 0x07]).is_err());
+    // The contents of an empty value aren't a part of the input's buffer.
+    assert!(ber::decode::<Vec<u8>>(&[0x30, 0x00]).unwrap().is_empty());
+
+    let records = RECORDER.0.lock().unwrap();
+    let has = |expected: &str| records.iter().any(|record| record.contains(expected));
+
+    assert!(has(
+        "encode_bool Tag { class: Universal, value: 1 } at offset 0: true (3 bytes)"
+    ));
+    assert!(has(
+        "encode_integer Tag { class: Universal, value: 2 } at offset 3: 7 (3 bytes)"
+    ));
+    assert!(has(
+        "encode_sequence Tag { class: Universal, value: 16 } at offset 0: .. (8 bytes)"
+    ));
+    assert!(has(
+        "decode_bool Tag { class: Universal, value: 1 } at offset 2: true"
+    ));
+    assert!(has(
+        "decode_integer Tag { class: Universal, value: 2 } at offset 5 failed"
+    ));
+    assert!(has(
+        "decode_sequence Tag { class: Universal, value: 16 } at offset 0 failed"
+    ));
+    assert!(has(
+        "decode_sequence_of Tag { class: Universal, value: 16 } at offset 0"
+    ));
+}
@@ -0,0 +1,58 @@
+use rasn::prelude::*;
+
+#[derive(AsnType, Decode, Encode, Debug, PartialEq)]
+struct Version1 {
+    id: Integer,
+    name: Utf8String,
+    #[rasn(unknown_fields)]
+    unknown: UnknownFields,
+}
+
+#[derive(AsnType, Decode, Encode, Debug, PartialEq)]
+struct Version2 {
+    id: Integer,
+    name: Utf8String,
+    flag: bool,
+    #[rasn(tag(context, 0))]
+    extra: Option<OctetString>,
+}
+
+#[test]
+fn collects_and_re_encodes_unknown_fields() {
+    let version2 = Version2 {
+        id: 7.into(),
+        name: "rasn".into(),
+        flag: true,
+        extra: Some(OctetString::from_static(&[1, 2, 3])),
+    };
+    let encoded = rasn::der::encode(&version2).unwrap();
+
+    let version1: Version1 = rasn::der::decode(&encoded).unwrap();
+
+    assert_eq!(2, version1.unknown.len());
+    assert_eq!(
+        vec![Tag::BOOL, Tag::new(Class::Context, 0)],
+        version1
+            .unknown
+            .iter()
+            .map(|(tag, _)| tag)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(encoded, rasn::der::encode(&version1).unwrap());
+    assert_eq!(
+        version2,
+        rasn::der::decode::<Version2>(&rasn::der::encode(&version1).unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn no_unknown_fields() {
+    let version1 = Version1 {
+        id: 1.into(),
+        name: "a".into(),
+        unknown: UnknownFields::new(),
+    };
+    let encoded = rasn::ber::encode(&version1).unwrap();
+
+    assert_eq!(version1, rasn::ber::decode(&encoded).unwrap());
+}